@@ -1,4 +1,4 @@
-use oxitty::{AtomicState, StateSnapshot};
+use oxitty::{state::StateFlags, AtomicState, StateSnapshot};
 use std::{
     sync::atomic::{AtomicBool, Ordering},
     thread,
@@ -229,6 +229,31 @@ fn main() {
         }
     }
 
+    // Flag-only states snapshot into a single word and must never allocate
+    println!("\n⚡ Flag snapshot fast path:");
+    let flags = StateFlags::with_flags(&[(StateFlags::RUNNING, true)]);
+    // The first update lazily sets up the change notifier; keep it out of the measurement
+    flags.set(StateFlags::PROCESSING, true);
+    let before_flags = MemoryMetrics::from_heap_stats(&dhat::HeapStats::get());
+    let mut quits = 0;
+    for i in 0..100_000u32 {
+        flags.set(StateFlags::PROCESSING, i % 2 == 0);
+        if AtomicState::snapshot(&flags).should_quit() {
+            quits += 1;
+        }
+    }
+    let after_flags = MemoryMetrics::from_heap_stats(&dhat::HeapStats::get());
+    let flags_diff = after_flags.diff(&before_flags);
+    println!(
+        "100000 snapshots: {} bytes ({} blocks) allocated",
+        flags_diff.total_bytes, flags_diff.total_blocks
+    );
+    assert_eq!(quits, 0);
+    assert_eq!(
+        flags_diff.total_blocks, 0,
+        "flag snapshots should not allocate"
+    );
+
     let final_metrics = MemoryMetrics::from_heap_stats(&dhat::HeapStats::get());
     print_metrics("Final Metrics", &final_metrics);
 }
@@ -60,16 +60,251 @@
 //! }
 //! ```
 
-use smol::{future::FutureExt, Task};
-use std::{future::Future, sync::Arc, time::Duration};
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseEvent};
+use ratatui::backend::{Backend, CrosstermBackend};
+use std::{
+    collections::VecDeque,
+    future::Future,
+    io::{self, Stdout},
+    sync::{
+        atomic::{AtomicBool, AtomicU32, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
 
 use crate::{
-    error::OxittyResult,
-    event::{Event, EventHandler},
+    clock::{Clock, SystemClock},
+    error::{as_oxitty, ErrorKind, OxittyError, OxittyResult},
+    event::{normalize_key, CloneableAny, CommandSender, Event, EventHandler, ScrollKeys, Tagged},
+    runtime::{self, Receiver, Task, TryRecvError},
     state::AtomicState,
     tui::Tui,
 };
 
+/// Outcome of a registered event handler
+///
+/// Tells [`App::run`] how to proceed after a handler has seen an event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventFlow {
+    /// Keep running and pass the event on to later handlers
+    Continue,
+    /// Request that a frame be rendered for this event
+    Redraw,
+    /// Stop the event loop
+    Quit,
+}
+
+/// Lifecycle transition of the event loop reported to a lifecycle hook
+///
+/// See [`App::set_lifecycle_hook`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoopPhase {
+    /// The event task was spawned and the loop is about to run
+    Started,
+    /// The loop stopped and its background tasks were cleaned up
+    Stopped,
+}
+
+/// Handle ringing the terminal bell from event handlers
+///
+/// Handlers only see the state, so they capture a clone of the handle
+/// returned by [`App::bell_handle`] and call [`ring`](Self::ring). The
+/// loop rings the bell through [`Tui::bell`] before its next render.
+#[derive(Debug, Clone, Default)]
+pub struct BellHandle {
+    /// Set by a ring that the loop has not serviced yet
+    rung: Arc<AtomicBool>,
+}
+
+impl BellHandle {
+    /// Requests that the bell ring once
+    ///
+    /// Rings made before the loop services them are merged into one.
+    pub fn ring(&self) {
+        self.rung.store(true, Ordering::Release);
+    }
+
+    /// Clears the pending ring, returning whether there was one
+    fn take(&self) -> bool {
+        self.rung.swap(false, Ordering::AcqRel)
+    }
+}
+
+/// Total time background tasks get to finish on shutdown unless configured otherwise
+const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Delay before the first event task restart unless configured otherwise
+const DEFAULT_RESTART_BACKOFF: Duration = Duration::from_millis(50);
+
+/// Upper bound on the backoff doubling, so long restart limits cannot overflow
+const MAX_BACKOFF_DOUBLINGS: u32 = 10;
+
+/// Number of frame intervals averaged by [`App::fps`] unless configured otherwise
+const DEFAULT_FPS_WINDOW: usize = 30;
+
+/// Rolling record of frame timings
+///
+/// Fed one timestamp per rendered frame, it keeps the intervals between the
+/// most recent frames and reports the frame rate averaged over them.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::{Duration, Instant};
+/// use oxitty::app::FrameTimer;
+///
+/// let mut timer = FrameTimer::new(4);
+/// let start = Instant::now();
+/// for frame in 0..5 {
+///     timer.record(start + Duration::from_millis(20) * frame);
+/// }
+///
+/// assert_eq!(timer.last_frame_time(), Duration::from_millis(20));
+/// assert!((timer.fps() - 50.0).abs() < 0.01);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FrameTimer {
+    /// Timestamp of the most recent frame
+    last_frame: Option<Instant>,
+    /// Most recent frame intervals, oldest first
+    intervals: VecDeque<Duration>,
+    /// Sum of `intervals`
+    total: Duration,
+    /// Maximum number of intervals kept
+    window: usize,
+}
+
+impl FrameTimer {
+    /// Creates a timer averaging over the last `window` frame intervals.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - Number of intervals to average (at least 1)
+    pub fn new(window: usize) -> Self {
+        let window = window.max(1);
+        Self {
+            last_frame: None,
+            intervals: VecDeque::with_capacity(window),
+            total: Duration::ZERO,
+            window,
+        }
+    }
+
+    /// Records that a frame was rendered at `now`.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - When the frame finished rendering
+    pub fn record(&mut self, now: Instant) {
+        if let Some(last) = self.last_frame.replace(now) {
+            self.push(now.saturating_duration_since(last));
+        }
+    }
+
+    /// Changes the number of averaged intervals, dropping the oldest if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - Number of intervals to average (at least 1)
+    pub fn set_window(&mut self, window: usize) {
+        self.window = window.max(1);
+        while self.intervals.len() > self.window {
+            self.pop();
+        }
+    }
+
+    /// Returns the interval between the two most recent frames.
+    ///
+    /// Zero until at least two frames have been recorded.
+    pub fn last_frame_time(&self) -> Duration {
+        self.intervals.back().copied().unwrap_or_default()
+    }
+
+    /// Returns the frame rate averaged over the recorded window.
+    ///
+    /// Zero until at least two frames have been recorded.
+    pub fn fps(&self) -> f32 {
+        if self.intervals.is_empty() || self.total.is_zero() {
+            return 0.0;
+        }
+
+        self.intervals.len() as f32 / self.total.as_secs_f32()
+    }
+
+    /// Appends an interval, evicting the oldest once the window is full.
+    fn push(&mut self, interval: Duration) {
+        if self.intervals.len() == self.window {
+            self.pop();
+        }
+        self.intervals.push_back(interval);
+        self.total += interval;
+    }
+
+    /// Removes the oldest interval.
+    fn pop(&mut self) {
+        if let Some(oldest) = self.intervals.pop_front() {
+            self.total -= oldest;
+        }
+    }
+}
+
+impl Default for FrameTimer {
+    fn default() -> Self {
+        Self::new(DEFAULT_FPS_WINDOW)
+    }
+}
+
+/// Handler closure for key events
+type KeyHandler<S> = Box<dyn FnMut(&KeyEvent, &S) -> EventFlow + Send>;
+/// Handler closure for mouse events
+type MouseHandler<S> = Box<dyn FnMut(&MouseEvent, &S) -> EventFlow + Send>;
+/// Handler closure for resize events
+type ResizeHandler<S> = Box<dyn FnMut(u16, u16, &S) -> EventFlow + Send>;
+/// Handler closure for custom events
+type CustomHandler<S> = Box<dyn FnMut(&dyn CloneableAny, &S) -> EventFlow + Send>;
+/// Handler closure for periodic ticks
+type TickHandler<S> = Box<dyn FnMut(&S) -> EventFlow + Send>;
+/// Action fired after a period without user input
+type IdleHandler<S> = Box<dyn FnMut(&S) -> EventFlow + Send>;
+/// Observer called with every event the loop receives
+type TraceHook = Box<dyn Fn(&Event) + Send>;
+/// Observer called when the event loop starts and stops
+type LifecycleHook = Box<dyn Fn(LoopPhase) + Send>;
+/// Rings the bell of a backend known to accept raw writes
+type BellRinger<S, B> = fn(&mut Tui<S, B>) -> OxittyResult<()>;
+/// Hook drawing into each frame before or after the render function
+type RenderHook<S> = Box<
+    dyn FnMut(&<S as AtomicState>::Snapshot, ratatui::layout::Rect, &mut ratatui::Frame<'_>) + Send,
+>;
+
+/// An event handler registered on [`App`], keyed by the event type it accepts
+enum Handler<S> {
+    Key(KeyHandler<S>),
+    Mouse(MouseHandler<S>),
+    Resize(ResizeHandler<S>),
+    Custom(CustomHandler<S>),
+    Tick(TickHandler<S>),
+}
+
+/// An extra event channel merged into the loop of [`App`]
+struct EventSource {
+    /// Id wrapped around custom payloads, for tagged sources
+    id: Option<&'static str>,
+    /// Receiving end of the channel
+    receiver: Receiver<Event>,
+}
+
+impl EventSource {
+    /// Wraps a custom payload in a [`Tagged`] if the source has an id
+    fn tag(&self, event: Event) -> Event {
+        match (self.id, event) {
+            (Some(id), Event::Custom(payload)) => Event::Custom(Box::new(Tagged::new(id, payload))),
+            (_, event) => event,
+        }
+    }
+}
+
 /// Core application struct managing all components
 ///
 /// This struct coordinates between the terminal interface, event system,
@@ -130,15 +365,55 @@ use crate::{
 ///     Ok(())
 /// }
 /// ```
-pub struct App<S: AtomicState> {
+pub struct App<S: AtomicState, B: Backend = CrosstermBackend<Stdout>> {
     /// Terminal interface manager
-    tui: Tui<S>,
+    tui: Tui<S, B>,
     /// Event handling system
     events: Arc<EventHandler>,
-    /// Event polling rate
-    tick_rate: Duration,
+    /// Additional event channels merged into the loop
+    sources: Vec<EventSource>,
     /// Background task handles
     tasks: Vec<Task<OxittyResult<()>>>,
+    /// Total time background tasks get to finish on shutdown
+    shutdown_timeout: Duration,
+    /// Whether events still queued when the loop stops are dispatched
+    drain_on_quit: bool,
+    /// Translation of scroll wheel events into keys, if enabled
+    scroll_keys: Option<ScrollKeys>,
+    /// Times a failed event task is restarted before the error is kept
+    event_restarts: u32,
+    /// Delay before the first event task restart, doubled for each one after
+    restart_backoff: Duration,
+    /// Times the event task has been restarted, shared with the supervisor
+    event_task_restarts: Arc<AtomicU32>,
+    /// Key combinations that terminate the event loop
+    quit_keys: Vec<(KeyCode, KeyModifiers)>,
+    /// Registered event handlers in registration order
+    handlers: Vec<Handler<S>>,
+    /// Time source for ticks and shutdown timeouts
+    clock: Arc<dyn Clock>,
+    /// Timing of recently rendered frames
+    frame_timer: FrameTimer,
+    /// Loop iterations that skipped rendering after a slow frame
+    skipped_frames: u64,
+    /// State changes folded into the most recently rendered frame
+    coalesced_updates: u64,
+    /// Optional observer of received events, for debugging
+    trace_hook: Option<TraceHook>,
+    /// Optional observer of loop start and stop, for debugging
+    lifecycle_hook: Option<LifecycleHook>,
+    /// Time without key or mouse input after which the action fires
+    idle: Option<(Duration, IdleHandler<S>)>,
+    /// Hooks run before the render function, in registration order
+    before_render_hooks: Vec<RenderHook<S>>,
+    /// Hooks run after the render function, in registration order
+    after_render_hooks: Vec<RenderHook<S>>,
+    /// Rings requested by handlers through a [`BellHandle`]
+    bell: BellHandle,
+    /// Rings the bell, set once a handle was handed out
+    ring_bell: Option<BellRinger<S, B>>,
+    /// The last frame was drawn flashed and the next one must clear it
+    flash_shown: bool,
 }
 
 impl<S: AtomicState + 'static> App<S> {
@@ -196,17 +471,490 @@ impl<S: AtomicState + 'static> App<S> {
     /// }
     /// ```
     pub fn new(state: S, tick_rate: Duration) -> OxittyResult<Self> {
-        let tui = Tui::new(state)?;
+        Ok(Self::with_tui(Tui::new(state)?, tick_rate))
+    }
+}
+
+impl<S: AtomicState + 'static, B: Backend> App<S, B> {
+    /// Creates a new application instance around an existing terminal interface
+    ///
+    /// This allows driving the application with a [`Tui`] built via
+    /// [`Tui::with_backend`], for example on top of ratatui's `TestBackend`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tui` - The terminal interface manager to render with
+    /// * `tick_rate` - Event polling rate
+    pub fn with_tui(tui: Tui<S, B>, tick_rate: Duration) -> Self {
         let events = EventHandler::new();
+        events.set_tick_rate(tick_rate);
 
-        Ok(Self {
+        Self {
             tui,
             events: Arc::new(events),
-            tick_rate,
+            sources: Vec::new(),
             tasks: Vec::new(),
+            shutdown_timeout: DEFAULT_SHUTDOWN_TIMEOUT,
+            drain_on_quit: false,
+            scroll_keys: None,
+            event_restarts: 0,
+            restart_backoff: DEFAULT_RESTART_BACKOFF,
+            event_task_restarts: Arc::new(AtomicU32::new(0)),
+            quit_keys: Self::default_quit_keys(),
+            handlers: Vec::new(),
+            clock: Arc::new(SystemClock),
+            frame_timer: FrameTimer::default(),
+            skipped_frames: 0,
+            coalesced_updates: 0,
+            trace_hook: None,
+            lifecycle_hook: None,
+            idle: None,
+            before_render_hooks: Vec::new(),
+            after_render_hooks: Vec::new(),
+            bell: BellHandle::default(),
+            ring_bell: None,
+            flash_shown: false,
+        }
+    }
+
+    /// Returns the default quit binding: a plain `q`.
+    fn default_quit_keys() -> Vec<(KeyCode, KeyModifiers)> {
+        vec![(KeyCode::Char('q'), KeyModifiers::NONE)]
+    }
+
+    /// Configures which key combinations terminate the event loop.
+    ///
+    /// Replaces the default plain `q` binding. A key event quits only if both
+    /// its code and modifiers match one of the given pairs exactly. Passing an
+    /// empty set leaves [`Event::Quit`] as the only way to stop the loop, which
+    /// suits applications with free-form text input.
+    ///
+    /// Bindings and incoming keys are both passed through [`normalize_key`],
+    /// so case is significant but the terminal's spelling of Shift is not:
+    /// binding `Char('Q')` or `Char('q')` + SHIFT matches Shift+Q however the
+    /// terminal reports it, while the default lowercase `q` never matches
+    /// Shift+Q.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - Key code and modifier pairs that should trigger quit
+    pub fn quit_on(&mut self, keys: impl IntoIterator<Item = (KeyCode, KeyModifiers)>) {
+        self.quit_keys = keys
+            .into_iter()
+            .map(|(code, modifiers)| normalize_key(code, modifiers))
+            .collect();
+    }
+
+    /// Registers an additional channel of events to merge into the loop
+    ///
+    /// Events from every source are processed exactly like terminal events,
+    /// so external producers such as file watchers or network clients can
+    /// feed [`Event::Custom`] payloads to the registered handlers. The
+    /// terminal handler is checked first on each iteration, followed by the
+    /// sources in registration order; ordering across sources is otherwise
+    /// best-effort. A source is dropped once its channel is closed.
+    ///
+    /// # Arguments
+    ///
+    /// * `receiver` - Receiving end of a channel created with
+    ///   [`runtime::bounded`] or [`runtime::unbounded`]
+    pub fn add_event_source(&mut self, receiver: Receiver<Event>) {
+        self.sources.push(EventSource { id: None, receiver });
+    }
+
+    /// Registers an additional channel of events labelled with a source id
+    ///
+    /// Like [`add_event_source`](Self::add_event_source), except that custom
+    /// payloads from this channel reach [`on_custom`](Self::on_custom)
+    /// handlers wrapped in a [`Tagged`] carrying `id`, so handlers can tell
+    /// which producer sent otherwise identical events. Other events are
+    /// passed through unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Source id reported by [`Tagged::source`]
+    /// * `receiver` - Receiving end of a channel created with
+    ///   [`runtime::bounded`] or [`runtime::unbounded`]
+    pub fn add_event_source_tagged(&mut self, id: &'static str, receiver: Receiver<Event>) {
+        self.sources.push(EventSource {
+            id: Some(id),
+            receiver,
+        });
+    }
+
+    /// Returns the next pending event from the terminal handler or any source
+    fn next_event(&mut self) -> OxittyResult<Option<Event>> {
+        let event = self.receive()?;
+
+        Ok(event.map(|event| {
+            self.scroll_keys
+                .and_then(|keys| keys.translate(&event))
+                .unwrap_or(event)
+        }))
+    }
+
+    /// Takes the next event from the terminal channel or an extra source
+    fn receive(&mut self) -> OxittyResult<Option<Event>> {
+        if let Some(event) = self.events.try_recv()? {
+            return Ok(Some(event));
+        }
+
+        let mut next = None;
+        self.sources.retain(|source| {
+            if next.is_some() {
+                return true;
+            }
+            match source.receiver.try_recv() {
+                Ok(event) => {
+                    next = Some(source.tag(event));
+                    true
+                }
+                Err(TryRecvError::Empty) => true,
+                Err(TryRecvError::Closed) => false,
+            }
+        });
+
+        Ok(next)
+    }
+
+    /// Installs a hook that observes every event the loop receives
+    ///
+    /// The hook runs for each event in the order it is received, before the
+    /// quit bindings and handlers see it, which makes it a convenient place
+    /// to forward input to a logging framework. Only one hook is kept;
+    /// setting another replaces it. Without a hook the loop pays only for an
+    /// `Option` check.
+    ///
+    /// # Arguments
+    ///
+    /// * `hook` - Closure receiving each event
+    pub fn set_trace_hook<H>(&mut self, hook: H)
+    where
+        H: Fn(&Event) + Send + 'static,
+    {
+        self.trace_hook = Some(Box::new(hook));
+    }
+
+    /// Installs a hook that observes the event loop starting and stopping
+    ///
+    /// [`run`](Self::run) reports [`LoopPhase::Started`] before the first
+    /// event is received and [`LoopPhase::Stopped`] once the loop has ended
+    /// and its tasks are cleaned up, bracketing the events seen by the
+    /// [trace hook](Self::set_trace_hook). A loop that fails with an error
+    /// does not report `Stopped`. Only one hook is kept; setting another
+    /// replaces it.
+    ///
+    /// # Arguments
+    ///
+    /// * `hook` - Closure receiving each transition
+    pub fn set_lifecycle_hook<H>(&mut self, hook: H)
+    where
+        H: Fn(LoopPhase) + Send + 'static,
+    {
+        self.lifecycle_hook = Some(Box::new(hook));
+    }
+
+    /// Reports a loop transition to the lifecycle hook, if any
+    fn trace_phase(&self, phase: LoopPhase) {
+        if let Some(hook) = &self.lifecycle_hook {
+            hook(phase);
+        }
+    }
+
+    /// Sets the total time background tasks get to finish on shutdown
+    ///
+    /// When the event loop stops, all tasks started with [`App::spawn`] are
+    /// joined against a single deadline; whatever is still running once it
+    /// passes is cancelled. Exit latency is therefore bounded by this timeout
+    /// regardless of how many tasks are running. Defaults to 1 second.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Shutdown budget shared by all tasks
+    pub fn with_shutdown_timeout(mut self, timeout: Duration) -> Self {
+        self.shutdown_timeout = timeout;
+        self
+    }
+
+    /// Dispatches events still queued when the loop stops
+    ///
+    /// By default, events received after the one that stopped the loop are
+    /// discarded. With draining enabled, every event already waiting in the
+    /// terminal channel and the [extra sources](Self::add_event_source) at
+    /// that point is passed to the trace hook and handlers once more before
+    /// background tasks are shut down, so a final action such as saving can
+    /// still run. Events arriving during the drain are not waited for, quit
+    /// events and quit keys are skipped, and handler results are ignored
+    /// since the application is already stopping. Defaults to `false`.
+    ///
+    /// # Arguments
+    ///
+    /// * `drain` - Whether to dispatch queued events on shutdown
+    pub fn with_drain_on_quit(mut self, drain: bool) -> Self {
+        self.drain_on_quit = drain;
+        self
+    }
+
+    /// Delivers scroll wheel events as key presses
+    ///
+    /// With a mapping set, every scroll event the loop receives is replaced
+    /// by a press of the mapped key before the trace hook, quit bindings
+    /// and handlers see it, so keyboard navigation handles scrolling
+    /// without extra code. Mouse handlers then no longer receive scroll
+    /// events; clicks, drags and moves are unaffected. Disabled by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - Key for each scroll direction, or `None` to keep raw scroll events
+    pub fn with_scroll_keys(mut self, keys: Option<ScrollKeys>) -> Self {
+        self.scroll_keys = keys;
+        self
+    }
+
+    /// Restarts the event task when it fails with a retryable error
+    ///
+    /// By default, the first error from polling or reading the terminal
+    /// ends the event task and the loop receives no further input. With
+    /// restarts enabled, [`App::run`] supervises the task: after a
+    /// terminal, I/O or event error it waits `backoff`, doubling the delay
+    /// after each further failure, and starts the task again, up to
+    /// `max_restarts` times. Each restart is counted in
+    /// [`event_task_restarts`](Self::event_task_restarts). Other errors,
+    /// such as a closed channel, and failures after the handler was stopped
+    /// are not retried. Defaults to 0 restarts.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_restarts` - Restarts allowed before the error is kept
+    /// * `backoff` - Delay before the first restart
+    pub fn with_event_restarts(mut self, max_restarts: u32, backoff: Duration) -> Self {
+        self.event_restarts = max_restarts;
+        self.restart_backoff = backoff;
+        self
+    }
+
+    /// Replaces the clock used for ticks and shutdown timeouts
+    ///
+    /// Defaults to [`SystemClock`]. Tests can pass a
+    /// [`ManualClock`](crate::clock::ManualClock) to drive time-based behavior
+    /// deterministically.
+    ///
+    /// # Arguments
+    ///
+    /// * `clock` - The time source to use
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+
+    /// Registers a handler for key events
+    ///
+    /// Handlers of all types run in registration order for each event they
+    /// accept, after the built-in quit bindings have been checked. Returning
+    /// [`EventFlow::Quit`] stops the loop without running later handlers.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - Closure receiving the key event and the application state
+    pub fn on_key<H>(&mut self, handler: H)
+    where
+        H: FnMut(&KeyEvent, &S) -> EventFlow + Send + 'static,
+    {
+        self.handlers.push(Handler::Key(Box::new(handler)));
+    }
+
+    /// Registers a handler for mouse events
+    ///
+    /// See [`on_key`](Self::on_key) for ordering and control flow.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - Closure receiving the mouse event and the application state
+    pub fn on_mouse<H>(&mut self, handler: H)
+    where
+        H: FnMut(&MouseEvent, &S) -> EventFlow + Send + 'static,
+    {
+        self.handlers.push(Handler::Mouse(Box::new(handler)));
+    }
+
+    /// Registers a handler for terminal resize events
+    ///
+    /// A resize is handled in three steps: the terminal is first resized
+    /// internally, then the resize handlers run with the new dimensions, and
+    /// finally a full frame is rendered at the new size. Handlers can
+    /// therefore recompute layout caches or clamp scroll offsets and have the
+    /// result show up in the very next frame. See [`on_key`](Self::on_key)
+    /// for ordering among handlers and control flow.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - Closure receiving the new width, height and the application state
+    pub fn on_resize<H>(&mut self, handler: H)
+    where
+        H: FnMut(u16, u16, &S) -> EventFlow + Send + 'static,
+    {
+        self.handlers.push(Handler::Resize(Box::new(handler)));
+    }
+
+    /// Registers a handler for custom events
+    ///
+    /// The payload can be inspected by upcasting to [`std::any::Any`] and
+    /// downcasting to the concrete type. See [`on_key`](Self::on_key) for
+    /// ordering and control flow.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - Closure receiving the custom payload and the application state
+    pub fn on_custom<H>(&mut self, handler: H)
+    where
+        H: FnMut(&dyn CloneableAny, &S) -> EventFlow + Send + 'static,
+    {
+        self.handlers.push(Handler::Custom(Box::new(handler)));
+    }
+
+    /// Registers a handler called once per tick
+    ///
+    /// A tick fires whenever at least one tick rate has passed on the
+    /// application's [`Clock`] since the previous tick, checked once per loop
+    /// iteration before rendering. See [`on_key`](Self::on_key) for ordering
+    /// and control flow.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - Closure receiving the application state
+    pub fn on_tick<H>(&mut self, handler: H)
+    where
+        H: FnMut(&S) -> EventFlow + Send + 'static,
+    {
+        self.handlers.push(Handler::Tick(Box::new(handler)));
+    }
+
+    /// Fires an action once the user has been idle for `timeout`
+    ///
+    /// Only key and mouse events count as input; ticks, resizes and custom
+    /// events do not reset the timer. The action runs once per idle period,
+    /// measured on the application's [`Clock`], and runs again only after
+    /// new input followed by another full period without any. Return
+    /// [`EventFlow::Quit`] to exit a kiosk session, or lock the UI in the
+    /// state and return [`EventFlow::Redraw`]. Replaces any previously
+    /// configured idle action.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - How long the user must be idle
+    /// * `action` - Closure receiving the application state
+    pub fn idle_timeout<H>(&mut self, timeout: Duration, action: H)
+    where
+        H: FnMut(&S) -> EventFlow + Send + 'static,
+    {
+        self.idle = Some((timeout, Box::new(action)));
+    }
+
+    /// Registers a hook called right before the render function each frame
+    ///
+    /// The hook receives the same snapshot, area and frame as the render
+    /// function and runs within the same draw, so it suits instrumentation
+    /// such as starting a frame timer, or drawing a background the render
+    /// function paints over. Hooks run in registration order.
+    ///
+    /// # Arguments
+    ///
+    /// * `hook` - Closure receiving the snapshot, area and frame
+    pub fn before_render<H>(&mut self, hook: H)
+    where
+        H: FnMut(&S::Snapshot, ratatui::layout::Rect, &mut ratatui::Frame<'_>) + Send + 'static,
+    {
+        self.before_render_hooks.push(Box::new(hook));
+    }
+
+    /// Registers a hook called right after the render function each frame
+    ///
+    /// Anything the hook draws lands on top of the render function's output
+    /// in the same frame, which makes it the place for debug overlays such
+    /// as a frame rate or event counter. Hooks run in registration order.
+    ///
+    /// # Arguments
+    ///
+    /// * `hook` - Closure receiving the snapshot, area and frame
+    pub fn after_render<H>(&mut self, hook: H)
+    where
+        H: FnMut(&S::Snapshot, ratatui::layout::Rect, &mut ratatui::Frame<'_>) + Send + 'static,
+    {
+        self.after_render_hooks.push(Box::new(hook));
+    }
+
+    /// Draws one frame, running the render hooks around `render_fn`
+    ///
+    /// The before hooks, `render_fn` and the after hooks all draw into the
+    /// same frame, in that order.
+    fn draw<F>(&mut self, render_fn: F) -> OxittyResult<()>
+    where
+        F: FnOnce(&S::Snapshot, ratatui::layout::Rect, &mut ratatui::Frame<'_>),
+    {
+        let (before, after) = (&mut self.before_render_hooks, &mut self.after_render_hooks);
+        self.tui.render(|snapshot, area, frame| {
+            for hook in before.iter_mut() {
+                hook(snapshot, area, frame);
+            }
+            render_fn(snapshot, area, frame);
+            for hook in after.iter_mut() {
+                hook(snapshot, area, frame);
+            }
         })
     }
 
+    /// Runs the registered tick handlers
+    ///
+    /// Follows the same control flow as [`dispatch`](Self::dispatch).
+    fn tick(&mut self) -> EventFlow {
+        let state = self.tui.state();
+        let mut flow = EventFlow::Continue;
+
+        for handler in &mut self.handlers {
+            let Handler::Tick(handler) = handler else {
+                continue;
+            };
+
+            match handler(state) {
+                EventFlow::Quit => return EventFlow::Quit,
+                EventFlow::Redraw => flow = EventFlow::Redraw,
+                EventFlow::Continue => {}
+            }
+        }
+
+        flow
+    }
+
+    /// Runs the registered handlers matching an event
+    ///
+    /// Returns [`EventFlow::Quit`] as soon as a handler requests it, otherwise
+    /// [`EventFlow::Redraw`] if any handler asked for one.
+    fn dispatch(&mut self, event: &Event) -> EventFlow {
+        let state = self.tui.state();
+        let mut flow = EventFlow::Continue;
+
+        for handler in &mut self.handlers {
+            let result = match (handler, event) {
+                (Handler::Key(handler), Event::Key(key)) => handler(key, state),
+                (Handler::Mouse(handler), Event::Mouse(mouse)) => handler(mouse, state),
+                (Handler::Resize(handler), Event::Resize(width, height)) => {
+                    handler(*width, *height, state)
+                }
+                (Handler::Custom(handler), Event::Custom(payload)) => {
+                    handler(payload.as_ref(), state)
+                }
+                _ => continue,
+            };
+
+            match result {
+                EventFlow::Quit => return EventFlow::Quit,
+                EventFlow::Redraw => flow = EventFlow::Redraw,
+                EventFlow::Continue => {}
+            }
+        }
+
+        flow
+    }
+
     /// Spawns a background task
     ///
     /// # Example
@@ -268,14 +1016,26 @@ impl<S: AtomicState + 'static> App<S> {
     where
         F: Future<Output = OxittyResult<()>> + Send + 'static,
     {
-        let task = smol::spawn(future);
+        let task = runtime::spawn(future);
         self.tasks.push(task);
         Ok(())
     }
 
     /// Runs the application event loop
     ///
-    /// Runs the application event loop
+    /// Returns the state's [`AtomicState::exit_code`] once the loop stops, so
+    /// callers can forward it as the process exit status.
+    ///
+    /// Each loop iteration handles at most one event and then renders at
+    /// most one frame from a single snapshot. However often the state
+    /// changes in between, for example from a background task, the frame
+    /// shows only the latest values; see
+    /// [`coalesced_updates`](Self::coalesced_updates).
+    ///
+    /// If the terminal goes away while a frame is written, for example when
+    /// an SSH session drops, the render fails with
+    /// [`OxittyError::Disconnected`](crate::OxittyError::Disconnected). The
+    /// loop then quits and shuts down as usual instead of returning the error.
     ///
     /// # Example
     ///
@@ -327,82 +1087,375 @@ impl<S: AtomicState + 'static> App<S> {
     ///     assert!(app.is_err(), "App creation should fail in test environment");
     ///
     ///     // If we had a real terminal, we would run like this:
-    ///     // smol::block_on(async {
+    ///     // let exit_code = smol::block_on(async {
     ///     //     app.run(|snapshot, area, frame| {
     ///     //         // Rendering logic here
     ///     //     }).await
     ///     // })?;
+    ///     // std::process::exit(exit_code);
     ///
     ///     Ok(())
     /// }
     /// ```
-    pub async fn run<F>(&mut self, render_fn: F) -> OxittyResult<()>
+    pub async fn run<F>(&mut self, render_fn: F) -> OxittyResult<i32>
     where
         F: Fn(&S::Snapshot, ratatui::layout::Rect, &mut ratatui::Frame<'_>) + Send + 'static,
     {
-        // Spawn event handling task
+        // Spawn event handling task, restarting it on failure if configured
         let events = self.events.clone();
-        let tick_rate = self.tick_rate;
-        self.spawn(async move { events.run(tick_rate).await })?;
+        let clock = self.clock.clone();
+        let (max_restarts, backoff) = (self.event_restarts, self.restart_backoff);
+        let restarts = self.event_task_restarts.clone();
+        self.spawn(async move {
+            supervise_events(
+                &events,
+                clock.as_ref(),
+                &restarts,
+                max_restarts,
+                backoff,
+                || events.run_at_current_rate(),
+            )
+            .await
+        })?;
+
+        let mut last_tick = self.clock.now();
+        // Set after a render overran the tick budget; no render before then
+        let mut next_render: Option<Instant> = None;
+        // A handler or resize asked for a frame that has not been drawn yet
+        let mut redraw_requested = false;
+        // Last key or mouse input, and whether the idle action already fired since
+        let mut last_input = last_tick;
+        let mut idle_fired = false;
+        self.trace_phase(LoopPhase::Started);
 
         // Main event loop
         while self.tui.state().is_running() {
             // Non-blocking event check
-            if let Some(event) = self.events.try_recv()? {
+            if let Some(event) = self.next_event()? {
+                if let Some(hook) = &self.trace_hook {
+                    hook(&event);
+                }
+
                 match event {
                     Event::Quit => {
                         self.tui.state().quit();
                         break;
                     }
-                    Event::Key(key) => {
-                        if let crossterm::event::KeyCode::Char('q') = key.code {
+                    Event::Key(key)
+                        if self
+                            .quit_keys
+                            .contains(&normalize_key(key.code, key.modifiers)) =>
+                    {
+                        self.tui.state().quit();
+                        break;
+                    }
+                    event => {
+                        if matches!(event, Event::Key(_) | Event::Mouse(_)) {
+                            last_input = self.clock.now();
+                            idle_fired = false;
+                        }
+
+                        // Resize before handlers run so they see the new dimensions
+                        if let Event::Resize(width, height) = event {
+                            self.tui.resize(width, height)?;
+                            redraw_requested = true;
+                        }
+
+                        match self.dispatch(&event) {
+                            EventFlow::Quit => {
+                                self.tui.state().quit();
+                                break;
+                            }
+                            EventFlow::Redraw => redraw_requested = true,
+                            EventFlow::Continue => {}
+                        }
+                    }
+                }
+            }
+
+            // Fire a tick once a full tick rate has elapsed; zero disables ticks
+            let now = self.clock.now();
+            let tick_rate = self.tick_rate();
+            if !tick_rate.is_zero() && now.duration_since(last_tick) >= tick_rate {
+                last_tick = now;
+                match self.tick() {
+                    EventFlow::Quit => {
+                        self.tui.state().quit();
+                        break;
+                    }
+                    EventFlow::Redraw => redraw_requested = true,
+                    EventFlow::Continue => {}
+                }
+            }
+
+            // Fire the idle action once per period without input
+            if let Some((timeout, action)) = &mut self.idle {
+                if !idle_fired && now.duration_since(last_input) >= *timeout {
+                    idle_fired = true;
+                    match action(self.tui.state()) {
+                        EventFlow::Quit => {
                             self.tui.state().quit();
                             break;
                         }
+                        EventFlow::Redraw => redraw_requested = true,
+                        EventFlow::Continue => {}
                     }
-                    _ => {}
                 }
             }
 
-            // Non-blocking render
-            self.tui.render(&render_fn)?;
-
+            // Ring the bell requested by handlers since the last iteration
+            if self.bell.take() {
+                if let Some(ring) = self.ring_bell {
+                    ring(&mut self.tui)?;
+                }
+            }
+
+            // Non-blocking render, skipped while recovering from a slow frame
+            if next_render.is_some_and(|at| self.clock.now() < at) {
+                self.skipped_frames += 1;
+            } else if self.needs_render(redraw_requested) {
+                redraw_requested = false;
+                let started = self.clock.now();
+                match self.draw(&render_fn) {
+                    // Nobody is left to render for, so stop like a quit
+                    Err(e)
+                        if as_oxitty(&e).is_some_and(|e| e.kind() == ErrorKind::Disconnected) =>
+                    {
+                        self.tui.state().quit();
+                        break;
+                    }
+                    drawn => drawn?,
+                }
+                let finished = self.clock.now();
+                self.frame_timer.record(finished);
+
+                let tick_rate = self.tick_rate();
+                next_render =
+                    (finished.duration_since(started) > tick_rate).then(|| finished + tick_rate);
+            }
+
             // Yield to other tasks
-            smol::future::yield_now().await;
+            runtime::yield_now().await;
         }
 
-        // Stop event handler and cleanup tasks
+        // Stop event handler, drain what is left and cleanup tasks
         self.events.stop();
+        let drained = if self.drain_on_quit {
+            self.drain_events()
+        } else {
+            Ok(())
+        };
         self.cleanup_tasks().await;
+        drained?;
+        self.trace_phase(LoopPhase::Stopped);
+
+        Ok(self.tui.state().exit_code())
+    }
+
+    /// Runs the event loop like [`run`](Self::run) and returns the final snapshot
+    ///
+    /// Suited to selection-style interfaces whose result is whatever the
+    /// state holds when the user quits, such as the highlighted entry of a
+    /// picker. The snapshot is taken after the loop has stopped and its
+    /// tasks have been cleaned up, so it reflects the last handler's
+    /// changes. The exit code remains available through
+    /// [`AtomicState::exit_code`].
+    ///
+    /// # Arguments
+    ///
+    /// * `render_fn` - Function drawing each frame from the current snapshot
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`run`](Self::run); no snapshot is taken
+    /// if the loop fails.
+    pub async fn run_to_snapshot<F>(&mut self, render_fn: F) -> OxittyResult<S::Snapshot>
+    where
+        F: Fn(&S::Snapshot, ratatui::layout::Rect, &mut ratatui::Frame<'_>) + Send + 'static,
+    {
+        self.run(render_fn).await?;
+        Ok(self.tui.state().snapshot())
+    }
+
+    /// Draws a single frame without running the event loop
+    ///
+    /// Suited to tools that show a final screen and exit, such as a
+    /// `--snapshot` flag. The frame is drawn once with `render_fn`. With
+    /// `wait_for_key` set, terminal events are then read until a key is
+    /// pressed or [`Event::Quit`] arrives, so the user has time to look;
+    /// handlers, ticks and background tasks do not run. Finally the terminal
+    /// is handed back with [`Tui::restore`]. Use an inline [`Tui`] if the
+    /// frame should stay visible after exit.
+    ///
+    /// # Arguments
+    ///
+    /// * `render_fn` - Function drawing the frame from the current snapshot
+    /// * `wait_for_key` - Whether to wait for a key press before restoring
+    ///
+    /// # Errors
+    ///
+    /// Returns error if drawing, reading events or restoring the terminal fails.
+    pub async fn render_once<F>(&mut self, render_fn: F, wait_for_key: bool) -> OxittyResult<()>
+    where
+        F: FnOnce(&S::Snapshot, ratatui::layout::Rect, &mut ratatui::Frame<'_>),
+    {
+        let drawn = self.draw(render_fn);
+
+        let waited = match drawn {
+            Ok(()) if wait_for_key => {
+                runtime::race(self.wait_for_key(), self.events.run_at_current_rate()).await
+            }
+            drawn => drawn,
+        };
+
+        let restored = self.tui.restore();
+        waited.and(restored)
+    }
+
+    /// Waits until a key is pressed or a quit event arrives
+    ///
+    /// Waits on the channel itself rather than polling with the tick rate,
+    /// which may be zero when ticks are disabled.
+    async fn wait_for_key(&self) -> OxittyResult<()> {
+        use futures_lite::StreamExt;
+
+        let mut events = self.events.stream();
+        while let Some(event) = events.next().await {
+            match event {
+                Event::Key(key) if key.kind != KeyEventKind::Release => return Ok(()),
+                Event::Quit => return Ok(()),
+                _ => {}
+            }
+        }
+        Err(OxittyError::channel_closed("event channel", (0, 0)).into())
+    }
+
+    /// Dispatches the events that were queued when the loop stopped
+    ///
+    /// Only as many events as were pending on entry are taken, so a source
+    /// that keeps producing cannot hold up shutdown.
+    fn drain_events(&mut self) -> OxittyResult<()> {
+        let queued = self.events.queued()
+            + self
+                .sources
+                .iter()
+                .map(|source| source.receiver.len())
+                .sum::<usize>();
+
+        for _ in 0..queued {
+            let Some(event) = self.next_event()? else {
+                break;
+            };
+            if let Some(hook) = &self.trace_hook {
+                hook(&event);
+            }
+
+            match event {
+                Event::Quit => {}
+                Event::Key(key)
+                    if self
+                        .quit_keys
+                        .contains(&normalize_key(key.code, key.modifiers)) => {}
+                event => {
+                    if let Event::Resize(width, height) = event {
+                        self.tui.resize(width, height)?;
+                    }
+                    self.dispatch(&event);
+                }
+            }
+        }
 
         Ok(())
     }
 
+    /// Decides whether the current loop iteration renders a frame
+    ///
+    /// Always `true` unless the state provides a
+    /// [`RenderState`](crate::state::RenderState), in which
+    /// case its dirty flag is consumed and combined with `requested`.
+    /// A pending [flash](Tui::flash) forces a frame, and so does the
+    /// frame after it that clears the flash again.
+    fn needs_render(&mut self, requested: bool) -> bool {
+        let flashing = self.tui.is_flash_pending();
+        let requested = requested || flashing || self.flash_shown;
+        let render = match self.tui.state().render_state() {
+            Some(render) => {
+                let changes = render.pending_changes();
+                // Take the flag first so it is cleared even when a redraw was requested
+                let render = render.take_dirty() || requested;
+                if render {
+                    self.coalesced_updates = changes;
+                }
+                render
+            }
+            None => true,
+        };
+        if render {
+            self.flash_shown = flashing;
+        }
+        render
+    }
+
+    /// Restarts the application in place
+    ///
+    /// Calls [`AtomicState::reset`] and clears the screen so the next frame
+    /// is fully redrawn from the fresh state.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the terminal cannot be cleared.
+    pub fn restart(&mut self) -> OxittyResult<()> {
+        self.tui.state().reset();
+        self.tui.invalidate_snapshot();
+        if let Some(render) = self.tui.state().render_state() {
+            render.mark_dirty();
+        }
+        self.tui.clear()
+    }
+
+    /// Stops the application without running it and restores the terminal
+    ///
+    /// Use this on early-exit paths where [`run`](Self::run) is never called
+    /// or returned an error, so a failure to restore the terminal is returned
+    /// rather than only printed when the [`Tui`] is dropped. The event
+    /// handler is stopped and background tasks are cancelled without waiting.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the terminal cannot be restored; see
+    /// [`Tui::shutdown`].
+    pub fn shutdown(self) -> OxittyResult<()> {
+        let Self {
+            tui, events, tasks, ..
+        } = self;
+
+        events.stop();
+        // Dropping a task handle cancels the task
+        drop(tasks);
+        tui.shutdown()
+    }
+
     /// Cleanup background tasks with timeout
     ///
     /// This method attempts to gracefully shut down all background tasks.
-    /// It will wait up to 1 second for each task to complete before moving on.
+    /// All tasks share one deadline of [`App::with_shutdown_timeout`].
     ///
     /// # Implementation Details
     ///
     /// - Takes ownership of the tasks vector to ensure all tasks are handled
-    /// - Uses a 1 second timeout for each task
+    /// - Races joining every task against a single shutdown timer
+    /// - Drops, and thereby cancels, any task still running at the deadline
     /// - Logs any errors during cleanup but continues with shutdown
     async fn cleanup_tasks(&mut self) {
         let tasks = std::mem::take(&mut self.tasks);
-        for task in tasks {
-            // Attempt to join task with timeout
-            match task
-                .or(async {
-                    smol::Timer::after(Duration::from_secs(1)).await;
-                    Ok(())
-                })
-                .await
-            {
-                Ok(_) => {}
-                Err(e) => eprintln!("Task cleanup error: {}", e),
+        let join_all = async {
+            for task in tasks {
+                if let Err(e) = task.await {
+                    eprintln!("Task cleanup error: {}", e);
+                }
             }
-        }
+        };
+        runtime::race(join_all, self.clock.sleep(self.shutdown_timeout)).await;
     }
 
     /// Returns a reference to the terminal interface manager.
@@ -410,10 +1463,19 @@ impl<S: AtomicState + 'static> App<S> {
     /// # Returns
     ///
     /// A reference to the [`Tui`] instance.
-    pub fn tui(&self) -> &Tui<S> {
+    pub fn tui(&self) -> &Tui<S, B> {
         &self.tui
     }
 
+    /// Returns a mutable reference to the terminal interface manager.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the [`Tui`] instance.
+    pub fn tui_mut(&mut self) -> &mut Tui<S, B> {
+        &mut self.tui
+    }
+
     /// Returns a reference to the event handler.
     ///
     /// # Returns
@@ -423,24 +1485,184 @@ impl<S: AtomicState + 'static> App<S> {
         &self.events
     }
 
+    /// Returns a shared handle to the event handler.
+    ///
+    /// Unlike [`events`](Self::events), the handle can be moved into
+    /// handlers and tasks, letting them inject events or adjust the
+    /// [tick rate](EventHandler::set_tick_rate) while the loop is running.
+    pub fn event_handle(&self) -> Arc<EventHandler> {
+        self.events.clone()
+    }
+
+    /// Returns a sender dispatching commands of type `T` into the event loop.
+    ///
+    /// Commands arrive as [`Event::Custom`] and reach the handlers
+    /// registered with [`on_custom`](Self::on_custom), which downcast the
+    /// payload back to `T`. The sender stays usable from other threads and
+    /// tasks for as long as the loop runs.
+    pub fn command_sender<T: CloneableAny + Send>(&self) -> CommandSender<T> {
+        CommandSender::new(self.events.clone())
+    }
+
+    /// Returns the clock used for ticks and shutdown timeouts.
+    pub fn clock(&self) -> &Arc<dyn Clock> {
+        &self.clock
+    }
+
+    /// Returns the time between the two most recently rendered frames.
+    ///
+    /// Zero until at least two frames have been rendered.
+    pub fn last_frame_time(&self) -> Duration {
+        self.frame_timer.last_frame_time()
+    }
+
+    /// Returns the rendered frame rate averaged over recent frames.
+    ///
+    /// The number of frames averaged is set with
+    /// [`set_fps_window`](Self::set_fps_window) and defaults to 30.
+    pub fn fps(&self) -> f32 {
+        self.frame_timer.fps()
+    }
+
+    /// Returns how many loop iterations skipped rendering to catch up.
+    ///
+    /// When a render takes longer than the tick rate, the loop keeps
+    /// processing input but does not render again until one tick rate has
+    /// passed since the slow frame finished. The next frame then shows the
+    /// latest state, so skipped frames are coalesced instead of queued.
+    pub fn skipped_frames(&self) -> u64 {
+        self.skipped_frames
+    }
+
+    /// Returns how many times the event task has been restarted.
+    ///
+    /// Only counts while restarts are enabled with
+    /// [`with_event_restarts`](Self::with_event_restarts). A growing count
+    /// points at an unreliable terminal, and the app can tell the user
+    /// without writing over the screen.
+    pub fn event_task_restarts(&self) -> u32 {
+        self.event_task_restarts.load(Ordering::Acquire)
+    }
+
+    /// Returns how many state changes the most recently rendered frame covered.
+    ///
+    /// Each frame renders a single snapshot taken just before drawing, so
+    /// changes made between two frames are batched rather than rendered one
+    /// by one. This counts the [`RenderState::mark_dirty`] calls folded into
+    /// the last frame, which lets applications confirm that a fast-updating
+    /// background task is being coalesced. Always zero for states without a
+    /// [`render_state`](AtomicState::render_state).
+    pub fn coalesced_updates(&self) -> u64 {
+        self.coalesced_updates
+    }
+
+    /// Sets how many recent frame intervals [`fps`](Self::fps) averages over.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - Number of intervals to average (at least 1)
+    pub fn set_fps_window(&mut self, window: usize) {
+        self.frame_timer.set_window(window);
+    }
+
     /// Returns the current tick rate.
     ///
     /// # Returns
     ///
     /// The [`Duration`] between event checks.
     pub fn tick_rate(&self) -> Duration {
-        self.tick_rate
+        self.events.tick_rate()
+    }
+
+    /// Changes the tick rate, also while the loop is running.
+    ///
+    /// The rate is shared with the event task through the
+    /// [`EventHandler`], so the change takes effect on its next poll and on
+    /// the next tick check, which lets apps poll slowly while idle and speed
+    /// up during interaction. While [`run`](Self::run) borrows the app,
+    /// handlers can make the same change through
+    /// [`event_handle`](Self::event_handle). A zero rate disables ticks.
+    ///
+    /// # Arguments
+    ///
+    /// * `rate` - New duration between event checks and ticks
+    pub fn set_tick_rate(&mut self, rate: Duration) {
+        self.events.set_tick_rate(rate);
+    }
+}
+
+impl<S: AtomicState + 'static, B: Backend + io::Write> App<S, B> {
+    /// Returns a handle that rings the terminal bell from handlers
+    ///
+    /// Each [`ring`](BellHandle::ring) is serviced by [`run`](Self::run)
+    /// through [`Tui::bell`]. With
+    /// [`set_visual_bell`](Tui::set_visual_bell) enabled, the next frame
+    /// flashes instead, and it is rendered even if the state is unchanged.
+    pub fn bell_handle(&mut self) -> BellHandle {
+        self.ring_bell = Some(Tui::bell);
+        self.bell.clone()
+    }
+}
+
+/// Runs the event task started by `start`, restarting it after retryable failures
+///
+/// # Arguments
+///
+/// * `events` - Handler whose running flag ends supervision
+/// * `clock` - Time source for the backoff delays
+/// * `count` - Incremented on every restart
+/// * `max_restarts` - Restarts allowed before the error is returned
+/// * `backoff` - Delay before the first restart, doubled for each one after
+/// * `start` - Starts one run of the event task
+///
+/// # Errors
+///
+/// Returns the task's error once it is not retryable or the restarts are used up.
+async fn supervise_events<F, Fut>(
+    events: &EventHandler,
+    clock: &dyn Clock,
+    count: &AtomicU32,
+    max_restarts: u32,
+    backoff: Duration,
+    mut start: F,
+) -> OxittyResult<()>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = OxittyResult<()>>,
+{
+    let mut restarts = 0;
+    loop {
+        let Err(error) = start().await else {
+            return Ok(());
+        };
+        let retryable = as_oxitty(&error).is_some_and(|e| {
+            matches!(
+                e.kind(),
+                ErrorKind::Terminal | ErrorKind::Io | ErrorKind::Event
+            )
+        });
+        if !retryable || restarts >= max_restarts || !events.is_running() {
+            return Err(error);
+        }
+
+        let delay = backoff.saturating_mul(1 << restarts.min(MAX_BACKOFF_DOUBLINGS));
+        restarts += 1;
+        count.fetch_add(1, Ordering::AcqRel);
+        clock.sleep(delay).await;
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::sync::atomic::{AtomicBool, Ordering};
+    use crate::{clock::ManualClock, state::RenderState};
+    use ratatui::backend::TestBackend;
+    use std::sync::atomic::{AtomicBool, AtomicI32, AtomicU32, Ordering};
 
     #[derive(Debug, Clone)]
     struct TestSnapshot {
         running: bool,
+        counter: u32,
     }
 
     impl crate::state::StateSnapshot for TestSnapshot {
@@ -452,6 +1674,25 @@ mod tests {
     #[derive(Debug)]
     struct TestState {
         running: AtomicBool,
+        exit_code: AtomicI32,
+        counter: AtomicU32,
+        render: Option<RenderState>,
+    }
+
+    impl TestState {
+        fn new() -> Self {
+            Self {
+                running: AtomicBool::new(true),
+                exit_code: AtomicI32::new(0),
+                counter: AtomicU32::new(0),
+                render: None,
+            }
+        }
+
+        fn quit_with_code(&self, code: i32) {
+            self.exit_code.store(code, Ordering::Release);
+            self.quit();
+        }
     }
 
     impl AtomicState for TestState {
@@ -460,6 +1701,7 @@ mod tests {
         fn snapshot(&self) -> Self::Snapshot {
             TestSnapshot {
                 running: self.running.load(Ordering::Acquire),
+                counter: self.counter.load(Ordering::Acquire),
             }
         }
 
@@ -470,15 +1712,51 @@ mod tests {
         fn is_running(&self) -> bool {
             self.running.load(Ordering::Acquire)
         }
+
+        fn exit_code(&self) -> i32 {
+            self.exit_code.load(Ordering::Acquire)
+        }
+
+        fn render_state(&self) -> Option<&RenderState> {
+            self.render.as_ref()
+        }
+
+        fn reset(&self) {
+            self.counter.store(0, Ordering::Release);
+        }
+    }
+
+    fn test_app(state: TestState) -> App<TestState, TestBackend> {
+        let tui = Tui::with_backend(state, TestBackend::new(20, 5)).unwrap();
+        App::with_tui(tui, Duration::from_millis(1))
+    }
+
+    /// Writer whose output stays readable after it is moved into a backend
+    #[derive(Clone, Default)]
+    struct SharedWriter(Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl SharedWriter {
+        fn contents(&self) -> String {
+            String::from_utf8_lossy(&self.0.lock().unwrap()).into_owned()
+        }
+    }
+
+    impl std::io::Write for SharedWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
     }
 
     #[test]
     fn test_app_creation() {
         std::env::set_var("TERM", "dumb");
 
-        let state = TestState {
-            running: AtomicBool::new(true),
-        };
+        let state = TestState::new();
 
         let app_result = App::new(state, Duration::from_millis(50));
         assert!(
@@ -491,9 +1769,7 @@ mod tests {
     fn test_task_spawning() {
         std::env::set_var("TERM", "dumb");
 
-        let state = TestState {
-            running: AtomicBool::new(true),
-        };
+        let state = TestState::new();
 
         if let Ok(mut app) = App::new(state, Duration::from_millis(50)) {
             let spawn_result = app.spawn(async { Ok(()) });
@@ -501,4 +1777,1158 @@ mod tests {
             assert_eq!(app.tasks.len(), 1);
         }
     }
+
+    #[test]
+    fn test_shutdown_timeout_is_total_budget() {
+        let budget = Duration::from_millis(200);
+        let mut app = test_app(TestState::new()).with_shutdown_timeout(budget);
+
+        // Each task alone would outlast the budget several times over
+        for _ in 0..5 {
+            app.spawn(async {
+                runtime::sleep(Duration::from_secs(2)).await;
+                Ok(())
+            })
+            .unwrap();
+        }
+        app.spawn(async { Ok(()) }).unwrap();
+
+        let start = Instant::now();
+        smol::block_on(app.cleanup_tasks());
+        let elapsed = start.elapsed();
+
+        assert!(app.tasks.is_empty());
+        assert!(elapsed >= budget);
+        assert!(elapsed < budget * 3, "cleanup took {:?}", elapsed);
+    }
+
+    #[test]
+    fn test_run_returns_exit_code() {
+        let state = TestState::new();
+        state.quit_with_code(3);
+
+        let mut app = test_app(state);
+        let exit_code = smol::block_on(app.run(|_, _, _| {})).unwrap();
+        assert_eq!(exit_code, 3);
+    }
+
+    #[test]
+    fn test_command_sender_reaches_custom_handlers() {
+        #[derive(Debug, Clone, PartialEq)]
+        enum Command {
+            Add(u32),
+            Stop,
+        }
+
+        let mut app = test_app(TestState::new());
+        app.on_custom(|payload, state| {
+            let payload: &dyn std::any::Any = payload;
+            match payload.downcast_ref::<Command>() {
+                Some(Command::Add(n)) => {
+                    state.counter.fetch_add(*n, Ordering::AcqRel);
+                    EventFlow::Continue
+                }
+                Some(Command::Stop) => EventFlow::Quit,
+                None => EventFlow::Continue,
+            }
+        });
+
+        let commands = app.command_sender::<Command>();
+        std::thread::spawn(move || {
+            commands.send(Command::Add(2)).unwrap();
+            commands.clone().send(Command::Add(3)).unwrap();
+            commands.send(Command::Stop).unwrap();
+        })
+        .join()
+        .unwrap();
+        // Other custom payloads pass through untouched
+        app.events().try_send(Event::Custom(Box::new(7u8))).unwrap();
+
+        smol::block_on(app.run(|_, _, _| {})).unwrap();
+        assert_eq!(app.tui().state().counter.load(Ordering::Acquire), 5);
+        assert!(!app.tui().state().is_running());
+    }
+
+    #[test]
+    fn test_run_quits_when_terminal_disconnects() {
+        /// Writer failing like a terminal whose SSH session dropped
+        struct BrokenPipe;
+
+        impl std::io::Write for BrokenPipe {
+            fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+                Err(std::io::ErrorKind::BrokenPipe.into())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Err(std::io::ErrorKind::BrokenPipe.into())
+            }
+        }
+
+        let size = ratatui::layout::Size::new(20, 5);
+        let tui = Tui::with_writer_sized(TestState::new(), BrokenPipe, size).unwrap();
+        let mut app = App::with_tui(tui, Duration::from_millis(1));
+        app.tui().state().exit_code.store(3, Ordering::Release);
+
+        let exit_code = smol::block_on(app.run(|_, _, _| {})).unwrap();
+        assert_eq!(exit_code, 3);
+        assert!(!app.tui().state().is_running());
+        assert!(app.tasks.is_empty());
+    }
+
+    #[test]
+    fn test_run_to_snapshot_returns_final_state() {
+        use crossterm::event::KeyEvent;
+
+        let mut app = test_app(TestState::new());
+        app.on_key(|key, state| match key.code {
+            KeyCode::Down => {
+                state.counter.fetch_add(1, Ordering::AcqRel);
+                EventFlow::Continue
+            }
+            KeyCode::Enter => EventFlow::Quit,
+            _ => EventFlow::Continue,
+        });
+
+        let events = app.events();
+        for code in [KeyCode::Down, KeyCode::Down, KeyCode::Enter] {
+            events
+                .try_send(Event::Key(KeyEvent::new(code, KeyModifiers::NONE)))
+                .unwrap();
+        }
+
+        let snapshot = smol::block_on(app.run_to_snapshot(|_, _, _| {})).unwrap();
+        assert!(!snapshot.running);
+        assert_eq!(snapshot.counter, 2);
+    }
+
+    #[test]
+    fn test_run_returns_default_exit_code_on_quit_event() {
+        let mut app = test_app(TestState::new());
+        app.events().try_send(Event::Quit).unwrap();
+
+        let exit_code = smol::block_on(app.run(|_, _, _| {})).unwrap();
+        assert_eq!(exit_code, 0);
+        assert!(!app.tui().state().is_running());
+    }
+
+    #[test]
+    fn test_quit_on_custom_keys() {
+        use crossterm::event::KeyEvent;
+
+        let mut app = test_app(TestState::new());
+        app.quit_on([(KeyCode::Char('c'), KeyModifiers::CONTROL)]);
+
+        // A plain `q` must not stop the loop, so the trailing Quit gets consumed
+        let events = app.events();
+        events
+            .try_send(Event::Key(KeyEvent::new(
+                KeyCode::Char('q'),
+                KeyModifiers::NONE,
+            )))
+            .unwrap();
+        events.try_send(Event::Quit).unwrap();
+
+        smol::block_on(app.run(|_, _, _| {})).unwrap();
+        assert!(app.events().try_recv().unwrap().is_none());
+
+        // Ctrl+C stops the loop immediately, leaving later events queued
+        let mut app = test_app(TestState::new());
+        app.quit_on([(KeyCode::Char('c'), KeyModifiers::CONTROL)]);
+
+        let events = app.events();
+        events
+            .try_send(Event::Key(KeyEvent::new(
+                KeyCode::Char('c'),
+                KeyModifiers::CONTROL,
+            )))
+            .unwrap();
+        events.try_send(Event::Quit).unwrap();
+
+        smol::block_on(app.run(|_, _, _| {})).unwrap();
+        assert!(matches!(
+            app.events().try_recv().unwrap(),
+            Some(Event::Quit)
+        ));
+    }
+
+    #[test]
+    fn test_quit_on_shifted_letter() {
+        use crossterm::event::KeyEvent;
+
+        let shift_q = [
+            (KeyCode::Char('Q'), KeyModifiers::NONE),
+            (KeyCode::Char('Q'), KeyModifiers::SHIFT),
+            (KeyCode::Char('q'), KeyModifiers::SHIFT),
+        ];
+
+        // Each terminal spelling of Shift+Q matches either spelling of the binding
+        for binding in [shift_q[0], shift_q[2]] {
+            for (code, modifiers) in shift_q {
+                let mut app = test_app(TestState::new());
+                app.quit_on([binding]);
+
+                let events = app.events();
+                events
+                    .try_send(Event::Key(KeyEvent::new(code, modifiers)))
+                    .unwrap();
+                events.try_send(Event::Quit).unwrap();
+
+                smol::block_on(app.run(|_, _, _| {})).unwrap();
+                assert!(
+                    matches!(app.events().try_recv().unwrap(), Some(Event::Quit)),
+                    "{:?} should match binding {:?}",
+                    (code, modifiers),
+                    binding
+                );
+            }
+        }
+
+        // The default lowercase binding ignores Shift+Q in both spellings
+        for (code, modifiers) in [shift_q[0], shift_q[2]] {
+            let mut app = test_app(TestState::new());
+
+            let events = app.events();
+            events
+                .try_send(Event::Key(KeyEvent::new(code, modifiers)))
+                .unwrap();
+            events.try_send(Event::Quit).unwrap();
+
+            smol::block_on(app.run(|_, _, _| {})).unwrap();
+            assert!(app.events().try_recv().unwrap().is_none());
+        }
+    }
+
+    #[test]
+    fn test_shutdown_without_run() {
+        let mut app = test_app(TestState::new());
+        app.spawn(std::future::pending()).unwrap();
+        let events = app.event_handle();
+
+        assert!(app.shutdown().is_ok());
+        assert!(!events.is_running());
+    }
+
+    #[test]
+    fn test_restart_resets_state() {
+        let state = TestState::new();
+        state.counter.store(7, Ordering::Release);
+
+        let mut app = test_app(state);
+        app.restart().unwrap();
+        assert_eq!(app.tui().state().counter.load(Ordering::Acquire), 0);
+        assert!(app.tui().state().is_running());
+    }
+
+    #[test]
+    fn test_handlers_receive_matching_events() {
+        use std::sync::atomic::AtomicUsize;
+
+        let keys = Arc::new(AtomicUsize::new(0));
+        let mice = Arc::new(AtomicUsize::new(0));
+
+        let mut app = test_app(TestState::new());
+        let seen = keys.clone();
+        app.on_key(move |key, _| {
+            assert_eq!(key.code, KeyCode::Char('x'));
+            seen.fetch_add(1, Ordering::Relaxed);
+            EventFlow::Continue
+        });
+        let seen = mice.clone();
+        app.on_mouse(move |_, _| {
+            seen.fetch_add(1, Ordering::Relaxed);
+            EventFlow::Continue
+        });
+
+        app.events()
+            .try_send(Event::Key(KeyEvent::new(
+                KeyCode::Char('x'),
+                KeyModifiers::NONE,
+            )))
+            .unwrap();
+        app.events().try_send(Event::Quit).unwrap();
+        smol::block_on(app.run(|_, _, _| {})).unwrap();
+
+        assert_eq!(keys.load(Ordering::Relaxed), 1);
+        assert_eq!(mice.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn test_injected_key_reaches_handler() {
+        let mut app = test_app(TestState::new());
+        app.on_key(|key, state| {
+            if key.code == KeyCode::Enter {
+                state.counter.fetch_add(1, Ordering::Relaxed);
+            }
+            EventFlow::Continue
+        });
+
+        let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        app.events().inject(Event::Key(enter)).unwrap();
+        app.events().inject(Event::Quit).unwrap();
+        smol::block_on(app.run(|_, _, _| {})).unwrap();
+
+        assert_eq!(app.tui().state().counter.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_event_filter_installed_through_app() {
+        let mut app = test_app(TestState::new());
+        app.on_key(|_, state| {
+            state.counter.fetch_add(1, Ordering::Relaxed);
+            EventFlow::Continue
+        });
+        app.on_mouse(|_, state| {
+            state.counter.fetch_add(100, Ordering::Relaxed);
+            EventFlow::Continue
+        });
+        app.events().set_filter(|event| match event {
+            Event::Mouse(_) => None,
+            other => Some(other),
+        });
+
+        let reads = vec![
+            crossterm::event::Event::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)),
+            crossterm::event::Event::Mouse(MouseEvent {
+                kind: crossterm::event::MouseEventKind::Moved,
+                column: 1,
+                row: 1,
+                modifiers: KeyModifiers::NONE,
+            }),
+        ];
+        let reads = std::cell::RefCell::new(reads);
+        let events = app.events();
+        smol::block_on(events.run_with(
+            Duration::from_millis(1),
+            |_| {
+                let pending = !reads.borrow().is_empty();
+                if !pending {
+                    events.stop();
+                }
+                Ok(pending)
+            },
+            || Ok(reads.borrow_mut().pop().expect("polled only while pending")),
+        ))
+        .unwrap();
+        app.events().inject(Event::Quit).unwrap();
+        smol::block_on(app.run(|_, _, _| {})).unwrap();
+
+        assert_eq!(app.tui().state().counter.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_extra_event_source_reaches_handlers() {
+        let mut app = test_app(TestState::new());
+
+        // A closed source is dropped without disturbing the others
+        let (closed_tx, closed_rx) = runtime::unbounded();
+        drop(closed_tx);
+        app.add_event_source(closed_rx);
+
+        let (tx, rx) = runtime::unbounded();
+        app.add_event_source(rx);
+
+        app.on_custom(|payload, state| {
+            let any: &dyn std::any::Any = payload;
+            match any.downcast_ref::<&str>() {
+                Some(&"ping") => {
+                    state.counter.fetch_add(1, Ordering::Relaxed);
+                    EventFlow::Continue
+                }
+                Some(&"stop") => EventFlow::Quit,
+                _ => EventFlow::Continue,
+            }
+        });
+
+        for payload in ["ping", "ping", "stop"] {
+            tx.try_send(Event::Custom(Box::new(payload))).unwrap();
+        }
+        smol::block_on(app.run(|_, _, _| {})).unwrap();
+
+        assert_eq!(app.tui().state().counter.load(Ordering::Relaxed), 2);
+        assert_eq!(app.sources.len(), 1);
+    }
+
+    #[test]
+    fn test_tagged_event_source_reports_its_id() {
+        use std::sync::Mutex;
+
+        let mut app = test_app(TestState::new());
+
+        let (watcher_tx, watcher_rx) = runtime::unbounded();
+        app.add_event_source_tagged("watcher", watcher_rx);
+        let (network_tx, network_rx) = runtime::unbounded();
+        app.add_event_source_tagged("network", network_rx);
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let recorded = seen.clone();
+        app.on_custom(move |payload, _| {
+            let any: &dyn std::any::Any = payload;
+            let tagged = any.downcast_ref::<Tagged>().expect("tagged payload");
+            let mut seen = recorded.lock().unwrap_or_else(|e| e.into_inner());
+            seen.push((tagged.source(), *tagged.downcast_ref::<u32>().unwrap()));
+            if seen.len() == 2 {
+                EventFlow::Quit
+            } else {
+                EventFlow::Continue
+            }
+        });
+
+        watcher_tx.try_send(Event::Custom(Box::new(1u32))).unwrap();
+        network_tx.try_send(Event::Custom(Box::new(1u32))).unwrap();
+        smol::block_on(app.run(|_, _, _| {})).unwrap();
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![("watcher", 1u32), ("network", 1u32)]
+        );
+    }
+
+    #[test]
+    fn test_trace_hook_observes_events_in_order() {
+        use crossterm::event::KeyEvent;
+        use std::sync::Mutex;
+
+        let mut app = test_app(TestState::new());
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let log = seen.clone();
+        app.set_trace_hook(move |event| log.lock().unwrap().push(format!("{:?}", event)));
+
+        let events = [
+            Event::Key(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE)),
+            Event::Resize(20, 5),
+            Event::Custom(Box::new(7u8)),
+            Event::Quit,
+        ];
+        for event in &events {
+            app.events().try_send(event.clone()).unwrap();
+        }
+        smol::block_on(app.run(|_, _, _| {})).unwrap();
+
+        let expected: Vec<_> = events.iter().map(|event| format!("{:?}", event)).collect();
+        assert_eq!(*seen.lock().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_lifecycle_hook_brackets_traced_events() {
+        use std::sync::Mutex;
+
+        let mut app = test_app(TestState::new());
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let (events, phases) = (seen.clone(), seen.clone());
+        app.set_trace_hook(move |event| events.lock().unwrap().push(format!("{:?}", event)));
+        app.set_lifecycle_hook(move |phase| phases.lock().unwrap().push(format!("{:?}", phase)));
+
+        app.events().try_send(Event::Resize(20, 5)).unwrap();
+        app.events().try_send(Event::Quit).unwrap();
+        smol::block_on(app.run(|_, _, _| {})).unwrap();
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            ["Started", "Resize(20, 5)", "Quit", "Stopped"]
+        );
+    }
+
+    #[test]
+    fn test_drain_on_quit_dispatches_queued_events() {
+        let queue = |app: &App<TestState, TestBackend>| {
+            app.events().try_send(Event::Quit).unwrap();
+            app.events()
+                .try_send(Event::Custom(Box::new("save")))
+                .unwrap();
+        };
+        let on_save = |app: &mut App<TestState, TestBackend>| {
+            app.on_custom(|_, state| {
+                state.counter.fetch_add(1, Ordering::Relaxed);
+                EventFlow::Quit
+            });
+        };
+
+        // Without draining, events behind the quit are dropped
+        let mut app = test_app(TestState::new());
+        on_save(&mut app);
+        queue(&app);
+        smol::block_on(app.run(|_, _, _| {})).unwrap();
+        assert_eq!(app.tui().state().counter.load(Ordering::Relaxed), 0);
+
+        // With draining, they reach the handlers once
+        let mut app = test_app(TestState::new()).with_drain_on_quit(true);
+        on_save(&mut app);
+        queue(&app);
+        app.events().try_send(Event::Quit).unwrap();
+        smol::block_on(app.run(|_, _, _| {})).unwrap();
+        assert_eq!(app.tui().state().counter.load(Ordering::Relaxed), 1);
+        assert!(app.events().try_recv().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_scroll_keys_dispatch_scroll_as_keys() {
+        let scroll_up = || {
+            Event::Mouse(MouseEvent {
+                kind: crossterm::event::MouseEventKind::ScrollUp,
+                column: 0,
+                row: 0,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+        let record = |app: &mut App<TestState, TestBackend>| {
+            app.on_key(|key, state| {
+                if key.code == KeyCode::Up {
+                    state.counter.fetch_add(1, Ordering::Relaxed);
+                }
+                EventFlow::Continue
+            });
+            app.on_mouse(|_, state| {
+                state.counter.fetch_add(100, Ordering::Relaxed);
+                EventFlow::Continue
+            });
+        };
+
+        // Off by default: handlers see the raw scroll event
+        let mut app = test_app(TestState::new());
+        record(&mut app);
+        app.events().try_send(scroll_up()).unwrap();
+        app.events().try_send(Event::Quit).unwrap();
+        smol::block_on(app.run(|_, _, _| {})).unwrap();
+        assert_eq!(app.tui().state().counter.load(Ordering::Relaxed), 100);
+
+        // With the mapping, the scroll arrives as an Up key press
+        let mut app = test_app(TestState::new()).with_scroll_keys(Some(ScrollKeys::default()));
+        record(&mut app);
+        app.events().try_send(scroll_up()).unwrap();
+        app.events().try_send(Event::Quit).unwrap();
+        smol::block_on(app.run(|_, _, _| {})).unwrap();
+        assert_eq!(app.tui().state().counter.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_render_hooks_wrap_render_fn() {
+        let order = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let log = |order: &Arc<std::sync::Mutex<Vec<&'static str>>>, name| {
+            let order = order.clone();
+            move || order.lock().unwrap_or_else(|e| e.into_inner()).push(name)
+        };
+
+        let mut app = test_app(TestState::new());
+        let before = log(&order, "before");
+        app.before_render(move |_, area, frame| {
+            before();
+            frame.render_widget(ratatui::widgets::Paragraph::new("background"), area);
+        });
+        let after = log(&order, "after");
+        app.after_render(move |snapshot, area, frame| {
+            after();
+            let overlay = format!("fps {}", snapshot.counter);
+            frame.render_widget(ratatui::widgets::Paragraph::new(overlay), area);
+        });
+
+        let render = log(&order, "render");
+        smol::block_on(app.render_once(
+            move |_, area, frame| {
+                render();
+                frame.render_widget(ratatui::widgets::Paragraph::new("user content"), area);
+            },
+            false,
+        ))
+        .unwrap();
+
+        assert_eq!(*order.lock().unwrap(), vec!["before", "render", "after"]);
+        // The overlay is drawn over the start of the user's content
+        let text = crate::tui::buffer_to_string(app.tui().terminal().backend().buffer());
+        assert!(text.starts_with("fps 0content"));
+    }
+
+    #[test]
+    fn test_render_once_draws_single_frame() {
+        use std::sync::atomic::AtomicUsize;
+
+        let frames = Arc::new(AtomicUsize::new(0));
+        let draw = |frames: Arc<AtomicUsize>| {
+            move |_: &TestSnapshot, area: ratatui::layout::Rect, frame: &mut ratatui::Frame| {
+                frames.fetch_add(1, Ordering::Relaxed);
+                frame.render_widget(ratatui::widgets::Paragraph::new("done"), area);
+            }
+        };
+
+        let mut app = test_app(TestState::new());
+        smol::block_on(app.render_once(draw(frames.clone()), false)).unwrap();
+        assert_eq!(frames.load(Ordering::Relaxed), 1);
+        let text = crate::tui::buffer_to_string(app.tui().terminal().backend().buffer());
+        assert!(text.starts_with("done"));
+        assert!(!app.tui().is_raw_mode());
+
+        // Waiting consumes events up to the first key press
+        let mut app = test_app(TestState::new());
+        app.events().try_send(Event::Resize(20, 5)).unwrap();
+        app.events()
+            .try_send(Event::Key(KeyEvent::new(
+                KeyCode::Enter,
+                KeyModifiers::NONE,
+            )))
+            .unwrap();
+        app.events().try_send(Event::Quit).unwrap();
+        smol::block_on(app.render_once(draw(frames.clone()), true)).unwrap();
+        assert_eq!(frames.load(Ordering::Relaxed), 2);
+        assert!(matches!(
+            app.events().try_recv().unwrap(),
+            Some(Event::Quit)
+        ));
+        assert!(app.tasks.is_empty());
+    }
+
+    #[test]
+    fn test_render_once_waits_with_ticks_disabled() {
+        let mut app = test_app(TestState::new());
+        // Nothing reads the terminal, so the wait cannot end on its own
+        app.events = Arc::new(EventHandler::test_handler());
+        app.set_tick_rate(Duration::ZERO);
+        app.events().try_send(Event::Resize(20, 5)).unwrap();
+
+        // The key arrives while the frame is already waiting
+        let events = app.event_handle();
+        let sender = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(20));
+            events
+                .try_send(Event::Key(KeyEvent::new(
+                    KeyCode::Enter,
+                    KeyModifiers::NONE,
+                )))
+                .unwrap();
+            events.try_send(Event::Custom(Box::new("after"))).unwrap();
+        });
+
+        smol::block_on(app.render_once(|_, _, _| {}, true)).unwrap();
+        sender.join().unwrap();
+        assert!(matches!(
+            app.events().try_recv().unwrap(),
+            Some(Event::Custom(_))
+        ));
+    }
+
+    #[test]
+    fn test_handler_can_quit() {
+        let mut app = test_app(TestState::new());
+        app.quit_on([]);
+        app.on_custom(|payload, state| {
+            let any: &dyn std::any::Any = payload;
+            if any.downcast_ref::<&str>() == Some(&"stop") {
+                state.quit_with_code(5);
+                return EventFlow::Quit;
+            }
+            EventFlow::Continue
+        });
+
+        app.events()
+            .try_send(Event::Custom(Box::new("stop")))
+            .unwrap();
+        let exit_code = smol::block_on(app.run(|_, _, _| {})).unwrap();
+        assert_eq!(exit_code, 5);
+    }
+
+    #[test]
+    fn test_resize_event_updates_render_area() {
+        use ratatui::layout::Rect;
+        use std::sync::Mutex;
+
+        let mut app = test_app(TestState::new());
+        app.tui_mut().terminal_mut().backend_mut().resize(30, 8);
+        app.events().try_send(Event::Resize(30, 8)).unwrap();
+        app.events().try_send(Event::Quit).unwrap();
+
+        let areas = Arc::new(Mutex::new(Vec::new()));
+        let seen = areas.clone();
+        smol::block_on(app.run(move |_, area, _| seen.lock().unwrap().push(area))).unwrap();
+
+        assert_eq!(*areas.lock().unwrap(), vec![Rect::new(0, 0, 30, 8)]);
+        assert_eq!(app.tui().size().unwrap(), ratatui::layout::Size::new(30, 8));
+    }
+
+    #[test]
+    fn test_resize_handler_runs_between_resize_and_render() {
+        use std::sync::Mutex;
+
+        let mut app = test_app(TestState::new());
+        app.tui_mut().terminal_mut().backend_mut().resize(30, 8);
+        app.events().try_send(Event::Resize(30, 8)).unwrap();
+        app.events().try_send(Event::Quit).unwrap();
+
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let resized = log.clone();
+        app.on_resize(move |width, height, _| {
+            resized
+                .lock()
+                .unwrap()
+                .push(format!("resize {width}x{height}"));
+            EventFlow::Continue
+        });
+
+        let rendered = log.clone();
+        smol::block_on(app.run(move |_, area, _| {
+            rendered
+                .lock()
+                .unwrap()
+                .push(format!("render {}x{}", area.width, area.height));
+        }))
+        .unwrap();
+
+        assert_eq!(*log.lock().unwrap(), ["resize 30x8", "render 30x8"]);
+    }
+
+    #[test]
+    fn test_frame_timer_fps() {
+        let start = Instant::now();
+        let mut timer = FrameTimer::new(3);
+        assert_eq!(timer.fps(), 0.0);
+        assert_eq!(timer.last_frame_time(), Duration::ZERO);
+
+        // A single frame has no interval yet
+        timer.record(start);
+        assert_eq!(timer.fps(), 0.0);
+
+        // Intervals of 10ms, 20ms and 30ms average to 20ms, i.e. 50 fps
+        let mut at = start;
+        for ms in [10, 20, 30] {
+            at += Duration::from_millis(ms);
+            timer.record(at);
+        }
+        assert_eq!(timer.last_frame_time(), Duration::from_millis(30));
+        assert!((timer.fps() - 50.0).abs() < 0.01);
+
+        // The oldest interval drops out of the window: (20 + 30 + 40) / 3 = 30ms
+        at += Duration::from_millis(40);
+        timer.record(at);
+        assert!((timer.fps() - 1000.0 / 30.0).abs() < 0.01);
+
+        // Shrinking the window keeps only the most recent interval
+        timer.set_window(1);
+        assert!((timer.fps() - 25.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_app_tracks_frame_rate() {
+        use crate::clock::ManualClock;
+
+        let clock = Arc::new(ManualClock::new());
+        let mut app = test_app(TestState::new());
+        app.set_clock(clock.clone());
+        // Keep frames within the tick budget so none are skipped
+        app.set_tick_rate(Duration::from_millis(50));
+        app.on_tick(|state| {
+            if state.counter.fetch_add(1, Ordering::Relaxed) == 10 {
+                EventFlow::Quit
+            } else {
+                EventFlow::Continue
+            }
+        });
+
+        let frame_clock = clock.clone();
+        smol::block_on(app.run(move |_, _, _| {
+            frame_clock.advance(Duration::from_millis(25));
+        }))
+        .unwrap();
+
+        assert_eq!(app.last_frame_time(), Duration::from_millis(25));
+        assert!((app.fps() - 40.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_ticks_follow_injected_clock() {
+        use crate::clock::ManualClock;
+
+        let clock = Arc::new(ManualClock::new());
+        let tui = Tui::with_backend(TestState::new(), TestBackend::new(20, 5)).unwrap();
+        let mut app = App::with_tui(tui, Duration::from_secs(10));
+        app.set_clock(clock.clone());
+        app.on_tick(|state| {
+            if state.counter.fetch_add(1, Ordering::Relaxed) + 1 == 3 {
+                EventFlow::Quit
+            } else {
+                EventFlow::Continue
+            }
+        });
+
+        // Each frame moves time forward by half a tick
+        let frames = Arc::new(AtomicU32::new(0));
+        let rendered = frames.clone();
+        let frame_clock = clock.clone();
+        let start = std::time::Instant::now();
+        smol::block_on(app.run(move |_, _, _| {
+            rendered.fetch_add(1, Ordering::Relaxed);
+            frame_clock.advance(Duration::from_secs(5));
+        }))
+        .unwrap();
+
+        // Ticks at 10s, 20s and 30s; the third quits before rendering
+        assert_eq!(app.tui().state().counter.load(Ordering::Relaxed), 3);
+        assert_eq!(frames.load(Ordering::Relaxed), 6);
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_idle_timeout_resets_on_input() {
+        let clock = Arc::new(ManualClock::new());
+        let tui = Tui::with_backend(TestState::new(), TestBackend::new(20, 5)).unwrap();
+        let mut app = App::with_tui(tui, Duration::from_secs(1));
+        app.set_clock(clock.clone());
+
+        // A key arrives 5s in, halfway through the idle window
+        let handle = app.event_handle();
+        let tick_clock = clock.clone();
+        app.on_tick(move |_| {
+            if tick_clock.elapsed() == Duration::from_secs(5) {
+                let key = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+                handle.inject(Event::Key(key)).unwrap();
+            }
+            EventFlow::Continue
+        });
+
+        let key_clock = clock.clone();
+        let key_at = Arc::new(std::sync::Mutex::new(None));
+        let seen = key_at.clone();
+        app.on_key(move |_, _| {
+            *seen.lock().unwrap() = Some(key_clock.elapsed());
+            EventFlow::Continue
+        });
+
+        let idle_clock = clock.clone();
+        let idle_at = Arc::new(std::sync::Mutex::new(None));
+        let fired = idle_at.clone();
+        app.idle_timeout(Duration::from_secs(10), move |_| {
+            *fired.lock().unwrap() = Some(idle_clock.elapsed());
+            EventFlow::Quit
+        });
+
+        // Each frame moves time forward by a second
+        let frame_clock = clock.clone();
+        smol::block_on(app.run(move |_, _, _| {
+            frame_clock.advance(Duration::from_secs(1));
+        }))
+        .unwrap();
+
+        // The action fires a full window after the key, not after startup
+        let key_at = key_at.lock().unwrap().expect("key was dispatched");
+        let idle_at = idle_at.lock().unwrap().expect("idle action fired");
+        assert!(key_at >= Duration::from_secs(5));
+        assert_eq!(idle_at, key_at + Duration::from_secs(10));
+        assert!(!app.tui().state().is_running());
+    }
+
+    #[test]
+    fn test_tick_rate_changes_mid_run() {
+        use crate::clock::ManualClock;
+
+        let clock = Arc::new(ManualClock::new());
+        let tui = Tui::with_backend(TestState::new(), TestBackend::new(20, 5)).unwrap();
+        let mut app = App::with_tui(tui, Duration::from_secs(10));
+        app.set_clock(clock.clone());
+
+        // The first tick slows ticking down; the second quits
+        let handle = app.event_handle();
+        let tick_clock = clock.clone();
+        let ticked_at = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let ticks = ticked_at.clone();
+        app.on_tick(move |_| {
+            let mut ticks = ticks.lock().unwrap();
+            ticks.push(tick_clock.elapsed());
+            if ticks.len() == 1 {
+                handle.set_tick_rate(Duration::from_secs(20));
+                EventFlow::Continue
+            } else {
+                EventFlow::Quit
+            }
+        });
+
+        let frame_clock = clock.clone();
+        smol::block_on(app.run(move |_, _, _| {
+            frame_clock.advance(Duration::from_secs(5));
+        }))
+        .unwrap();
+
+        assert_eq!(
+            *ticked_at.lock().unwrap(),
+            [Duration::from_secs(10), Duration::from_secs(30)]
+        );
+        assert_eq!(app.tick_rate(), Duration::from_secs(20));
+
+        app.set_tick_rate(Duration::from_millis(50));
+        assert_eq!(app.events().tick_rate(), Duration::from_millis(50));
+    }
+
+    #[test]
+    fn test_render_state_limits_renders_to_changes() {
+        let state = TestState {
+            render: Some(RenderState::new()),
+            ..TestState::new()
+        };
+        let mut app = test_app(state);
+        app.on_custom(|payload, state| {
+            let any: &dyn std::any::Any = payload;
+            match any.downcast_ref::<&str>() {
+                Some(&"touch") => {
+                    state.render.as_ref().unwrap().mark_dirty();
+                    EventFlow::Continue
+                }
+                Some(&"redraw") => EventFlow::Redraw,
+                _ => EventFlow::Continue,
+            }
+        });
+
+        for payload in ["noop", "touch", "noop", "redraw", "noop"] {
+            app.events()
+                .try_send(Event::Custom(Box::new(payload)))
+                .unwrap();
+        }
+        app.events().try_send(Event::Quit).unwrap();
+
+        // The initial frame, the change and the requested redraw
+        let renders = Arc::new(AtomicU32::new(0));
+        let rendered = renders.clone();
+        smol::block_on(app.run(move |_, _, _| {
+            rendered.fetch_add(1, Ordering::Relaxed);
+        }))
+        .unwrap();
+        assert_eq!(renders.load(Ordering::Relaxed), 3);
+
+        // Restarting marks the state dirty so the cleared screen is redrawn
+        app.restart().unwrap();
+        assert!(app.tui().state().render.as_ref().unwrap().is_dirty());
+    }
+
+    #[test]
+    fn test_zero_tick_rate_disables_ticks() {
+        let mut app = test_app(TestState::new());
+        app.set_tick_rate(Duration::ZERO);
+        app.on_tick(|state| {
+            state.counter.fetch_add(1, Ordering::Relaxed);
+            EventFlow::Continue
+        });
+
+        for _ in 0..5 {
+            app.events().try_send(Event::Custom(Box::new(()))).unwrap();
+        }
+        app.events().try_send(Event::Quit).unwrap();
+        smol::block_on(app.run(|_, _, _| {})).unwrap();
+
+        assert_eq!(app.tui().state().counter.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn test_bell_handle_rings_from_handlers() {
+        let writer = SharedWriter::default();
+        let size = ratatui::layout::Size::new(20, 5);
+        let tui = Tui::with_writer_sized(TestState::new(), writer.clone(), size).unwrap();
+        let mut app = App::with_tui(tui, Duration::from_millis(1));
+        let bell = app.bell_handle();
+        app.on_custom(move |_, _| {
+            bell.ring();
+            EventFlow::Continue
+        });
+
+        app.events().try_send(Event::Custom(Box::new(()))).unwrap();
+        app.events().try_send(Event::Quit).unwrap();
+        smol::block_on(app.run(|_, _, _| {})).unwrap();
+
+        assert_eq!(writer.contents().matches('\x07').count(), 1);
+    }
+
+    #[test]
+    fn test_visual_bell_renders_flash_and_clearing_frame() {
+        let state = TestState {
+            render: Some(RenderState::new()),
+            ..TestState::new()
+        };
+        let writer = SharedWriter::default();
+        let size = ratatui::layout::Size::new(20, 5);
+        let tui = Tui::with_writer_sized(state, writer.clone(), size).unwrap();
+        let mut app = App::with_tui(tui, Duration::from_millis(1));
+        app.tui_mut().set_visual_bell(true);
+        let bell = app.bell_handle();
+        app.on_custom(move |payload, _| {
+            let any: &dyn std::any::Any = payload;
+            if any.downcast_ref::<&str>() == Some(&"ring") {
+                bell.ring();
+            }
+            EventFlow::Continue
+        });
+
+        for payload in ["noop", "ring", "noop", "noop"] {
+            app.events()
+                .try_send(Event::Custom(Box::new(payload)))
+                .unwrap();
+        }
+        app.events().try_send(Event::Quit).unwrap();
+
+        let renders = Arc::new(AtomicU32::new(0));
+        let rendered = renders.clone();
+        smol::block_on(app.run(move |_, _, _| {
+            rendered.fetch_add(1, Ordering::Relaxed);
+        }))
+        .unwrap();
+
+        // The initial frame, the flashed frame and the one clearing it
+        assert_eq!(renders.load(Ordering::Relaxed), 3);
+        assert!(!app.tui().is_flash_pending());
+        let written = writer.contents();
+        assert!(written.contains("\x1b[7m"));
+        assert!(!written.contains('\x07'));
+    }
+
+    #[test]
+    fn test_state_changes_coalesce_into_one_frame() {
+        let state = TestState {
+            render: Some(RenderState::new()),
+            ..TestState::new()
+        };
+        let mut app = test_app(state);
+        app.on_custom(|payload, state| {
+            let any: &dyn std::any::Any = payload;
+            if any.downcast_ref::<&str>() == Some(&"burst") {
+                for _ in 0..100 {
+                    state.counter.fetch_add(1, Ordering::AcqRel);
+                    state.render.as_ref().unwrap().mark_dirty();
+                }
+            }
+            EventFlow::Continue
+        });
+
+        for payload in ["noop", "burst"] {
+            app.events()
+                .try_send(Event::Custom(Box::new(payload)))
+                .unwrap();
+        }
+        app.events().try_send(Event::Quit).unwrap();
+
+        let rendered = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let frames = rendered.clone();
+        smol::block_on(app.run(move |snapshot, _, _| {
+            frames.lock().unwrap().push(snapshot.counter);
+        }))
+        .unwrap();
+
+        // The initial frame, then one frame with the final value of the burst
+        assert_eq!(*rendered.lock().unwrap(), [0, 100]);
+        assert_eq!(app.coalesced_updates(), 100);
+    }
+
+    #[test]
+    fn test_slow_render_skips_frames() {
+        use crate::clock::ManualClock;
+
+        let clock = Arc::new(ManualClock::new());
+        let tui = Tui::with_backend(TestState::new(), TestBackend::new(20, 5)).unwrap();
+        let mut app = App::with_tui(tui, Duration::from_secs(10));
+        app.set_clock(clock.clone());
+
+        // Every event takes a second to handle
+        let hook_clock = clock.clone();
+        app.set_trace_hook(move |_| hook_clock.advance(Duration::from_secs(1)));
+        for _ in 0..30 {
+            app.events().try_send(Event::Custom(Box::new(()))).unwrap();
+        }
+        app.events().try_send(Event::Quit).unwrap();
+
+        // Only the first frame overruns the 10s budget, by taking 25s
+        let renders = Arc::new(AtomicU32::new(0));
+        let rendered = renders.clone();
+        let frame_clock = clock.clone();
+        smol::block_on(app.run(move |_, _, _| {
+            if rendered.fetch_add(1, Ordering::Relaxed) == 0 {
+                frame_clock.advance(Duration::from_secs(25));
+            }
+        }))
+        .unwrap();
+
+        // The slow frame ends at 26s; the nine events up to 35s are handled
+        // without rendering, then every event renders again
+        assert_eq!(app.skipped_frames(), 9);
+        assert_eq!(renders.load(Ordering::Relaxed), 21);
+        assert!(app.events().try_recv().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_event_task_restarts_up_to_limit() {
+        let handler = EventHandler::test_handler();
+        let clock = ManualClock::new();
+        let backoff = Duration::from_millis(10);
+        let restarts = AtomicU32::new(0);
+        let mut starts = 0;
+
+        let result = smol::block_on(supervise_events(
+            &handler,
+            &clock,
+            &restarts,
+            3,
+            backoff,
+            || {
+                starts += 1;
+                handler.run_with(
+                    Duration::from_millis(1),
+                    |_| Err(std::io::Error::other("poll failed")),
+                    || unreachable!("polling never succeeds"),
+                )
+            },
+        ));
+
+        assert!(result.is_err());
+        assert_eq!(starts, 4);
+        assert_eq!(restarts.load(Ordering::Relaxed), 3);
+        assert_eq!(clock.elapsed(), Duration::from_millis(10 + 20 + 40));
+
+        // Without restarts the first failure is final
+        let mut starts = 0;
+        let restarts = AtomicU32::new(0);
+        let result = smol::block_on(supervise_events(
+            &handler,
+            &clock,
+            &restarts,
+            0,
+            backoff,
+            || {
+                starts += 1;
+                handler.run_with(
+                    Duration::from_millis(1),
+                    |_| Err(std::io::Error::other("poll failed")),
+                    || unreachable!("polling never succeeds"),
+                )
+            },
+        ));
+        assert!(result.is_err());
+        assert_eq!(starts, 1);
+        assert_eq!(restarts.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn test_event_task_recovers_after_restart() {
+        let handler = &EventHandler::test_handler();
+        let clock = ManualClock::new();
+        let restarts = AtomicU32::new(0);
+        let mut starts = 0;
+
+        let result = smol::block_on(supervise_events(
+            handler,
+            &clock,
+            &restarts,
+            5,
+            Duration::from_millis(10),
+            || {
+                starts += 1;
+                let fail = starts == 1;
+                handler.run_with(
+                    Duration::from_millis(1),
+                    move |_| {
+                        if fail {
+                            return Err(std::io::Error::other("poll failed"));
+                        }
+                        handler.stop();
+                        Ok(false)
+                    },
+                    || unreachable!("no events are reported"),
+                )
+            },
+        ));
+
+        assert!(result.is_ok());
+        assert_eq!(starts, 2);
+        assert_eq!(restarts.load(Ordering::Relaxed), 1);
+        assert_eq!(clock.elapsed(), Duration::from_millis(10));
+    }
 }
@@ -0,0 +1,177 @@
+//! Time source abstraction
+//!
+//! Timing features such as tick callbacks and timeouts read the current time
+//! and sleep through a [`Clock`] instead of calling [`Instant::now`] or
+//! [`runtime::sleep`](crate::runtime::sleep) directly. Production code uses [`SystemClock`], while tests
+//! can substitute a [`ManualClock`] to advance time deterministically without
+//! any real delay.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use oxitty::clock::{Clock, ManualClock};
+//!
+//! let clock = ManualClock::new();
+//! let start = clock.now();
+//!
+//! clock.advance(Duration::from_secs(5));
+//! assert_eq!(clock.now() - start, Duration::from_secs(5));
+//!
+//! // Sleeping completes immediately and moves time forward
+//! smol::block_on(clock.sleep(Duration::from_secs(1)));
+//! assert_eq!(clock.now() - start, Duration::from_secs(6));
+//! ```
+
+use std::{
+    fmt::Debug,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// Future returned by [`Clock::sleep`].
+pub type Sleep = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Source of the current time and of timed waits.
+///
+/// Implementations must be shareable across tasks, as the same clock is
+/// typically held by both the application and its background tasks.
+pub trait Clock: Debug + Send + Sync {
+    /// Returns the current instant according to this clock.
+    fn now(&self) -> Instant;
+
+    /// Returns a future that completes once `duration` has elapsed on this clock.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - How long to wait
+    fn sleep(&self, duration: Duration) -> Sleep;
+}
+
+/// Clock backed by the system's monotonic time and [`runtime::sleep`](crate::runtime::sleep).
+///
+/// This is the default clock for [`App`](crate::App) and
+/// [`EventHandler`](crate::EventHandler).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep(&self, duration: Duration) -> Sleep {
+        Box::pin(crate::runtime::sleep(duration))
+    }
+}
+
+/// Clock whose time only moves when told to.
+///
+/// Time starts at the instant the clock is created and moves forward through
+/// [`advance`](Self::advance). [`sleep`](Clock::sleep) advances the clock by
+/// the requested duration when first polled and completes immediately, so
+/// code waiting on the clock runs as if that time had passed. A sleep that is
+/// dropped without being polled, such as the losing side of a race, leaves
+/// the clock untouched.
+#[derive(Debug)]
+pub struct ManualClock {
+    /// Real instant the clock's time is measured from
+    origin: Instant,
+    /// Time advanced since `origin`, shared with pending sleeps
+    elapsed: Arc<Mutex<Duration>>,
+}
+
+impl ManualClock {
+    /// Creates a clock starting at the current instant.
+    pub fn new() -> Self {
+        Self {
+            origin: Instant::now(),
+            elapsed: Arc::new(Mutex::new(Duration::ZERO)),
+        }
+    }
+
+    /// Moves the clock forward.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - How far to advance
+    pub fn advance(&self, duration: Duration) {
+        *self.elapsed.lock().unwrap_or_else(|e| e.into_inner()) += duration;
+    }
+
+    /// Returns the total time advanced since the clock was created.
+    pub fn elapsed(&self) -> Duration {
+        *self.elapsed.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.origin + self.elapsed()
+    }
+
+    fn sleep(&self, duration: Duration) -> Sleep {
+        let elapsed = self.elapsed.clone();
+        Box::pin(async move {
+            *elapsed.lock().unwrap_or_else(|e| e.into_inner()) += duration;
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manual_clock_advances() {
+        let clock = ManualClock::new();
+        let start = clock.now();
+        assert_eq!(clock.elapsed(), Duration::ZERO);
+
+        clock.advance(Duration::from_millis(250));
+        assert_eq!(clock.now() - start, Duration::from_millis(250));
+
+        // Sleeping a full hour takes no real time
+        let real_start = Instant::now();
+        smol::block_on(clock.sleep(Duration::from_secs(3600)));
+        assert!(real_start.elapsed() < Duration::from_secs(1));
+        assert_eq!(
+            clock.elapsed(),
+            Duration::from_millis(250) + Duration::from_secs(3600)
+        );
+    }
+
+    #[test]
+    fn test_manual_clock_sleep_advances_when_polled() {
+        let clock = ManualClock::new();
+
+        let sleep = clock.sleep(Duration::from_secs(1));
+        assert_eq!(clock.elapsed(), Duration::ZERO);
+        smol::block_on(sleep);
+        assert_eq!(clock.elapsed(), Duration::from_secs(1));
+
+        // The losing side of a race never moves time forward
+        smol::block_on(crate::runtime::race(
+            std::future::ready(()),
+            clock.sleep(Duration::from_secs(60)),
+        ));
+        assert_eq!(clock.elapsed(), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_system_clock_sleeps() {
+        let clock = SystemClock;
+        let start = clock.now();
+
+        smol::block_on(clock.sleep(Duration::from_millis(10)));
+        assert!(clock.now() - start >= Duration::from_millis(10));
+    }
+}
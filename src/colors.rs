@@ -9,15 +9,21 @@
 //! - Full RGBA color support with 8-bit components
 //! - HSL and hexadecimal color space conversions
 //! - Color manipulation (lighten, darken, saturate)
+//! - Perceptually uniform mixing in the Oklab color space
 //! - Theme management with semantic color groupings
 //! - Integration with owo-colors and ratatui
+//! - Fallback to the 16 named ANSI colors on minimal terminals
 //! - Thread-safe and zero-allocation color operations
 //!
 //! ## Main Components
 //!
 //! - [`Color`]: Core struct for color representation and manipulation
 //! - [`ThemeColorize`]: Trait for applying theme colors to text
+//! - [`ThemeStyle`]: Builder for composed owo-colors styles from theme colors
+//! - [`ColorAnimator`]: Frame-rate independent fades toward a target color
+//! - [`reduce_motion`]: Accessibility setting that turns animations into instant changes
 //! - [`theme`]: Module containing all theme-related color constants and functions
+//! - [`Theme`]: Runtime palette that can be loaded from and saved to TOML
 //!
 //! ## Examples
 //!
@@ -52,9 +58,134 @@
 //! println!("{}", "Warning alert".warning());
 //! ```
 
-use owo_colors::OwoColorize;
+#[cfg(feature = "serde")]
+use crate::error::{OxittyError, OxittyResult};
+use owo_colors::{AnsiColors, DynColors, OwoColorize, XtermColors};
 use ratatui::style::Color as RatatuiColor;
-use std::fmt::{self, Display};
+use std::{
+    cell::Cell,
+    env,
+    fmt::{self, Display},
+    ops::{Add, Mul, Sub},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        OnceLock,
+    },
+    time::Duration,
+};
+#[cfg(feature = "serde")]
+use std::{fs, path::Path};
+
+/// Converts a floating point channel value to `u8`.
+///
+/// Rounds to the nearest integer and clamps to `[0, 255]` so out-of-range
+/// intermediate results saturate instead of producing unrelated colors.
+#[inline]
+fn f32_to_u8(v: f32) -> u8 {
+    v.round().clamp(0.0, 255.0) as u8
+}
+
+/// Converts an 8-bit sRGB channel to linear light (0.0-1.0).
+#[inline]
+fn srgb_to_linear(c: u8) -> f32 {
+    let c = c as f32 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts a linear light channel (0.0-1.0) to 8-bit sRGB.
+#[inline]
+fn linear_to_srgb(c: f32) -> u8 {
+    let c = c.clamp(0.0, 1.0);
+    let v = if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    f32_to_u8(v * 255.0)
+}
+
+/// Converts 8-bit sRGB channels to Oklab `(L, a, b)`.
+///
+/// Uses the reference matrices from Björn Ottosson's Oklab definition.
+fn srgb_to_oklab(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+    let (r, g, b) = (srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b));
+
+    let l = 0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b;
+    let m = 0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b;
+    let s = 0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b;
+
+    let (l, m, s) = (l.cbrt(), m.cbrt(), s.cbrt());
+
+    (
+        0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+        1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+        0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+    )
+}
+
+/// Converts Oklab `(L, a, b)` to linear sRGB, without clamping to gamut.
+fn oklab_to_linear(l: f32, a: f32, b: f32) -> (f32, f32, f32) {
+    let l_ = l + 0.396_337_78 * a + 0.215_803_76 * b;
+    let m_ = l - 0.105_561_346 * a - 0.063_854_17 * b;
+    let s_ = l - 0.089_484_18 * a - 1.291_485_5 * b;
+
+    let (l, m, s) = (l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_);
+
+    (
+        4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+        -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+        -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+    )
+}
+
+/// Converts Oklab `(L, a, b)` back to 8-bit sRGB channels, clamping to gamut.
+fn oklab_to_srgb(l: f32, a: f32, b: f32) -> (u8, u8, u8) {
+    let (r, g, b) = oklab_to_linear(l, a, b);
+    (linear_to_srgb(r), linear_to_srgb(g), linear_to_srgb(b))
+}
+
+/// Returns the factor to scale Oklab `(a, b)` by so the color fits in sRGB.
+///
+/// `1.0` if the color already fits; otherwise the largest factor that fits,
+/// found by bisection. Lightness and hue are unaffected by the scaling.
+fn gamut_chroma_scale(l: f32, a: f32, b: f32) -> f32 {
+    const GAMUT_EPSILON: f32 = 1e-4;
+
+    let in_gamut = |scale: f32| {
+        let (r, g, b) = oklab_to_linear(l, a * scale, b * scale);
+        [r, g, b]
+            .iter()
+            .all(|c| (-GAMUT_EPSILON..=1.0 + GAMUT_EPSILON).contains(c))
+    };
+
+    if in_gamut(1.0) {
+        return 1.0;
+    }
+
+    let (mut low, mut high) = (0.0, 1.0);
+    for _ in 0..16 {
+        let mid = (low + high) / 2.0;
+        if in_gamut(mid) {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+/// Converts Oklab `(L, a, b)` to 8-bit sRGB, reducing chroma to fit the gamut.
+///
+/// Lightness and hue are kept; see [`gamut_chroma_scale`].
+fn oklab_to_srgb_in_gamut(l: f32, a: f32, b: f32) -> (u8, u8, u8) {
+    let scale = gamut_chroma_scale(l, a, b);
+    oklab_to_srgb(l, a * scale, b * scale)
+}
 
 /// Represents an RGBA color with 8-bit components for each channel.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -70,6 +201,19 @@ pub struct Color {
 }
 
 impl Color {
+    /// Opaque black.
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    /// Opaque white.
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+    /// Fully transparent black.
+    pub const TRANSPARENT: Color = Color::rgba(0, 0, 0, 0);
+    /// Opaque pure red.
+    pub const RED: Color = Color::rgb(255, 0, 0);
+    /// Opaque pure green.
+    pub const GREEN: Color = Color::rgb(0, 255, 0);
+    /// Opaque pure blue.
+    pub const BLUE: Color = Color::rgb(0, 0, 255);
+
     /// Creates a new RGB color with full opacity.
     ///
     /// # Arguments
@@ -113,8 +257,60 @@ impl Color {
         Self { r, g, b, a }
     }
 
+    /// Creates an opaque color from normalized floating-point components.
+    ///
+    /// Components are clamped to 0.0-1.0 and scaled to the nearest 8-bit
+    /// value; NaN maps to 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `r` - Red component (0.0-1.0)
+    /// * `g` - Green component (0.0-1.0)
+    /// * `b` - Blue component (0.0-1.0)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// let orange = Color::from_rgb_f32(1.0, 0.5, 0.0);
+    /// assert_eq!(orange.rgb_components(), (255, 128, 0));
+    /// ```
+    pub fn from_rgb_f32(r: f32, g: f32, b: f32) -> Self {
+        Self::from_rgba_f32(r, g, b, 1.0)
+    }
+
+    /// Creates a color from normalized floating-point components with alpha.
+    ///
+    /// Components are clamped to 0.0-1.0 and scaled to the nearest 8-bit
+    /// value; NaN maps to 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `r` - Red component (0.0-1.0)
+    /// * `g` - Green component (0.0-1.0)
+    /// * `b` - Blue component (0.0-1.0)
+    /// * `a` - Alpha component (0.0-1.0)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// let color = Color::from_rgba_f32(0.0, 0.0, 1.0, 0.5);
+    /// assert_eq!(color.rgba_components(), (0, 0, 255, 128));
+    /// ```
+    pub fn from_rgba_f32(r: f32, g: f32, b: f32, a: f32) -> Self {
+        let scale = |c: f32| f32_to_u8(c.clamp(0.0, 1.0) * 255.0);
+        Self::rgba(scale(r), scale(g), scale(b), scale(a))
+    }
+
     /// Creates a color from HSL values.
     ///
+    /// Hue wraps around the color wheel, so any value (including negative
+    /// ones) is normalized into `[0, 360)`: `-30.0` is treated as `330.0`
+    /// and `360.0` as `0.0`. Saturation and lightness are clamped to 0-100.
+    ///
     /// # Arguments
     ///
     /// * `h` - Hue in degrees (0-360)
@@ -128,14 +324,16 @@ impl Color {
     ///
     /// let red = Color::from_hsl(0.0, 100.0, 50.0);
     /// let pastel_blue = Color::from_hsl(210.0, 65.0, 75.0);
+    /// assert_eq!(Color::from_hsl(-30.0, 100.0, 50.0), Color::from_hsl(330.0, 100.0, 50.0));
     /// ```
     pub fn from_hsl(h: f32, s: f32, l: f32) -> Self {
-        let h = h % 360.0;
+        // rem_euclid keeps negative hues on the wheel, unlike `%`
+        let h = h.rem_euclid(360.0);
         let s = s.clamp(0.0, 100.0) / 100.0;
         let l = l.clamp(0.0, 100.0) / 100.0;
 
         if s == 0.0 {
-            let v = (l * 255.0) as u8;
+            let v = f32_to_u8(l * 255.0);
             return Self::rgb(v, v, v);
         }
 
@@ -149,13 +347,14 @@ impl Color {
             120..=179 => (0.0, c, x),
             180..=239 => (0.0, x, c),
             240..=299 => (x, 0.0, c),
+            // 300..360, the only remaining range after normalization
             _ => (c, 0.0, x),
         };
 
         Self::rgb(
-            ((r + m) * 255.0) as u8,
-            ((g + m) * 255.0) as u8,
-            ((b + m) * 255.0) as u8,
+            f32_to_u8((r + m) * 255.0),
+            f32_to_u8((g + m) * 255.0),
+            f32_to_u8((b + m) * 255.0),
         )
     }
 
@@ -212,10 +411,57 @@ impl Color {
         (h, s * 100.0, l * 100.0)
     }
 
+    /// Returns the shortest angular distance between two hues in degrees.
+    ///
+    /// Hues come from [`to_hsl`](Self::to_hsl), so the result is in 0-180
+    /// regardless of which way round the wheel is shorter. Grays have no
+    /// hue and are treated as hue 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The color to compare against
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// let red = Color::rgb(255, 0, 0);
+    /// let magenta = Color::rgb(255, 0, 255);
+    /// assert_eq!(red.hue_diff(&magenta), 60.0);
+    /// ```
+    pub fn hue_diff(&self, other: &Color) -> f32 {
+        let (h1, _, _) = self.to_hsl();
+        let (h2, _, _) = other.to_hsl();
+        let diff = (h1 - h2).abs() % 360.0;
+        diff.min(360.0 - diff)
+    }
+
+    /// Returns `true` if the hues sit opposite each other on the color wheel.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The color to compare against
+    /// * `tolerance` - Allowed deviation from 180 degrees
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// let red = Color::rgb(255, 0, 0);
+    /// assert!(red.is_complementary(&Color::rgb(0, 255, 255), 1.0));
+    /// assert!(!red.is_complementary(&Color::rgb(0, 255, 0), 10.0));
+    /// ```
+    pub fn is_complementary(&self, other: &Color, tolerance: f32) -> bool {
+        180.0 - self.hue_diff(other) <= tolerance
+    }
+
     /// Creates a color from a hexadecimal string.
     ///
-    /// Supports both RGB (#RRGGBB) and RGBA (#RRGGBBAA) formats.
-    /// The '#' prefix is optional.
+    /// Supports RGB (#RRGGBB) and RGBA (#RRGGBBAA) formats, as well as the
+    /// short forms #RGB and #RGBA where each digit is repeated (`#f80` is
+    /// `#ff8800`). The '#' prefix is optional.
     ///
     /// # Arguments
     ///
@@ -232,27 +478,79 @@ impl Color {
     ///
     /// let red = Color::from_hex("#FF0000").unwrap();
     /// let transparent_blue = Color::from_hex("0000FF80").unwrap();
+    /// assert_eq!(Color::from_hex("#fff"), Some(Color::rgb(255, 255, 255)));
     /// assert!(Color::from_hex("invalid").is_none());
     /// ```
     pub fn from_hex(hex: &str) -> Option<Self> {
         let hex = hex.trim_start_matches('#');
+        if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
 
-        match hex.len() {
-            6 => {
-                let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
-                let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
-                let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
-                Some(Self::rgb(r, g, b))
-            }
-            8 => {
-                let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
-                let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
-                let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
-                let a = u8::from_str_radix(&hex[6..8], 16).ok()?;
-                Some(Self::rgba(r, g, b, a))
+        let width = match hex.len() {
+            3 | 4 => 1,
+            6 | 8 => 2,
+            _ => return None,
+        };
+        // A single digit stands for itself repeated, so f is ff
+        let scale = if width == 1 { 17 } else { 1 };
+        let channel = |i: usize| {
+            let digits = &hex[i * width..(i + 1) * width];
+            Some(u8::from_str_radix(digits, 16).ok()? * scale)
+        };
+
+        let a = if hex.len() == 4 * width {
+            channel(3)?
+        } else {
+            255
+        };
+        Some(Self::rgba(channel(0)?, channel(1)?, channel(2)?, a))
+    }
+
+    /// Creates a color from an X11 `rgb:` color specification.
+    ///
+    /// This is the format terminals use when answering OSC 10/11 color
+    /// queries, such as `rgb:ffff/8080/0000`. Each channel has one to four
+    /// hex digits and is scaled from its own range to 8 bits, so `f`, `ff`
+    /// and `ffff` all mean full intensity. The `rgb:` prefix is matched
+    /// case-insensitively.
+    ///
+    /// # Arguments
+    ///
+    /// * `spec` - X11 color specification
+    ///
+    /// # Returns
+    ///
+    /// `Some(Color)` if parsing succeeds, `None` if the string is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// assert_eq!(Color::from_x11("rgb:ffff/0000/0000"), Some(Color::rgb(255, 0, 0)));
+    /// assert_eq!(Color::from_x11("rgb:8/80/8080"), Some(Color::rgb(136, 128, 128)));
+    /// assert!(Color::from_x11("#ff0000").is_none());
+    /// ```
+    pub fn from_x11(spec: &str) -> Option<Self> {
+        let channels = spec
+            .get(..4)
+            .filter(|prefix| prefix.eq_ignore_ascii_case("rgb:"))
+            .map(|_| &spec[4..])?;
+
+        let mut parts = channels.split('/');
+        let mut channel = || {
+            let digits = parts.next()?;
+            if !(1..=4).contains(&digits.len()) || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return None;
             }
-            _ => None,
-        }
+            let value = u32::from_str_radix(digits, 16).ok()?;
+            let max = (1 << (4 * digits.len())) - 1;
+            Some(((value * 255 + max / 2) / max) as u8)
+        };
+
+        let (r, g, b) = (channel()?, channel()?, channel()?);
+        parts.next().is_none().then(|| Self::rgb(r, g, b))
     }
 
     /// Converts the color to a hexadecimal string.
@@ -279,6 +577,63 @@ impl Color {
         }
     }
 
+    /// Converts the color to an 8-digit hexadecimal string.
+    ///
+    /// Unlike [`to_hex`](Self::to_hex), the alpha byte is always emitted
+    /// (#RRGGBBAA), which suits formats that require a fixed width.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// let color = Color::rgb(255, 0, 0);
+    /// assert_eq!(color.to_hex_rgba(), "#ff0000ff");
+    /// ```
+    pub fn to_hex_rgba(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}{:02x}", self.r, self.g, self.b, self.a)
+    }
+
+    /// Converts the color to a CSS `rgb()` string, ignoring alpha.
+    ///
+    /// Unlike [`Display`](fmt::Display), the format does not change with
+    /// the alpha value, which makes it safe for machine consumption.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// let color = Color::rgba(255, 128, 0, 64);
+    /// assert_eq!(color.to_css_rgb(), "rgb(255, 128, 0)");
+    /// ```
+    pub fn to_css_rgb(&self) -> String {
+        format!("rgb({}, {}, {})", self.r, self.g, self.b)
+    }
+
+    /// Converts the color to a CSS `rgba()` string.
+    ///
+    /// Alpha is always emitted as a 0.0-1.0 value with exactly three
+    /// decimal places, including for opaque colors.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// assert_eq!(Color::rgba(255, 128, 0, 128).to_css_rgba(), "rgba(255, 128, 0, 0.502)");
+    /// assert_eq!(Color::rgb(255, 128, 0).to_css_rgba(), "rgba(255, 128, 0, 1.000)");
+    /// ```
+    pub fn to_css_rgba(&self) -> String {
+        format!(
+            "rgba({}, {}, {}, {:.3})",
+            self.r,
+            self.g,
+            self.b,
+            self.a as f32 / 255.0
+        )
+    }
+
     /// Returns a new color with modified alpha value.
     ///
     /// # Arguments
@@ -298,356 +653,2224 @@ impl Color {
         Self { a: alpha, ..*self }
     }
 
-    /// Lightens the color by a percentage.
+    /// Returns a new color with modified RGB components, keeping alpha.
+    ///
+    /// The counterpart of [`with_alpha`](Self::with_alpha), for changing
+    /// the color of a translucent overlay without touching its opacity.
     ///
     /// # Arguments
     ///
-    /// * `amount` - Percentage to lighten (0-100)
+    /// * `r` - New red component (0-255)
+    /// * `g` - New green component (0-255)
+    /// * `b` - New blue component (0-255)
     ///
     /// # Examples
     ///
     /// ```rust
     /// use oxitty::colors::Color;
     ///
-    /// let color = Color::rgb(100, 100, 100);
-    /// let lighter = color.lighten(20.0);
-    /// let (_, _, l1) = color.to_hsl();
-    /// let (_, _, l2) = lighter.to_hsl();
-    /// assert!(l2 > l1);
+    /// let overlay = Color::rgba(255, 0, 0, 64);
+    /// assert_eq!(overlay.with_rgb(0, 0, 255), Color::rgba(0, 0, 255, 64));
     /// ```
-    pub fn lighten(&self, amount: f32) -> Self {
-        let (h, s, l) = self.to_hsl();
-        Self::from_hsl(h, s, (l + amount).min(100.0))
+    pub fn with_rgb(&self, r: u8, g: u8, b: u8) -> Self {
+        Self::rgba(r, g, b, self.a)
     }
 
-    /// Darkens the color by a percentage.
+    /// Returns the color with its RGB channels rearranged, keeping alpha.
+    ///
+    /// Entry `i` of `order` names the channel whose value becomes the
+    /// `i`-th channel of the result, so `[B, G, R]` turns RGB into BGR.
+    /// Channels may repeat, e.g. `[G, G, G]` yields the green channel as
+    /// a gray.
     ///
     /// # Arguments
     ///
-    /// * `amount` - Percentage to darken (0-100)
+    /// * `order` - Source channel for the new red, green and blue
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::{Channel, Color};
+    ///
+    /// let color = Color::rgba(1, 2, 3, 128);
+    /// assert_eq!(
+    ///     color.swizzle([Channel::G, Channel::R, Channel::B]),
+    ///     Color::rgba(2, 1, 3, 128)
+    /// );
+    /// ```
+    pub fn swizzle(&self, order: [Channel; 3]) -> Self {
+        let [r, g, b] = order.map(|channel| match channel {
+            Channel::R => self.r,
+            Channel::G => self.g,
+            Channel::B => self.b,
+        });
+        Self::rgba(r, g, b, self.a)
+    }
+
+    /// Returns the alpha component normalized to 0.0-1.0.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use oxitty::colors::Color;
     ///
-    /// let color = Color::rgb(200, 200, 200);
-    /// let darker = color.darken(30.0);
-    /// let (_, _, l1) = color.to_hsl();
-    /// let (_, _, l2) = darker.to_hsl();
-    /// assert!(l2 < l1);
+    /// assert_eq!(Color::rgb(255, 0, 0).alpha_f32(), 1.0);
+    /// assert_eq!(Color::TRANSPARENT.alpha_f32(), 0.0);
     /// ```
-    pub fn darken(&self, amount: f32) -> Self {
-        let (h, s, l) = self.to_hsl();
-        Self::from_hsl(h, s, (l - amount).max(0.0))
+    pub fn alpha_f32(&self) -> f32 {
+        self.a as f32 / 255.0
     }
 
-    /// Adjusts the saturation by a percentage.
+    /// Creates a new color with the given opacity.
+    ///
+    /// The float counterpart of [`with_alpha`](Self::with_alpha), convenient
+    /// when tweening opacity. `alpha` is clamped to 0.0-1.0 and scaled to the
+    /// nearest 8-bit value; NaN maps to 0.
     ///
     /// # Arguments
     ///
-    /// * `amount` - Percentage to adjust (-100 to 100)
+    /// * `alpha` - New opacity (0.0-1.0)
     ///
     /// # Examples
     ///
     /// ```rust
     /// use oxitty::colors::Color;
     ///
-    /// let color = Color::rgb(200, 100, 100);
-    /// let more_saturated = color.saturate(20.0);
-    /// let less_saturated = color.saturate(-20.0);
+    /// let faded = Color::rgb(255, 0, 0).with_alpha_f32(0.25);
+    /// assert_eq!(faded.rgba_components(), (255, 0, 0, 64));
     /// ```
-    pub fn saturate(&self, amount: f32) -> Self {
-        let (h, s, l) = self.to_hsl();
-        Self::from_hsl(h, (s + amount).clamp(0.0, 100.0), l)
+    pub fn with_alpha_f32(&self, alpha: f32) -> Self {
+        self.with_alpha(f32_to_u8(alpha.clamp(0.0, 1.0) * 255.0))
     }
 
-    /// Converts to owo-colors RGB type.
+    /// Converts a straight-alpha color to premultiplied alpha.
+    ///
+    /// Each RGB channel is scaled by `alpha / 255`; alpha itself is kept.
+    /// Repeated blending in straight alpha accumulates rounding error, so
+    /// compositing operations such as an `over` blend should convert both
+    /// operands with this method, blend, and convert back with
+    /// [`unpremultiply`](Self::unpremultiply).
     ///
     /// # Examples
     ///
     /// ```rust
     /// use oxitty::colors::Color;
     ///
-    /// let color = Color::rgb(255, 0, 0);
-    /// let owo_color = color.to_owo_rgb();
+    /// let half_white = Color::rgba(255, 255, 255, 128);
+    /// assert_eq!(half_white.premultiply(), Color::rgba(128, 128, 128, 128));
     /// ```
-    pub fn to_owo_rgb(&self) -> owo_colors::Rgb {
-        owo_colors::Rgb(self.r, self.g, self.b)
+    pub fn premultiply(&self) -> Self {
+        let alpha = self.a as f32 / 255.0;
+        let scale = |c: u8| f32_to_u8(c as f32 * alpha);
+
+        Self {
+            r: scale(self.r),
+            g: scale(self.g),
+            b: scale(self.b),
+            a: self.a,
+        }
     }
 
-    /// Converts to ratatui Color type.
+    /// Converts a premultiplied-alpha color back to straight alpha.
+    ///
+    /// Each RGB channel is divided by `alpha / 255` and clamped to 0-255.
+    /// A fully transparent color carries no color information, so it yields
+    /// [`Color::TRANSPARENT`] instead of dividing by zero.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use oxitty::colors::Color;
     ///
-    /// let color = Color::rgb(255, 0, 0);
-    /// let ratatui_color = color.to_ratatui();
+    /// let premultiplied = Color::rgba(128, 128, 128, 128);
+    /// assert_eq!(premultiplied.unpremultiply(), Color::rgba(255, 255, 255, 128));
+    /// assert_eq!(Color::rgba(10, 20, 30, 0).unpremultiply(), Color::TRANSPARENT);
     /// ```
-    pub fn to_ratatui(&self) -> RatatuiColor {
-        RatatuiColor::Rgb(self.r, self.g, self.b)
+    pub fn unpremultiply(&self) -> Self {
+        if self.a == 0 {
+            return Self::TRANSPARENT;
+        }
+
+        let alpha = self.a as f32 / 255.0;
+        let scale = |c: u8| f32_to_u8(c as f32 / alpha);
+
+        Self {
+            r: scale(self.r),
+            g: scale(self.g),
+            b: scale(self.b),
+            a: self.a,
+        }
     }
 
-    /// Returns the RGB components as a tuple.
+    /// Composites this color over a background ("source over").
+    ///
+    /// The result is what a translucent color looks like when drawn on
+    /// `background`, which is how the `_SUBTLE` theme colors are meant to be
+    /// used on terminals that cannot blend. Blending happens in
+    /// premultiplied alpha at full precision; an opaque background gives an
+    /// opaque result.
+    ///
+    /// # Arguments
+    ///
+    /// * `background` - Color underneath this one
     ///
     /// # Examples
     ///
     /// ```rust
     /// use oxitty::colors::Color;
     ///
-    /// let color = Color::rgb(255, 128, 0);
-    /// assert_eq!(color.rgb_components(), (255, 128, 0));
+    /// let half_white = Color::rgba(255, 255, 255, 128);
+    /// assert_eq!(half_white.over(&Color::BLACK), Color::rgb(128, 128, 128));
+    ///
+    /// // Opaque colors hide the background entirely
+    /// let red = Color::rgb(255, 0, 0);
+    /// assert_eq!(red.over(&Color::WHITE), red);
     /// ```
-    pub fn rgb_components(&self) -> (u8, u8, u8) {
-        (self.r, self.g, self.b)
+    pub fn over(&self, background: &Color) -> Self {
+        let (sr, sg, sb, sa) = self.to_rgba_f32();
+        let (br, bg, bb, ba) = background.to_rgba_f32();
+
+        let alpha = sa + ba * (1.0 - sa);
+        if alpha <= 0.0 {
+            return Self::TRANSPARENT;
+        }
+        let channel = |s: f32, b: f32| (s * sa + b * ba * (1.0 - sa)) / alpha;
+
+        Self::from_rgba_f32(channel(sr, br), channel(sg, bg), channel(sb, bb), alpha)
     }
 
-    /// Returns the RGBA components as a tuple.
+    /// Returns a new color with the hue set to an absolute value.
+    ///
+    /// Saturation, lightness and alpha are preserved.
+    ///
+    /// # Arguments
+    ///
+    /// * `hue` - New hue in degrees, wrapped into 0-360
     ///
     /// # Examples
     ///
     /// ```rust
     /// use oxitty::colors::Color;
     ///
-    /// let color = Color::rgba(255, 128, 0, 128);
-    /// assert_eq!(color.rgba_components(), (255, 128, 0, 128));
+    /// let red = Color::rgb(255, 0, 0);
+    /// let blue = red.with_hue(240.0);
+    /// assert_eq!(blue.rgb_components(), (0, 0, 255));
     /// ```
-    pub fn rgba_components(&self) -> (u8, u8, u8, u8) {
-        (self.r, self.g, self.b, self.a)
+    pub fn with_hue(&self, hue: f32) -> Self {
+        let (_, s, l) = self.to_hsl();
+        Self::from_hsl(hue, s, l).with_alpha(self.a)
     }
 
-    /// Mixes with another color by a specified amount.
+    /// Returns a new color with the saturation set to an absolute value.
+    ///
+    /// Hue, lightness and alpha are preserved.
     ///
     /// # Arguments
     ///
-    /// * `other` - The color to mix with
-    /// * `amount` - Mix ratio (0.0-1.0), where 0.0 is this color and 1.0 is the other color
+    /// * `saturation` - New saturation percentage, clamped to 0-100
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use oxitty::Color;
+    /// use oxitty::colors::Color;
     ///
-    /// let red = Color::rgb(255, 0, 0);
-    /// let blue = Color::rgb(0, 0, 255);
-    /// let purple = red.mix(&blue, 0.5);
-    /// assert_eq!(purple.rgb_components(), (127, 0, 127));
+    /// let gray = Color::rgb(200, 100, 100).with_saturation(0.0);
+    /// let (r, g, b) = gray.rgb_components();
+    /// assert!(r == g && g == b);
     /// ```
-    pub fn mix(&self, other: &Color, amount: f32) -> Self {
-        let amount = amount.clamp(0.0, 1.0);
-        // Use floor instead of round for consistent results at 0.5
-        let r = ((self.r as f32 * (1.0 - amount) + other.r as f32 * amount).floor()) as u8;
-        let g = ((self.g as f32 * (1.0 - amount) + other.g as f32 * amount).floor()) as u8;
-        let b = ((self.b as f32 * (1.0 - amount) + other.b as f32 * amount).floor()) as u8;
-        let a = ((self.a as f32 * (1.0 - amount) + other.a as f32 * amount).floor()) as u8;
-        Self::rgba(r, g, b, a)
+    pub fn with_saturation(&self, saturation: f32) -> Self {
+        let (h, _, l) = self.to_hsl();
+        Self::from_hsl(h, saturation.clamp(0.0, 100.0), l).with_alpha(self.a)
     }
 
-    /// Returns the inverse of the color.
+    /// Returns a new color with the lightness set to an absolute value.
+    ///
+    /// Hue, saturation and alpha are preserved.
+    ///
+    /// # Arguments
+    ///
+    /// * `lightness` - New lightness percentage, clamped to 0-100
     ///
     /// # Examples
     ///
     /// ```rust
     /// use oxitty::colors::Color;
     ///
-    /// let white = Color::rgb(255, 255, 255);
-    /// let black = white.invert();
+    /// let black = Color::rgb(0, 228, 154).with_lightness(0.0);
     /// assert_eq!(black.rgb_components(), (0, 0, 0));
     /// ```
-    pub fn invert(&self) -> Self {
-        Self::rgba(255 - self.r, 255 - self.g, 255 - self.b, self.a)
+    pub fn with_lightness(&self, lightness: f32) -> Self {
+        let (h, s, _) = self.to_hsl();
+        Self::from_hsl(h, s, lightness.clamp(0.0, 100.0)).with_alpha(self.a)
     }
-}
 
-// Implement conversion to owo-colors RGB
-impl From<Color> for owo_colors::Rgb {
-    /// Converts the color to owo-colors RGB format.
-    fn from(color: Color) -> Self {
-        color.to_owo_rgb()
+    /// Lightens the color by a percentage.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Percentage to lighten (0-100)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// let color = Color::rgb(100, 100, 100);
+    /// let lighter = color.lighten(20.0);
+    /// let (_, _, l1) = color.to_hsl();
+    /// let (_, _, l2) = lighter.to_hsl();
+    /// assert!(l2 > l1);
+    /// ```
+    pub fn lighten(&self, amount: f32) -> Self {
+        let (h, s, l) = self.to_hsl();
+        Self::from_hsl(h, s, (l + amount).min(100.0))
     }
-}
 
-// Implement conversion to ratatui Color
-impl From<Color> for RatatuiColor {
-    /// Converts the color to ratatui Color format.
-    fn from(color: Color) -> Self {
-        color.to_ratatui()
+    /// Darkens the color by a percentage.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Percentage to darken (0-100)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// let color = Color::rgb(200, 200, 200);
+    /// let darker = color.darken(30.0);
+    /// let (_, _, l1) = color.to_hsl();
+    /// let (_, _, l2) = darker.to_hsl();
+    /// assert!(l2 < l1);
+    /// ```
+    pub fn darken(&self, amount: f32) -> Self {
+        let (h, s, l) = self.to_hsl();
+        Self::from_hsl(h, s, (l - amount).max(0.0))
     }
-}
 
-impl fmt::Display for Color {
-    /// Formats the color as a string representation.
+    /// Lightens the color by a percentage of perceptual lightness.
     ///
-    /// Returns either "rgb(r, g, b)" or "rgba(r, g, b, a)" format.
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.a == 255 {
-            write!(f, "rgb({}, {}, {})", self.r, self.g, self.b)
-        } else {
-            // Format alpha to exactly one decimal place
-            let alpha = (self.a as f32 / 255.0 * 10.0).round() / 10.0;
-            write!(f, "rgba({}, {}, {}, {})", self.r, self.g, self.b, alpha)
-        }
+    /// Unlike [`lighten`](Self::lighten), which works on HSL lightness, this
+    /// raises the Oklab L channel, so the same amount looks like the same
+    /// step regardless of hue. When the result would leave the sRGB gamut,
+    /// chroma is reduced until it fits so the lightness step is kept.
+    /// Alpha is preserved.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Percentage points of Oklab lightness to add (0-100)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// let color = Color::rgb(40, 40, 120);
+    /// assert!(color.lighten_perceptual(20.0).relative_luminance() > color.relative_luminance());
+    /// ```
+    pub fn lighten_perceptual(&self, amount: f32) -> Self {
+        self.shift_oklab_lightness(amount / 100.0)
     }
-}
 
-/// Extension trait for applying theme colors to strings with owo-colors.
-///
-/// This trait provides convenient methods for applying semantic theme colors to text.
-/// It is automatically implemented for all types that implement `OwoColorize`.
-///
-/// # Examples
-///
-/// ```rust
-/// use oxitty::colors::ThemeColorize;
-///
-/// println!("{}", "Important message".primary());
-/// println!("{}", "Warning alert".warning());
-/// println!("{}", "Error occurred".error());
-/// ```
-pub trait ThemeColorize: OwoColorize {
-    /// Apply primary text color.
+    /// Darkens the color by a percentage of perceptual lightness.
+    ///
+    /// The perceptual counterpart of [`darken`](Self::darken); see
+    /// [`lighten_perceptual`](Self::lighten_perceptual).
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Percentage points of Oklab lightness to remove (0-100)
+    pub fn darken_perceptual(&self, amount: f32) -> Self {
+        self.shift_oklab_lightness(-amount / 100.0)
+    }
+
+    /// Creates a color from OKLCH values.
+    ///
+    /// OKLCH is the polar form of Oklab: lightness, chroma (distance from
+    /// gray) and hue angle. Equal steps look equally large to the eye, which
+    /// makes it a good space for specifying palettes. Colors outside the
+    /// sRGB gamut keep their lightness and hue while chroma is reduced
+    /// toward gray until they fit, instead of clipping each channel.
+    ///
+    /// # Arguments
+    ///
+    /// * `l` - Lightness (0.0-1.0)
+    /// * `c` - Chroma (0.0 and up, about 0.37 at most within sRGB)
+    /// * `h` - Hue in degrees, wrapped into 0-360
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use oxitty::colors::ThemeColorize;
+    /// use oxitty::colors::Color;
     ///
-    /// println!("{}", "Main content".primary());
+    /// let white = Color::from_oklch(1.0, 0.0, 0.0);
+    /// assert_eq!(white.rgb_components(), (255, 255, 255));
+    ///
+    /// let red = Color::from_oklch(0.628, 0.2577, 29.23);
+    /// assert!(red.approx_eq(&Color::rgb(255, 0, 0), 2));
     /// ```
-    #[inline]
-    fn primary(self) -> impl fmt::Display
-    where
-        Self: Sized + Display,
-    {
-        let (r, g, b) = theme::text::PRIMARY.rgb_components();
-        format!("{}", self.truecolor(r, g, b))
+    pub fn from_oklch(l: f32, c: f32, h: f32) -> Self {
+        let (l, c, h) = Self::clamp_to_gamut(l, c, h);
+        let hue = h.to_radians();
+        let (r, g, b) = oklab_to_srgb(l, c * hue.cos(), c * hue.sin());
+        Self::rgb(r, g, b)
     }
 
-    /// Apply secondary text color.
+    /// Maps OKLCH values onto the nearest color sRGB can show.
+    ///
+    /// Lightness is clamped to 0.0-1.0 and hue wrapped into 0-360; both are
+    /// then held fixed while chroma is reduced until the color fits, so
+    /// vivid inputs fade toward gray instead of shifting hue the way
+    /// clipping each RGB channel does. In-gamut values are returned as is.
+    /// [`from_oklch`](Self::from_oklch) maps its input this way.
+    ///
+    /// # Arguments
+    ///
+    /// * `l` - Lightness
+    /// * `c` - Chroma, negative values are treated as `0.0`
+    /// * `h` - Hue in degrees
+    ///
+    /// # Returns
+    ///
+    /// A tuple of (lightness 0.0-1.0, chroma, hue 0-360) inside the gamut
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use oxitty::colors::ThemeColorize;
+    /// use oxitty::colors::Color;
     ///
-    /// println!("{}", "Additional info".secondary());
+    /// // Far more green than a screen can show at this lightness
+    /// let (l, c, h) = Color::clamp_to_gamut(0.8, 0.5, 140.0);
+    /// assert_eq!((l, h), (0.8, 140.0));
+    /// assert!(c < 0.5);
+    ///
+    /// assert_eq!(Color::clamp_to_gamut(0.7, 0.1, 560.0), (0.7, 0.1, 200.0));
     /// ```
-    #[inline]
-    fn secondary(self) -> impl fmt::Display
-    where
-        Self: Sized + Display,
-    {
-        let (r, g, b) = theme::text::SECONDARY.rgb_components();
-        format!("{}", self.truecolor(r, g, b))
+    pub fn clamp_to_gamut(l: f32, c: f32, h: f32) -> (f32, f32, f32) {
+        let l = l.clamp(0.0, 1.0);
+        let c = c.max(0.0);
+        let h = h.rem_euclid(360.0);
+
+        let hue = h.to_radians();
+        let scale = gamut_chroma_scale(l, c * hue.cos(), c * hue.sin());
+        (l, c * scale, h)
     }
 
-    /// Apply info status color.
+    /// Converts the color to OKLCH values.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of (lightness 0.0-1.0, chroma, hue 0-360). Grays have a
+    /// chroma of about zero and report a hue of 0. Alpha is ignored.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use oxitty::colors::ThemeColorize;
+    /// use oxitty::colors::Color;
     ///
-    /// println!("{}", "Information".info());
+    /// let (l, c, _) = Color::rgb(128, 128, 128).to_oklch();
+    /// assert!(l > 0.5 && l < 0.7);
+    /// assert!(c < 1e-3);
     /// ```
-    #[inline]
-    fn info(self) -> impl fmt::Display
-    where
-        Self: Sized + Display,
-    {
-        let (r, g, b) = theme::status::INFO.rgb_components();
-        format!("{}", self.truecolor(r, g, b))
+    pub fn to_oklch(&self) -> (f32, f32, f32) {
+        const ACHROMATIC: f32 = 1e-4;
+        let (l, a, b) = srgb_to_oklab(self.r, self.g, self.b);
+        let c = a.hypot(b);
+        let h = if c < ACHROMATIC {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        };
+
+        (l, c, h)
     }
 
-    /// Apply warning status color.
+    /// Adds `delta` to the Oklab L channel, keeping hue and alpha.
+    ///
+    /// Chroma is kept too unless the result falls outside the sRGB gamut;
+    /// see [`oklab_to_srgb_in_gamut`].
+    fn shift_oklab_lightness(&self, delta: f32) -> Self {
+        let (l, a, b) = srgb_to_oklab(self.r, self.g, self.b);
+        let l = (l + delta).clamp(0.0, 1.0);
+
+        let (r, g, b) = oklab_to_srgb_in_gamut(l, a, b);
+        Self::rgba(r, g, b, self.a)
+    }
+
+    /// Adjusts the saturation by a percentage.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Percentage to adjust (-100 to 100)
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use oxitty::colors::ThemeColorize;
+    /// use oxitty::colors::Color;
     ///
-    /// println!("{}", "Warning message".warning());
+    /// let color = Color::rgb(200, 100, 100);
+    /// let more_saturated = color.saturate(20.0);
+    /// let less_saturated = color.saturate(-20.0);
     /// ```
-    #[inline]
-    fn warning(self) -> String
-    where
-        Self: Sized + Display,
-    {
-        let (r, g, b) = theme::status::WARNING.rgb_components();
-        format!("{}", self.truecolor(r, g, b))
+    pub fn saturate(&self, amount: f32) -> Self {
+        let (h, s, l) = self.to_hsl();
+        Self::from_hsl(h, (s + amount).clamp(0.0, 100.0), l)
     }
 
-    /// Apply error status color.
+    /// Adjusts the HSV saturation by a percentage.
+    ///
+    /// Unlike [`saturate`](Self::saturate), which works in HSL, the
+    /// brightest channel (the HSV value) and the hue stay fixed and only the
+    /// other channels move. Near-white colors therefore gain a tint instead
+    /// of shifting in brightness, which makes this the better choice for
+    /// emphasis effects. Gray has no hue and is returned unchanged. Alpha is
+    /// preserved.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Percentage points to add to the saturation (-100 to 100)
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use oxitty::colors::ThemeColorize;
+    /// use oxitty::colors::Color;
     ///
-    /// println!("{}", "Error message".error());
+    /// let pale = Color::rgb(240, 220, 220);
+    /// assert_eq!(pale.saturate_hsv(100.0), Color::rgb(240, 0, 0));
     /// ```
-    #[inline]
-    fn error(self) -> impl fmt::Display
-    where
-        Self: Sized + Display,
-    {
-        let (r, g, b) = theme::status::ERROR.rgb_components();
-        format!("{}", self.truecolor(r, g, b))
+    pub fn saturate_hsv(&self, amount: f32) -> Self {
+        let channels = [self.r, self.g, self.b].map(f32::from);
+        let value = channels[0].max(channels[1]).max(channels[2]);
+        let min = channels[0].min(channels[1]).min(channels[2]);
+        if value == min {
+            return *self;
+        }
+
+        // Scaling each channel's distance from the value keeps the hue
+        let saturation = (value - min) / value;
+        let target = (saturation + amount / 100.0).clamp(0.0, 1.0);
+        let scale = target / saturation;
+        let [r, g, b] = channels.map(|c| f32_to_u8(value - (value - c) * scale));
+        Self::rgba(r, g, b, self.a)
     }
 
-    /// Apply v01d green color.
+    /// Reduces the HSV saturation by a percentage.
+    ///
+    /// Shorthand for [`saturate_hsv`](Self::saturate_hsv) with a negated
+    /// amount; at 100 the result is a gray as bright as the original's
+    /// brightest channel.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Percentage points to remove from the saturation (0 to 100)
+    pub fn desaturate_hsv(&self, amount: f32) -> Self {
+        self.saturate_hsv(-amount)
+    }
+
+    /// Converts to owo-colors RGB type.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use oxitty::colors::ThemeColorize;
+    /// use oxitty::colors::Color;
     ///
-    /// println!("{}", "Success".void_green());
+    /// let color = Color::rgb(255, 0, 0);
+    /// let owo_color = color.to_owo_rgb();
     /// ```
-    #[inline]
-    fn void_green(self) -> impl fmt::Display
-    where
-        Self: Sized + Display,
-    {
-        let (r, g, b) = theme::void::GREEN.rgb_components();
-        format!("{}", self.truecolor(r, g, b))
+    pub fn to_owo_rgb(&self) -> owo_colors::Rgb {
+        owo_colors::Rgb(self.r, self.g, self.b)
     }
 
-    /// Apply v01d purple color.
+    /// Converts to ratatui Color type.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use oxitty::colors::ThemeColorize;
+    /// use oxitty::colors::Color;
     ///
-    /// println!("{}", "Accent".void_purple());
+    /// let color = Color::rgb(255, 0, 0);
+    /// let ratatui_color = color.to_ratatui();
     /// ```
-    #[inline]
-    fn void_purple(self) -> impl fmt::Display
-    where
-        Self: Sized + Display,
-    {
-        let (r, g, b) = theme::void::PURPLE.rgb_components();
-        format!("{}", self.truecolor(r, g, b))
+    pub fn to_ratatui(&self) -> RatatuiColor {
+        RatatuiColor::Rgb(self.r, self.g, self.b)
     }
-}
 
-// Implement ThemeColorize for all types that implement OwoColorize
-impl<T: OwoColorize + Display> ThemeColorize for T {}
+    /// Returns the nearest of the 16 named ANSI colors.
+    ///
+    /// Distance is measured in Oklab against the standard xterm values of
+    /// the ANSI palette. Alpha is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    /// use ratatui::style::Color as RatatuiColor;
+    ///
+    /// assert_eq!(Color::rgb(250, 250, 250).to_ansi16(), RatatuiColor::White);
+    /// assert_eq!(Color::rgb(10, 10, 20).to_ansi16(), RatatuiColor::Black);
+    /// ```
+    pub fn to_ansi16(&self) -> RatatuiColor {
+        let (l, a, b) = srgb_to_oklab(self.r, self.g, self.b);
+        let distance = |color: &Color| {
+            let (l2, a2, b2) = srgb_to_oklab(color.r, color.g, color.b);
+            (l - l2).powi(2) + (a - a2).powi(2) + (b - b2).powi(2)
+        };
 
-/// Theme color constants and semantic color groupings.
-pub mod theme {
-    use super::Color;
+        ANSI16_PALETTE
+            .iter()
+            .min_by(|x, y| distance(&x.1).total_cmp(&distance(&y.1)))
+            .map(|(named, _)| *named)
+            .unwrap_or(RatatuiColor::Reset)
+    }
 
-    /// Background elevation hierarchy.
-    pub mod background {
-        use super::Color;
+    /// Returns the xterm grayscale ramp entry closest in luminance.
+    ///
+    /// The ramp spans palette indices 232 (darkest) to 255 (lightest). The
+    /// color's [`relative_luminance`](Self::relative_luminance) is encoded
+    /// back to an sRGB gray and matched to the nearest ramp step, so colors
+    /// keep their relative brightness on monochrome displays. Alpha is
+    /// ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// assert_eq!(Color::BLACK.to_gray_level(), 232);
+    /// assert_eq!(Color::WHITE.to_gray_level(), 255);
+    /// assert_eq!(Color::from_ansi256(240).to_gray_level(), 240);
+    /// ```
+    pub fn to_gray_level(&self) -> u8 {
+        let gray = linear_to_srgb(self.relative_luminance()) as f32;
+        // Ramp entry `n` is the gray 8 + 10 * n
+        let step = ((gray - 8.0) / 10.0).round().clamp(0.0, 23.0) as u8;
+        232 + step
+    }
 
-        /// Base background color.
-        pub const BASE: Color = Color::rgb(15, 18, 20);
-        /// First level elevation color.
+    /// Converts to a ratatui color the terminal can display.
+    ///
+    /// # Arguments
+    ///
+    /// * `support` - Colors the terminal supports, usually [`color_support`]
+    ///
+    /// # Returns
+    ///
+    /// [`to_ratatui`](Self::to_ratatui) on true-color terminals,
+    /// [`to_ansi16`](Self::to_ansi16) on 16-color ones and the
+    /// [`to_gray_level`](Self::to_gray_level) palette entry in grayscale mode.
+    pub fn to_ratatui_for(&self, support: ColorSupport) -> RatatuiColor {
+        match support {
+            ColorSupport::Ansi16 => self.to_ansi16(),
+            ColorSupport::TrueColor => self.to_ratatui(),
+            ColorSupport::Grayscale => RatatuiColor::Indexed(self.to_gray_level()),
+        }
+    }
+
+    /// Creates a color from an index into the xterm 256-color palette.
+    ///
+    /// Indices 0-15 are the named ANSI colors, 16-231 the 6x6x6 color cube
+    /// and 232-255 the grayscale ramp, all at their standard xterm values.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Palette index
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// assert_eq!(Color::from_ansi256(9), Color::rgb(255, 0, 0));
+    /// assert_eq!(Color::from_ansi256(196), Color::rgb(255, 0, 0));
+    /// assert_eq!(Color::from_ansi256(232), Color::rgb(8, 8, 8));
+    /// ```
+    pub const fn from_ansi256(index: u8) -> Self {
+        const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+        match index {
+            0..=15 => ANSI16_PALETTE[index as usize].1,
+            16..=231 => {
+                let i = index - 16;
+                Self::rgb(
+                    CUBE_LEVELS[(i / 36) as usize],
+                    CUBE_LEVELS[(i / 6 % 6) as usize],
+                    CUBE_LEVELS[(i % 6) as usize],
+                )
+            }
+            _ => {
+                let level = 8 + (index - 232) * 10;
+                Self::rgb(level, level, level)
+            }
+        }
+    }
+
+    /// Converts a ratatui color into a `Color`.
+    ///
+    /// RGB colors convert exactly. Indexed colors use
+    /// [`from_ansi256`](Self::from_ansi256) and the named colors their
+    /// standard xterm values, so the result matches what most terminals
+    /// display rather than the user's configured palette.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - Ratatui color to convert
+    ///
+    /// # Returns
+    ///
+    /// * `Some(color)` - The equivalent opaque color
+    /// * `None` - For [`RatatuiColor::Reset`], which has no fixed value
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    /// use ratatui::style::Color as RatatuiColor;
+    ///
+    /// let teal = Color::rgb(0, 128, 128);
+    /// assert_eq!(Color::from_ratatui(teal.to_ratatui()), Some(teal));
+    /// assert_eq!(Color::from_ratatui(RatatuiColor::Reset), None);
+    /// ```
+    pub fn from_ratatui(color: RatatuiColor) -> Option<Self> {
+        match color {
+            RatatuiColor::Reset => None,
+            RatatuiColor::Rgb(r, g, b) => Some(Self::rgb(r, g, b)),
+            RatatuiColor::Indexed(index) => Some(Self::from_ansi256(index)),
+            named => ANSI16_PALETTE
+                .iter()
+                .find(|(ansi, _)| *ansi == named)
+                .map(|(_, color)| *color),
+        }
+    }
+
+    /// Creates the color of a blackbody radiator at the given temperature.
+    ///
+    /// Uses Tanner Helland's piecewise approximation of the blackbody
+    /// curve, accurate to a few units per channel. Low temperatures give
+    /// warm oranges and reds, around 6500K is close to white, and higher
+    /// ones shift to pale blue, which suits warmer themes in the evening or
+    /// reading modes.
+    ///
+    /// # Arguments
+    ///
+    /// * `kelvin` - Temperature in Kelvin, clamped to 1000-40000; NaN maps to 1000
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// let candle = Color::from_temperature(1900.0);
+    /// let (r, g, b) = candle.rgb_components();
+    /// assert!(r > g && g > b);
+    ///
+    /// let daylight = Color::from_temperature(6500.0);
+    /// assert!(daylight.distance(&Color::WHITE) < 10.0);
+    /// ```
+    pub fn from_temperature(kelvin: f32) -> Self {
+        let kelvin = if kelvin.is_nan() {
+            1000.0
+        } else {
+            kelvin.clamp(1000.0, 40000.0)
+        };
+        // The fit works in hundreds of Kelvin
+        let t = f64::from(kelvin) / 100.0;
+
+        let (r, g) = if t <= 66.0 {
+            (255.0, 99.470_802_586_1 * t.ln() - 161.119_568_166_1)
+        } else {
+            (
+                329.698_727_446 * (t - 60.0).powf(-0.133_204_759_2),
+                288.122_169_528_3 * (t - 60.0).powf(-0.075_514_849_2),
+            )
+        };
+        let b = if t >= 66.0 {
+            255.0
+        } else if t <= 19.0 {
+            0.0
+        } else {
+            138.517_731_223_1 * (t - 10.0).ln() - 305.044_792_730_7
+        };
+
+        Self::rgb(
+            f32_to_u8(r as f32),
+            f32_to_u8(g as f32),
+            f32_to_u8(b as f32),
+        )
+    }
+
+    /// Returns the RGB components as a tuple.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// let color = Color::rgb(255, 128, 0);
+    /// assert_eq!(color.rgb_components(), (255, 128, 0));
+    /// ```
+    pub fn rgb_components(&self) -> (u8, u8, u8) {
+        (self.r, self.g, self.b)
+    }
+
+    /// Returns the RGBA components as a tuple.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// let color = Color::rgba(255, 128, 0, 128);
+    /// assert_eq!(color.rgba_components(), (255, 128, 0, 128));
+    /// ```
+    pub fn rgba_components(&self) -> (u8, u8, u8, u8) {
+        (self.r, self.g, self.b, self.a)
+    }
+
+    /// Packs the color into a `u32` laid out as `0xAARRGGBB`.
+    ///
+    /// Alpha occupies the most significant byte, followed by red, green and
+    /// blue in the least significant byte. The layout refers to the numeric
+    /// value, not to memory, so it is the same on every platform; use
+    /// `to_be_bytes` for the `[a, r, g, b]` byte sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// assert_eq!(Color::rgba(0x12, 0x34, 0x56, 0x78).to_u32(), 0x7812_3456);
+    /// assert_eq!(Color::rgb(255, 0, 0).to_u32(), 0xffff_0000);
+    /// ```
+    #[inline]
+    pub const fn to_u32(&self) -> u32 {
+        u32::from_be_bytes([self.a, self.r, self.g, self.b])
+    }
+
+    /// Unpacks a color from a `u32` laid out as `0xAARRGGBB`.
+    ///
+    /// The inverse of [`to_u32`](Self::to_u32). Note that an opaque color
+    /// needs `0xff` in the top byte: `0x00ff0000` is fully transparent red.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Packed color with alpha in the most significant byte
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// assert_eq!(Color::from_u32(0xff00_80ff), Color::rgb(0, 128, 255));
+    /// assert!(Color::from_u32(0x00ff_0000).is_transparent());
+    /// ```
+    #[inline]
+    pub const fn from_u32(value: u32) -> Self {
+        let [a, r, g, b] = value.to_be_bytes();
+        Self::rgba(r, g, b, a)
+    }
+
+    /// Returns the RGB components normalized to 0.0-1.0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// assert_eq!(Color::rgb(255, 0, 51).to_rgb_f32(), (1.0, 0.0, 0.2));
+    /// ```
+    pub fn to_rgb_f32(&self) -> (f32, f32, f32) {
+        let (r, g, b, _) = self.to_rgba_f32();
+        (r, g, b)
+    }
+
+    /// Returns the RGBA components normalized to 0.0-1.0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// assert_eq!(Color::rgba(255, 0, 0, 0).to_rgba_f32(), (1.0, 0.0, 0.0, 0.0));
+    /// ```
+    pub fn to_rgba_f32(&self) -> (f32, f32, f32, f32) {
+        let norm = |c: u8| c as f32 / 255.0;
+        (norm(self.r), norm(self.g), norm(self.b), norm(self.a))
+    }
+
+    /// Mixes with another color by a specified amount.
+    ///
+    /// **Direction:** `amount` is how far to move *toward `other`*. At 0.0
+    /// the result is this color, at 1.0 it is `other`, so small amounts
+    /// give a slight tint of `other`. [`Color::lerp`] spells out the same
+    /// operation with explicit endpoints.
+    ///
+    /// **Rounding:** each channel is rounded to the nearest value, with
+    /// halves rounded up, so blends are symmetric: an even mix of 0 and 255
+    /// gives 128 from either side, never 127.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The color to mix with
+    /// * `amount` - Mix ratio (0.0-1.0), where 0.0 is this color and 1.0 is the other color
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::Color;
+    ///
+    /// let red = Color::rgb(255, 0, 0);
+    /// let blue = Color::rgb(0, 0, 255);
+    /// let purple = red.mix(&blue, 0.5);
+    /// assert_eq!(purple.rgb_components(), (128, 0, 128));
+    ///
+    /// // Mostly red with a little blue
+    /// assert_eq!(red.mix(&blue, 0.25).rgb_components(), (191, 0, 64));
+    /// ```
+    pub fn mix(&self, other: &Color, amount: f32) -> Self {
+        let amount = amount.clamp(0.0, 1.0);
+        let channel =
+            |from: u8, to: u8| f32_to_u8(from as f32 * (1.0 - amount) + to as f32 * amount);
+        let r = channel(self.r, other.r);
+        let g = channel(self.g, other.g);
+        let b = channel(self.b, other.b);
+        let a = channel(self.a, other.a);
+        Self::rgba(r, g, b, a)
+    }
+
+    /// Mixes with another color, weighting each color by its alpha.
+    ///
+    /// [`mix`](Self::mix) interpolates alpha like any other channel, so the
+    /// RGB of a nearly transparent color still bleeds into the result and
+    /// shows up as a dark or tinted halo. This premultiplies RGB by alpha
+    /// before interpolating and divides it back out afterwards, which is
+    /// the correct way to blend translucent colors. For two opaque colors
+    /// both methods agree. A fully transparent result is transparent black.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The color to mix with
+    /// * `amount` - Mix ratio (0.0-1.0), where 0.0 is this color and 1.0 is the other color
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::Color;
+    ///
+    /// let invisible = Color::rgba(255, 255, 255, 0);
+    /// let blue = Color::rgb(0, 0, 255);
+    ///
+    /// // Fading in from transparent keeps the hue of the visible color
+    /// assert_eq!(invisible.mix_premultiplied(&blue, 0.5), Color::rgba(0, 0, 255, 128));
+    /// ```
+    pub fn mix_premultiplied(&self, other: &Color, amount: f32) -> Self {
+        let amount = amount.clamp(0.0, 1.0);
+        let (from_a, to_a) = (self.a as f32 / 255.0, other.a as f32 / 255.0);
+        let alpha = from_a * (1.0 - amount) + to_a * amount;
+        if alpha <= 0.0 {
+            return Self::rgba(0, 0, 0, 0);
+        }
+
+        let channel = |from: u8, to: u8| {
+            let premultiplied = from as f32 * from_a * (1.0 - amount) + to as f32 * to_a * amount;
+            f32_to_u8(premultiplied / alpha)
+        };
+        let r = channel(self.r, other.r);
+        let g = channel(self.g, other.g);
+        let b = channel(self.b, other.b);
+        Self::rgba(r, g, b, f32_to_u8(alpha * 255.0))
+    }
+
+    /// Linearly interpolates from one color to another.
+    ///
+    /// Equivalent to `from.mix(&to, t)`: `t = 0.0` gives `from` and
+    /// `t = 1.0` gives `to`. Every channel, including alpha, is
+    /// interpolated in sRGB and `t` is clamped to 0.0-1.0.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Color at `t = 0.0`
+    /// * `to` - Color at `t = 1.0`
+    /// * `t` - Interpolation parameter (0.0-1.0)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::Color;
+    ///
+    /// let color = Color::lerp(Color::BLACK, Color::WHITE, 0.25);
+    /// assert_eq!(color.rgb_components(), (64, 64, 64));
+    /// ```
+    pub fn lerp(from: Color, to: Color, t: f32) -> Self {
+        from.mix(&to, t)
+    }
+
+    /// Returns the equal-weight mean of `colors`.
+    ///
+    /// Like [`mix`](Self::mix), each channel (including alpha) is averaged
+    /// independently in sRGB and rounded to the nearest value.
+    ///
+    /// # Arguments
+    ///
+    /// * `colors` - Colors to average
+    ///
+    /// # Returns
+    ///
+    /// The mean color, or [`Color::TRANSPARENT`] if `colors` is empty
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::Color;
+    ///
+    /// let purple = Color::average(&[Color::RED, Color::BLUE]);
+    /// assert_eq!(purple.rgb_components(), (128, 0, 128));
+    /// assert_eq!(Color::average(&[]), Color::TRANSPARENT);
+    /// ```
+    pub fn average(colors: &[Color]) -> Self {
+        let pairs: Vec<(Color, f32)> = colors.iter().map(|&color| (color, 1.0)).collect();
+        Self::weighted(&pairs)
+    }
+
+    /// Returns the weighted mean of `pairs`, normalized by the total weight.
+    ///
+    /// Weights are relative, so `[(a, 1.0), (b, 3.0)]` and
+    /// `[(a, 0.25), (b, 0.75)]` give the same color. Negative and NaN
+    /// weights count as zero. Channels are averaged as in
+    /// [`average`](Self::average).
+    ///
+    /// # Arguments
+    ///
+    /// * `pairs` - Colors paired with their weights
+    ///
+    /// # Returns
+    ///
+    /// The weighted mean, or [`Color::TRANSPARENT`] if the total weight is zero
+    /// or infinite
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::Color;
+    ///
+    /// let color = Color::weighted(&[(Color::BLACK, 1.0), (Color::WHITE, 3.0)]);
+    /// assert_eq!(color.rgb_components(), (191, 191, 191));
+    /// ```
+    pub fn weighted(pairs: &[(Color, f32)]) -> Self {
+        let mut sums = [0.0f32; 4];
+        let mut total = 0.0f32;
+
+        for &(color, weight) in pairs {
+            // `max` discards NaN in favour of 0.0
+            let weight = weight.max(0.0);
+            total += weight;
+            for (sum, channel) in sums.iter_mut().zip([color.r, color.g, color.b, color.a]) {
+                *sum += channel as f32 * weight;
+            }
+        }
+
+        if total <= 0.0 || !total.is_finite() {
+            return Self::TRANSPARENT;
+        }
+
+        let [r, g, b, a] = sums.map(|sum| f32_to_u8(sum / total));
+        Self::rgba(r, g, b, a)
+    }
+
+    /// Returns `steps` colors evenly spaced from this color to `other`.
+    ///
+    /// The first entry is this color and the last is `other`, each step
+    /// computed with [`mix`](Self::mix). A single step yields just this
+    /// color and zero steps an empty vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The color the gradient ends at
+    /// * `steps` - Number of colors to produce
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::Color;
+    ///
+    /// let ramp = Color::BLACK.gradient(&Color::WHITE, 3);
+    /// assert_eq!(ramp, vec![Color::BLACK, Color::rgb(128, 128, 128), Color::WHITE]);
+    /// ```
+    pub fn gradient(&self, other: &Color, steps: usize) -> Vec<Color> {
+        match steps {
+            0 => Vec::new(),
+            1 => vec![*self],
+            _ => (0..steps)
+                .map(|step| self.mix(other, step as f32 / (steps - 1) as f32))
+                .collect(),
+        }
+    }
+
+    /// Samples a gradient through evenly spaced `stops` at position `t`.
+    ///
+    /// The stops divide `[0, 1]` into equal segments and `t` is
+    /// interpolated with [`mix`](Self::mix) between the two stops around
+    /// it, which suits legends and heatmaps. Positions at or before 0.0
+    /// (including NaN) give the first stop and positions at or past 1.0
+    /// the last.
+    ///
+    /// # Arguments
+    ///
+    /// * `stops` - Colors the gradient passes through, in order
+    /// * `t` - Position along the gradient (0.0-1.0)
+    ///
+    /// # Returns
+    ///
+    /// The sampled color, the only stop of a single-stop slice, or
+    /// [`Color::TRANSPARENT`] if `stops` is empty
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::Color;
+    ///
+    /// let heat = [Color::BLUE, Color::GREEN, Color::RED];
+    /// assert_eq!(Color::multi_gradient(&heat, 0.5), Color::GREEN);
+    /// assert_eq!(Color::multi_gradient(&heat, 2.0), Color::RED);
+    /// ```
+    pub fn multi_gradient(stops: &[Color], t: f32) -> Self {
+        let (first, last) = match stops {
+            [] => return Self::TRANSPARENT,
+            [first, .., last] => (*first, *last),
+            [only] => return *only,
+        };
+        if t.is_nan() || t <= 0.0 {
+            return first;
+        }
+        if t >= 1.0 {
+            return last;
+        }
+
+        let position = t * (stops.len() - 1) as f32;
+        let index = (position as usize).min(stops.len() - 2);
+        stops[index].mix(&stops[index + 1], position - index as f32)
+    }
+
+    /// Mixes with another color in the perceptually uniform Oklab space.
+    ///
+    /// Unlike [`mix`](Self::mix), which interpolates sRGB channels directly,
+    /// this avoids muddy intermediate tones when blending distant hues and
+    /// produces evenly stepped lightness, which suits gradients and heatmaps.
+    /// Alpha is interpolated linearly.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The color to mix with
+    /// * `amount` - Mix ratio (0.0-1.0), where 0.0 is this color and 1.0 is the other color
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::Color;
+    ///
+    /// let black = Color::rgb(0, 0, 0);
+    /// let white = Color::rgb(255, 255, 255);
+    /// assert_eq!(black.mix_oklab(&white, 0.0), black);
+    /// assert_eq!(black.mix_oklab(&white, 1.0), white);
+    /// ```
+    pub fn mix_oklab(&self, other: &Color, amount: f32) -> Self {
+        let amount = amount.clamp(0.0, 1.0);
+        let (l1, a1, b1) = srgb_to_oklab(self.r, self.g, self.b);
+        let (l2, a2, b2) = srgb_to_oklab(other.r, other.g, other.b);
+
+        let lerp = |from: f32, to: f32| from + (to - from) * amount;
+        let (r, g, b) = oklab_to_srgb(lerp(l1, l2), lerp(a1, a2), lerp(b1, b2));
+        let a = f32_to_u8(lerp(self.a as f32, other.a as f32));
+
+        Self::rgba(r, g, b, a)
+    }
+
+    /// Returns the WCAG relative luminance of the color (0.0-1.0).
+    ///
+    /// Channels are linearized from sRGB and weighted per WCAG 2.x. Alpha
+    /// is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// assert_eq!(Color::rgb(0, 0, 0).relative_luminance(), 0.0);
+    /// assert!((Color::rgb(255, 255, 255).relative_luminance() - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn relative_luminance(&self) -> f32 {
+        0.2126 * srgb_to_linear(self.r)
+            + 0.7152 * srgb_to_linear(self.g)
+            + 0.0722 * srgb_to_linear(self.b)
+    }
+
+    /// Returns whether the color is dark (relative luminance below 0.5).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::{theme, Color};
+    ///
+    /// assert!(theme::background::BASE.is_dark());
+    /// assert!(!Color::rgb(255, 255, 255).is_dark());
+    /// ```
+    pub fn is_dark(&self) -> bool {
+        self.relative_luminance() < 0.5
+    }
+
+    /// Returns whether the color is light (relative luminance of 0.5 or above).
+    pub fn is_light(&self) -> bool {
+        !self.is_dark()
+    }
+
+    /// Returns whether the color is a shade of gray (equal red, green and blue).
+    ///
+    /// Alpha is ignored, so translucent grays count too.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// assert!(Color::rgb(128, 128, 128).is_grayscale());
+    /// assert!(Color::BLACK.is_grayscale());
+    /// assert!(!Color::rgb(128, 128, 129).is_grayscale());
+    /// ```
+    #[inline]
+    pub const fn is_grayscale(&self) -> bool {
+        self.r == self.g && self.g == self.b
+    }
+
+    /// Returns whether the color is fully transparent (alpha of 0).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// assert!(Color::TRANSPARENT.is_transparent());
+    /// assert!(!Color::rgba(255, 0, 0, 1).is_transparent());
+    /// ```
+    #[inline]
+    pub const fn is_transparent(&self) -> bool {
+        self.a == 0
+    }
+
+    /// Returns whether the color is fully opaque (alpha of 255).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// assert!(Color::rgb(255, 0, 0).is_opaque());
+    /// assert!(!Color::rgba(255, 0, 0, 254).is_opaque());
+    /// ```
+    #[inline]
+    pub const fn is_opaque(&self) -> bool {
+        self.a == 255
+    }
+
+    /// Picks a legible text color for use on top of this color.
+    ///
+    /// Returns [`theme::text::PRIMARY`] or the near-black
+    /// [`theme::background::BASE`], whichever has the higher WCAG contrast
+    /// ratio against this color.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::{theme, Color};
+    ///
+    /// let on_white = Color::rgb(255, 255, 255).readable_foreground();
+    /// assert_eq!(on_white, theme::background::BASE);
+    /// ```
+    pub fn readable_foreground(&self) -> Color {
+        let light = theme::text::PRIMARY;
+        let dark = theme::background::BASE;
+
+        if self.contrast_ratio(&light) >= self.contrast_ratio(&dark) {
+            light
+        } else {
+            dark
+        }
+    }
+
+    /// Returns the WCAG contrast ratio between two colors (1.0-21.0).
+    ///
+    /// The ratio is symmetric and ignores alpha. WCAG AA asks for at least
+    /// 4.5 for body text and 3.0 for large text and UI components.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// let black = Color::rgb(0, 0, 0);
+    /// let white = Color::rgb(255, 255, 255);
+    /// assert!((black.contrast_ratio(&white) - 21.0).abs() < 0.01);
+    /// assert_eq!(white.contrast_ratio(&white), 1.0);
+    /// ```
+    pub fn contrast_ratio(&self, other: &Color) -> f32 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Returns the inverse of the color.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// let white = Color::rgb(255, 255, 255);
+    /// let black = white.invert();
+    /// assert_eq!(black.rgb_components(), (0, 0, 0));
+    /// ```
+    pub fn invert(&self) -> Self {
+        Self::rgba(255 - self.r, 255 - self.g, 255 - self.b, self.a)
+    }
+
+    /// Returns the Euclidean distance to another color in RGB space.
+    ///
+    /// Alpha is ignored. The result ranges from 0.0 for identical colors to
+    /// about 441.7 between black and white.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The color to measure against
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// let a = Color::rgb(0, 0, 0);
+    /// let b = Color::rgb(3, 4, 0);
+    /// assert_eq!(a.distance(&b), 5.0);
+    /// ```
+    pub fn distance(&self, other: &Color) -> f32 {
+        let delta = |a: u8, b: u8| a as f32 - b as f32;
+        let (dr, dg, db) = (
+            delta(self.r, other.r),
+            delta(self.g, other.g),
+            delta(self.b, other.b),
+        );
+        (dr * dr + dg * dg + db * db).sqrt()
+    }
+
+    /// Returns whether every channel, including alpha, differs by at most `tolerance`.
+    ///
+    /// Useful for comparing results of color math, where rounding can leave
+    /// channels off by one from the exact value.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The color to compare against
+    /// * `tolerance` - Largest allowed per-channel difference
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// let a = Color::rgb(100, 150, 200);
+    /// let b = Color::rgb(101, 149, 200);
+    /// assert!(a.approx_eq(&b, 1));
+    /// assert!(!a.approx_eq(&b, 0));
+    /// ```
+    pub fn approx_eq(&self, other: &Color, tolerance: u8) -> bool {
+        self.r.abs_diff(other.r) <= tolerance
+            && self.g.abs_diff(other.g) <= tolerance
+            && self.b.abs_diff(other.b) <= tolerance
+            && self.a.abs_diff(other.a) <= tolerance
+    }
+}
+
+// Implement conversion to owo-colors RGB
+impl From<Color> for owo_colors::Rgb {
+    /// Converts the color to owo-colors RGB format.
+    fn from(color: Color) -> Self {
+        color.to_owo_rgb()
+    }
+}
+
+// Implement conversion to ratatui Color
+impl From<Color> for RatatuiColor {
+    /// Converts the color to ratatui Color format.
+    fn from(color: Color) -> Self {
+        color.to_ratatui()
+    }
+}
+
+impl Add for Color {
+    type Output = Color;
+
+    /// Adds the RGB channels, saturating at 255 instead of wrapping.
+    ///
+    /// The alpha of the left-hand color is kept, so adding a tint never
+    /// changes opacity.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// let tinted = Color::rgb(250, 100, 0) + Color::rgb(10, 10, 10);
+    /// assert_eq!(tinted.rgb_components(), (255, 110, 10));
+    /// ```
+    fn add(self, other: Color) -> Color {
+        Color::rgba(
+            self.r.saturating_add(other.r),
+            self.g.saturating_add(other.g),
+            self.b.saturating_add(other.b),
+            self.a,
+        )
+    }
+}
+
+impl Sub for Color {
+    type Output = Color;
+
+    /// Subtracts the RGB channels, saturating at 0 instead of wrapping.
+    ///
+    /// The alpha of the left-hand color is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// let shaded = Color::rgb(5, 100, 200) - Color::rgb(10, 10, 10);
+    /// assert_eq!(shaded.rgb_components(), (0, 90, 190));
+    /// ```
+    fn sub(self, other: Color) -> Color {
+        Color::rgba(
+            self.r.saturating_sub(other.r),
+            self.g.saturating_sub(other.g),
+            self.b.saturating_sub(other.b),
+            self.a,
+        )
+    }
+}
+
+impl Mul<f32> for Color {
+    type Output = Color;
+
+    /// Scales the RGB channels by `factor`, keeping alpha.
+    ///
+    /// Factors below 1.0 shade toward black and `0.0` yields black; factors
+    /// above 1.0 brighten, with each channel saturating at 255. Negative
+    /// factors are treated as `0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Color;
+    ///
+    /// let half = Color::rgb(200, 100, 50) * 0.5;
+    /// assert_eq!(half.rgb_components(), (100, 50, 25));
+    /// ```
+    fn mul(self, factor: f32) -> Color {
+        let factor = factor.max(0.0);
+        let scale = |channel: u8| f32_to_u8(channel as f32 * factor);
+        Color::rgba(scale(self.r), scale(self.g), scale(self.b), self.a)
+    }
+}
+
+impl fmt::Display for Color {
+    /// Formats the color as a string representation.
+    ///
+    /// Returns either "rgb(r, g, b)" or "rgba(r, g, b, a)" format.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.a == 255 {
+            write!(f, "rgb({}, {}, {})", self.r, self.g, self.b)
+        } else {
+            // Format alpha to exactly one decimal place
+            let alpha = (self.a as f32 / 255.0 * 10.0).round() / 10.0;
+            write!(f, "rgba({}, {}, {}, {})", self.r, self.g, self.b, alpha)
+        }
+    }
+}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    /// Parses a hex color as accepted by [`Color::from_hex`] or an X11
+    /// specification as accepted by [`Color::from_x11`].
+    ///
+    /// Surrounding whitespace is ignored.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        Self::from_x11(s)
+            .or_else(|| Self::from_hex(s))
+            .ok_or_else(|| ParseColorError {
+                input: s.to_string(),
+            })
+    }
+}
+
+/// Colors serialize as hex strings in the form produced by [`Color::to_hex`].
+#[cfg(feature = "serde")]
+impl serde::Serialize for Color {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_hex())
+    }
+}
+
+/// Colors deserialize from hex strings as accepted by [`Color::from_hex`].
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Color {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let hex = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        Color::from_hex(&hex)
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid color `{}`", hex)))
+    }
+}
+
+/// An RGB channel, used to rearrange channels with [`Color::swizzle`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Channel {
+    /// Red channel
+    R,
+    /// Green channel
+    G,
+    /// Blue channel
+    B,
+}
+
+/// Error returned when parsing a [`Color`] from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseColorError {
+    /// The text that could not be parsed
+    pub input: String,
+}
+
+impl Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid color `{}`: expected #rgb, #rrggbb, #rrggbbaa or rgb:rrrr/gggg/bbbb",
+            self.input
+        )
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+/// Smoothly animates a color toward a target with exponential smoothing.
+///
+/// Each [`step`](Self::step) closes a fraction of the remaining distance
+/// that depends only on the elapsed time, so fades look the same at any
+/// frame rate. Progress is tracked in floating point, so slow fades keep
+/// moving even when a single frame changes a channel by less than one
+/// step, and the color snaps to the target once every channel is within
+/// half a step of it. Useful for focus rings and hover highlights. While
+/// [`reduce_motion`] is on, every step jumps straight to the target.
+///
+/// # Examples
+///
+/// ```rust
+/// use oxitty::{colors::ColorAnimator, Color};
+/// use std::time::Duration;
+///
+/// let mut animator = ColorAnimator::new(Color::BLACK);
+/// animator.set_target(Color::WHITE);
+///
+/// let color = animator.step(Duration::from_millis(16));
+/// assert!(color != Color::BLACK && color != Color::WHITE);
+///
+/// animator.step(Duration::from_secs(5));
+/// assert!(animator.is_settled());
+/// assert_eq!(animator.current(), Color::WHITE);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorAnimator {
+    /// Current RGBA channels (0.0-255.0)
+    current: [f32; 4],
+    /// Color being approached
+    target: Color,
+    /// Smoothing rate per second
+    rate: f32,
+}
+
+impl ColorAnimator {
+    /// Default smoothing rate, closing about 63% of the distance in 100ms.
+    pub const DEFAULT_RATE: f32 = 10.0;
+
+    /// Channel distance below which the animation snaps to its target.
+    const SNAP_EPSILON: f32 = 0.5;
+
+    /// Creates an animator resting at `color`.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - Initial and target color
+    pub fn new(color: Color) -> Self {
+        Self {
+            current: Self::channels(color),
+            target: color,
+            rate: Self::DEFAULT_RATE,
+        }
+    }
+
+    /// Sets the smoothing rate.
+    ///
+    /// After `t` seconds a fraction `1 - e^(-rate * t)` of the distance to
+    /// the target is covered; larger rates settle faster. Negative and NaN
+    /// rates are treated as `0.0`, which freezes the animation.
+    ///
+    /// # Arguments
+    ///
+    /// * `rate` - Smoothing rate per second
+    pub fn with_rate(mut self, rate: f32) -> Self {
+        self.rate = rate.max(0.0);
+        self
+    }
+
+    /// Sets the color to animate toward, starting from the current color.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - New target color
+    pub fn set_target(&mut self, target: Color) {
+        self.target = target;
+    }
+
+    /// Jumps straight to `color` and stops animating.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - New current and target color
+    pub fn snap_to(&mut self, color: Color) {
+        *self = Self::new(color).with_rate(self.rate);
+    }
+
+    /// Returns the color being approached.
+    pub fn target(&self) -> Color {
+        self.target
+    }
+
+    /// Returns the color for the current frame.
+    pub fn current(&self) -> Color {
+        let [r, g, b, a] = self.current.map(f32_to_u8);
+        Color::rgba(r, g, b, a)
+    }
+
+    /// Returns whether the animation has reached its target.
+    pub fn is_settled(&self) -> bool {
+        self.current == Self::channels(self.target)
+    }
+
+    /// Advances the animation by `dt`.
+    ///
+    /// # Arguments
+    ///
+    /// * `dt` - Time elapsed since the previous step
+    ///
+    /// # Returns
+    ///
+    /// The color to draw for this frame
+    pub fn step(&mut self, dt: Duration) -> Color {
+        let target = Self::channels(self.target);
+        if reduce_motion() {
+            self.current = target;
+            return self.target;
+        }
+
+        let amount = 1.0 - (-self.rate * dt.as_secs_f32()).exp();
+
+        let mut settled = true;
+        for (current, target) in self.current.iter_mut().zip(target) {
+            *current += (target - *current) * amount;
+            settled &= (target - *current).abs() < Self::SNAP_EPSILON;
+        }
+        if settled {
+            self.current = target;
+        }
+
+        self.current()
+    }
+
+    fn channels(color: Color) -> [f32; 4] {
+        let (r, g, b, a) = color.rgba_components();
+        [r, g, b, a].map(f32::from)
+    }
+}
+
+/// The 16 named ANSI colors with their standard xterm values.
+const ANSI16_PALETTE: [(RatatuiColor, Color); 16] = [
+    (RatatuiColor::Black, Color::rgb(0, 0, 0)),
+    (RatatuiColor::Red, Color::rgb(205, 0, 0)),
+    (RatatuiColor::Green, Color::rgb(0, 205, 0)),
+    (RatatuiColor::Yellow, Color::rgb(205, 205, 0)),
+    (RatatuiColor::Blue, Color::rgb(0, 0, 238)),
+    (RatatuiColor::Magenta, Color::rgb(205, 0, 205)),
+    (RatatuiColor::Cyan, Color::rgb(0, 205, 205)),
+    (RatatuiColor::Gray, Color::rgb(229, 229, 229)),
+    (RatatuiColor::DarkGray, Color::rgb(127, 127, 127)),
+    (RatatuiColor::LightRed, Color::rgb(255, 0, 0)),
+    (RatatuiColor::LightGreen, Color::rgb(0, 255, 0)),
+    (RatatuiColor::LightYellow, Color::rgb(255, 255, 0)),
+    (RatatuiColor::LightBlue, Color::rgb(92, 92, 255)),
+    (RatatuiColor::LightMagenta, Color::rgb(255, 0, 255)),
+    (RatatuiColor::LightCyan, Color::rgb(0, 255, 255)),
+    (RatatuiColor::White, Color::rgb(255, 255, 255)),
+];
+
+/// Range of colors a terminal can display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorSupport {
+    /// Only the 16 named ANSI colors
+    Ansi16,
+    /// Arbitrary RGB colors
+    TrueColor,
+    /// Shades of the 24-step gray ramp only, for monochrome displays and
+    /// accessibility modes. Never detected, only selected explicitly.
+    Grayscale,
+}
+
+impl ColorSupport {
+    /// Detects color support from the `COLORTERM` and `TERM` variables.
+    ///
+    /// `COLORTERM=truecolor` or `24bit` always means true color. Otherwise
+    /// `TERM` values naming a 16-color terminal (`linux`, `vt100`, `vt220`,
+    /// `ansi`, `cygwin` or anything ending in `-16color`) select
+    /// [`Ansi16`](Self::Ansi16). Everything else, including an unset
+    /// `TERM`, is assumed to support true color.
+    pub fn detect() -> Self {
+        let colorterm = env::var("COLORTERM").ok();
+        let term = env::var("TERM").ok();
+        Self::from_env(colorterm.as_deref(), term.as_deref())
+    }
+
+    /// Applies the rules of [`detect`](Self::detect) to the given values.
+    fn from_env(colorterm: Option<&str>, term: Option<&str>) -> Self {
+        if matches!(colorterm, Some("truecolor" | "24bit")) {
+            return ColorSupport::TrueColor;
+        }
+
+        match term {
+            Some("linux" | "vt100" | "vt220" | "ansi" | "cygwin") => ColorSupport::Ansi16,
+            Some(term) if term.ends_with("-16color") => ColorSupport::Ansi16,
+            _ => ColorSupport::TrueColor,
+        }
+    }
+}
+
+thread_local! {
+    /// Override installed by [`with_color_support`] on this thread
+    static COLOR_SUPPORT_OVERRIDE: Cell<Option<ColorSupport>> = const { Cell::new(None) };
+}
+
+/// Returns the color support theme helpers should target.
+///
+/// Uses the override of an enclosing [`with_color_support`] call on this
+/// thread, or else [`ColorSupport::detect`], evaluated once per process.
+pub fn color_support() -> ColorSupport {
+    static DETECTED: OnceLock<ColorSupport> = OnceLock::new();
+
+    COLOR_SUPPORT_OVERRIDE
+        .get()
+        .unwrap_or_else(|| *DETECTED.get_or_init(ColorSupport::detect))
+}
+
+/// Runs `f` with [`color_support`] reporting `support` on this thread.
+///
+/// Useful to force a fallback palette, or to preview and test how the UI
+/// looks on other terminals. The previous value is restored afterwards,
+/// even if `f` panics.
+///
+/// # Arguments
+///
+/// * `support` - Color support to simulate
+/// * `f` - Closure to run with the override in place
+///
+/// # Examples
+///
+/// ```rust
+/// use oxitty::colors::{color_support, with_color_support, ColorSupport};
+///
+/// let inside = with_color_support(ColorSupport::Ansi16, color_support);
+/// assert_eq!(inside, ColorSupport::Ansi16);
+/// ```
+pub fn with_color_support<T>(support: ColorSupport, f: impl FnOnce() -> T) -> T {
+    /// Restores the previous override when dropped
+    struct Restore(Option<ColorSupport>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            COLOR_SUPPORT_OVERRIDE.set(self.0);
+        }
+    }
+
+    let _restore = Restore(COLOR_SUPPORT_OVERRIDE.replace(Some(support)));
+    f()
+}
+
+thread_local! {
+    /// Override installed by [`with_reduce_motion`] on this thread
+    static REDUCE_MOTION_OVERRIDE: Cell<Option<bool>> = const { Cell::new(None) };
+}
+
+/// Process-wide reduced motion setting, initialized from the environment.
+fn reduce_motion_setting() -> &'static AtomicBool {
+    static SETTING: OnceLock<AtomicBool> = OnceLock::new();
+
+    SETTING.get_or_init(|| {
+        let requested = env::var_os("NO_MOTION").is_some_and(|value| !value.is_empty());
+        AtomicBool::new(requested)
+    })
+}
+
+/// Returns whether animations should snap straight to their end state.
+///
+/// Users who are sensitive to motion, or want to save battery, can turn
+/// animation off; [`ColorAnimator`] and [`Theme::lerp`] then jump to their
+/// target instead of fading, and custom animations should do the same.
+/// Uses the override of an enclosing [`with_reduce_motion`] call on this
+/// thread, or else the value of [`set_reduce_motion`]. Defaults to `true`
+/// when the `NO_MOTION` environment variable is set to a non-empty value.
+pub fn reduce_motion() -> bool {
+    REDUCE_MOTION_OVERRIDE
+        .get()
+        .unwrap_or_else(|| reduce_motion_setting().load(Ordering::Relaxed))
+}
+
+/// Turns reduced motion on or off for the whole process.
+///
+/// Overrides the default taken from `NO_MOTION`; see [`reduce_motion`].
+///
+/// # Arguments
+///
+/// * `enabled` - Whether animations should snap to their end state
+pub fn set_reduce_motion(enabled: bool) {
+    reduce_motion_setting().store(enabled, Ordering::Relaxed);
+}
+
+/// Runs `f` with [`reduce_motion`] reporting `enabled` on this thread.
+///
+/// Useful to preview and test both modes without touching the
+/// process-wide setting. The previous value is restored afterwards, even
+/// if `f` panics.
+///
+/// # Arguments
+///
+/// * `enabled` - Reduced motion setting to simulate
+/// * `f` - Closure to run with the override in place
+///
+/// # Examples
+///
+/// ```rust
+/// use oxitty::colors::{reduce_motion, with_reduce_motion};
+///
+/// assert!(with_reduce_motion(true, reduce_motion));
+/// assert!(!with_reduce_motion(false, reduce_motion));
+/// ```
+pub fn with_reduce_motion<T>(enabled: bool, f: impl FnOnce() -> T) -> T {
+    /// Restores the previous override when dropped
+    struct Restore(Option<bool>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            REDUCE_MOTION_OVERRIDE.set(self.0);
+        }
+    }
+
+    let _restore = Restore(REDUCE_MOTION_OVERRIDE.replace(Some(enabled)));
+    f()
+}
+
+/// Lightness points by which [`ThemeColorize::muted`] and
+/// [`ThemeColorize::emphasized`] shift the primary text color.
+const EMPHASIS_SHIFT: f32 = 30.0;
+
+/// Extension trait for applying theme colors to strings with owo-colors.
+///
+/// This trait provides convenient methods for applying semantic theme colors to text.
+/// It is automatically implemented for all types that implement `OwoColorize`.
+///
+/// # Examples
+///
+/// ```rust
+/// use oxitty::colors::ThemeColorize;
+///
+/// println!("{}", "Important message".primary());
+/// println!("{}", "Warning alert".warning());
+/// println!("{}", "Error occurred".error());
+/// ```
+pub trait ThemeColorize: OwoColorize {
+    /// Apply primary text color.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::ThemeColorize;
+    ///
+    /// println!("{}", "Main content".primary());
+    /// ```
+    #[inline]
+    fn primary(self) -> impl fmt::Display
+    where
+        Self: Sized + Display,
+    {
+        let (r, g, b) = theme::text::PRIMARY.rgb_components();
+        format!("{}", self.truecolor(r, g, b))
+    }
+
+    /// Apply secondary text color.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::ThemeColorize;
+    ///
+    /// println!("{}", "Additional info".secondary());
+    /// ```
+    #[inline]
+    fn secondary(self) -> impl fmt::Display
+    where
+        Self: Sized + Display,
+    {
+        let (r, g, b) = theme::text::SECONDARY.rgb_components();
+        format!("{}", self.truecolor(r, g, b))
+    }
+
+    /// Apply info status color.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::ThemeColorize;
+    ///
+    /// println!("{}", "Information".info());
+    /// ```
+    #[inline]
+    fn info(self) -> impl fmt::Display
+    where
+        Self: Sized + Display,
+    {
+        let (r, g, b) = theme::status::INFO.rgb_components();
+        format!("{}", self.truecolor(r, g, b))
+    }
+
+    /// Apply warning status color.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::ThemeColorize;
+    ///
+    /// println!("{}", "Warning message".warning());
+    /// ```
+    #[inline]
+    fn warning(self) -> String
+    where
+        Self: Sized + Display,
+    {
+        let (r, g, b) = theme::status::WARNING.rgb_components();
+        format!("{}", self.truecolor(r, g, b))
+    }
+
+    /// Apply error status color.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::ThemeColorize;
+    ///
+    /// println!("{}", "Error message".error());
+    /// ```
+    #[inline]
+    fn error(self) -> impl fmt::Display
+    where
+        Self: Sized + Display,
+    {
+        let (r, g, b) = theme::status::ERROR.rgb_components();
+        format!("{}", self.truecolor(r, g, b))
+    }
+
+    /// Apply v01d green color.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::ThemeColorize;
+    ///
+    /// println!("{}", "Success".void_green());
+    /// ```
+    #[inline]
+    fn void_green(self) -> impl fmt::Display
+    where
+        Self: Sized + Display,
+    {
+        let (r, g, b) = theme::void::GREEN.rgb_components();
+        format!("{}", self.truecolor(r, g, b))
+    }
+
+    /// Apply v01d purple color.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::ThemeColorize;
+    ///
+    /// println!("{}", "Accent".void_purple());
+    /// ```
+    #[inline]
+    fn void_purple(self) -> impl fmt::Display
+    where
+        Self: Sized + Display,
+    {
+        let (r, g, b) = theme::void::PURPLE.rgb_components();
+        format!("{}", self.truecolor(r, g, b))
+    }
+
+    /// Apply a de-emphasized variant of the primary text color.
+    ///
+    /// This recolors the text with the primary color darkened by 30
+    /// lightness points rather than setting the SGR "faint" attribute, so it
+    /// renders the same on terminals that ignore attributes. Use owo-colors'
+    /// `dimmed` for the attribute instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::ThemeColorize;
+    ///
+    /// println!("{}", "Less important".muted());
+    /// ```
+    #[inline]
+    fn muted(self) -> impl fmt::Display
+    where
+        Self: Sized + Display,
+    {
+        let (r, g, b) = theme::text::PRIMARY.darken(EMPHASIS_SHIFT).rgb_components();
+        format!("{}", self.truecolor(r, g, b))
+    }
+
+    /// Apply an emphasized variant of the primary text color.
+    ///
+    /// Like [`muted`](Self::muted), this recolors the text, here with the
+    /// primary color lightened by 30 lightness points, rather than setting
+    /// the SGR bold attribute. Use owo-colors' `bold`
+    /// for the attribute instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::ThemeColorize;
+    ///
+    /// println!("{}", "Look here".emphasized());
+    /// ```
+    #[inline]
+    fn emphasized(self) -> impl fmt::Display
+    where
+        Self: Sized + Display,
+    {
+        let (r, g, b) = theme::text::PRIMARY
+            .lighten(EMPHASIS_SHIFT)
+            .rgb_components();
+        format!("{}", self.truecolor(r, g, b))
+    }
+
+    /// Turn the text into a clickable OSC 8 hyperlink in the link color.
+    ///
+    /// The text is colored with [`theme::semantic::LINK`] and wrapped in
+    /// `ESC ]8;;URL ST` and `ESC ]8;; ST`. Terminals without OSC 8 support
+    /// ignore the framing and show the colored text alone. Control
+    /// characters are stripped from `url`, so it cannot terminate the
+    /// sequence early and inject escape codes of its own.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Target of the link
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::ThemeColorize;
+    ///
+    /// println!("{}", "docs".hyperlink("https://docs.rs/oxitty"));
+    /// ```
+    #[inline]
+    fn hyperlink(self, url: &str) -> impl fmt::Display
+    where
+        Self: Sized + Display,
+    {
+        let url: String = url.chars().filter(|c| !c.is_control()).collect();
+        let (r, g, b) = theme::semantic::LINK.rgb_components();
+        format!(
+            "\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\",
+            url,
+            self.truecolor(r, g, b)
+        )
+    }
+}
+
+// Implement ThemeColorize for all types that implement OwoColorize
+impl<T: OwoColorize + Display> ThemeColorize for T {}
+
+/// Builder for a reusable owo-colors [`Style`](owo_colors::Style) made from
+/// theme colors and text attributes.
+///
+/// Where [`ThemeColorize`] applies a single color per call, `ThemeStyle`
+/// composes a foreground, a background and attributes into one style that
+/// can be stored and applied to any number of strings. Colors are converted
+/// for the current [`color_support`] when they are set, so on 16-color
+/// terminals the style uses the nearest named ANSI colors.
+///
+/// # Examples
+///
+/// ```rust
+/// use oxitty::colors::{theme, ThemeStyle};
+///
+/// let alert = ThemeStyle::new()
+///     .fg(theme::status::ERROR)
+///     .bold();
+///
+/// println!("{}", alert.apply("Connection lost"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ThemeStyle {
+    style: owo_colors::Style,
+}
+
+impl ThemeStyle {
+    /// Creates an empty style with no colors or attributes.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            style: owo_colors::Style::new(),
+        }
+    }
+
+    /// Sets the foreground color.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - Theme color for the text; alpha is ignored
+    #[inline]
+    pub fn fg(self, color: Color) -> Self {
+        Self {
+            style: self.style.color(owo_color_for(color, color_support())),
+        }
+    }
+
+    /// Sets the background color.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - Theme color behind the text; alpha is ignored
+    #[inline]
+    pub fn bg(self, color: Color) -> Self {
+        Self {
+            style: self.style.on_color(owo_color_for(color, color_support())),
+        }
+    }
+
+    /// Adds the bold attribute.
+    #[inline]
+    pub fn bold(self) -> Self {
+        Self {
+            style: self.style.bold(),
+        }
+    }
+
+    /// Adds the italic attribute.
+    #[inline]
+    pub fn italic(self) -> Self {
+        Self {
+            style: self.style.italic(),
+        }
+    }
+
+    /// Adds the underline attribute.
+    #[inline]
+    pub fn underline(self) -> Self {
+        Self {
+            style: self.style.underline(),
+        }
+    }
+
+    /// Adds the SGR "faint" attribute.
+    ///
+    /// Unlike [`ThemeColorize::muted`], this leaves the color untouched and
+    /// relies on the terminal to render the attribute.
+    #[inline]
+    pub fn dimmed(self) -> Self {
+        Self {
+            style: self.style.dimmed(),
+        }
+    }
+
+    /// Returns the underlying owo-colors style.
+    #[inline]
+    pub fn style(&self) -> owo_colors::Style {
+        self.style
+    }
+
+    /// Applies the style to `text`.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - Value to style
+    ///
+    /// # Returns
+    ///
+    /// A displayable wrapper that emits the style's escape codes around `text`
+    #[inline]
+    pub fn apply<T: Display>(&self, text: T) -> impl Display {
+        self.style.style(text)
+    }
+}
+
+impl From<ThemeStyle> for owo_colors::Style {
+    fn from(style: ThemeStyle) -> Self {
+        style.style
+    }
+}
+
+/// Converts a color to the owo-colors color a terminal with `support` can
+/// display, following [`Color::to_ratatui_for`].
+fn owo_color_for(color: Color, support: ColorSupport) -> DynColors {
+    let ansi = match color.to_ratatui_for(support) {
+        RatatuiColor::Black => AnsiColors::Black,
+        RatatuiColor::Red => AnsiColors::Red,
+        RatatuiColor::Green => AnsiColors::Green,
+        RatatuiColor::Yellow => AnsiColors::Yellow,
+        RatatuiColor::Blue => AnsiColors::Blue,
+        RatatuiColor::Magenta => AnsiColors::Magenta,
+        RatatuiColor::Cyan => AnsiColors::Cyan,
+        RatatuiColor::Gray => AnsiColors::White,
+        RatatuiColor::DarkGray => AnsiColors::BrightBlack,
+        RatatuiColor::LightRed => AnsiColors::BrightRed,
+        RatatuiColor::LightGreen => AnsiColors::BrightGreen,
+        RatatuiColor::LightYellow => AnsiColors::BrightYellow,
+        RatatuiColor::LightBlue => AnsiColors::BrightBlue,
+        RatatuiColor::LightMagenta => AnsiColors::BrightMagenta,
+        RatatuiColor::LightCyan => AnsiColors::BrightCyan,
+        RatatuiColor::White => AnsiColors::BrightWhite,
+        RatatuiColor::Indexed(index) => return DynColors::Xterm(XtermColors::from(index)),
+        _ => {
+            let (r, g, b) = color.rgb_components();
+            return DynColors::Rgb(r, g, b);
+        }
+    };
+    DynColors::Ansi(ansi)
+}
+
+/// Theme color constants and semantic color groupings.
+pub mod theme {
+    use super::Color;
+
+    /// Background elevation hierarchy.
+    pub mod background {
+        use super::Color;
+
+        /// Base background color.
+        pub const BASE: Color = Color::rgb(15, 18, 20);
+        /// First level elevation color.
         pub const ELEVATION_1: Color = Color::rgb(22, 27, 30);
         /// Second level elevation color.
         pub const ELEVATION_2: Color = Color::rgb(29, 36, 40);
@@ -672,210 +2895,1208 @@ pub mod theme {
             let floor = level.floor() as usize;
             let fract = level.fract();
 
-            match floor {
-                0 => BASE.mix(&ELEVATION_1, fract),
-                1 => ELEVATION_1.mix(&ELEVATION_2, fract),
-                2 => ELEVATION_2.mix(&ELEVATION_3, fract),
-                _ => ELEVATION_3,
+            match floor {
+                0 => BASE.mix(&ELEVATION_1, fract),
+                1 => ELEVATION_1.mix(&ELEVATION_2, fract),
+                2 => ELEVATION_2.mix(&ELEVATION_3, fract),
+                _ => ELEVATION_3,
+            }
+        }
+
+        /// Returns `n` background shades from [`BASE`] to [`ELEVATION_3`].
+        ///
+        /// Shades are evenly spaced in Oklab (see [`Color::mix_oklab`]), so
+        /// each step looks like the same change in depth. The first and last
+        /// shades are exactly `BASE` and `ELEVATION_3`; a single shade is
+        /// just `BASE` and zero shades an empty vector.
+        ///
+        /// # Arguments
+        ///
+        /// * `n` - Number of shades to produce
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use oxitty::colors::theme::background;
+        ///
+        /// let ramp = background::elevation_ramp(6);
+        /// assert_eq!(ramp.len(), 6);
+        /// assert_eq!(ramp[0], background::BASE);
+        /// assert_eq!(ramp[5], background::ELEVATION_3);
+        /// ```
+        pub fn elevation_ramp(n: usize) -> Vec<Color> {
+            match n {
+                0 => Vec::new(),
+                1 => vec![BASE],
+                _ => (0..n)
+                    .map(|step| match step {
+                        0 => BASE,
+                        // Pin the end so Oklab rounding cannot drift from the constant
+                        _ if step == n - 1 => ELEVATION_3,
+                        _ => BASE.mix_oklab(&ELEVATION_3, step as f32 / (n - 1) as f32),
+                    })
+                    .collect(),
+            }
+        }
+    }
+
+    /// v01d colors and variants.
+    pub mod void {
+        use super::Color;
+
+        /// Primary green color.
+        pub const GREEN: Color = Color::rgb(0, 228, 154);
+        /// Subtle green color (15% opacity).
+        pub const GREEN_SUBTLE: Color = Color::rgba(0, 228, 154, 38);
+        /// Primary purple color.
+        pub const PURPLE: Color = Color::rgb(184, 110, 255);
+        /// Subtle purple color (15% opacity).
+        pub const PURPLE_SUBTLE: Color = Color::rgba(184, 110, 255, 38);
+
+        /// Creates a custom variant of the v01d green color.
+        ///
+        /// # Arguments
+        ///
+        /// * `lightness` - Target lightness value (0-100)
+        pub fn green_variant(lightness: f32) -> Color {
+            let (h, s, _) = GREEN.to_hsl();
+            Color::from_hsl(h, s, lightness)
+        }
+
+        /// Creates a custom variant of the v01d purple color.
+        ///
+        /// # Arguments
+        ///
+        /// * `lightness` - Target lightness value (0-100)
+        pub fn purple_variant(lightness: f32) -> Color {
+            let (h, s, _) = PURPLE.to_hsl();
+            Color::from_hsl(h, s, lightness)
+        }
+    }
+
+    /// Text hierarchy and variations.
+    pub mod text {
+        use super::Color;
+
+        /// Primary text color.
+        pub const PRIMARY: Color = Color::rgb(230, 237, 243);
+        /// Secondary text color.
+        pub const SECONDARY: Color = Color::rgb(139, 148, 158);
+        /// Disabled text color.
+        pub const DISABLED: Color = Color::rgb(106, 115, 125);
+        /// Placeholder text color.
+        pub const PLACEHOLDER: Color = Color::rgb(88, 96, 105);
+
+        /// Creates a custom text color with specified opacity.
+        ///
+        /// # Arguments
+        ///
+        /// * `base` - Base color to modify
+        /// * `opacity` - Target opacity (0-255)
+        pub fn with_opacity(base: Color, opacity: u8) -> Color {
+            base.with_alpha(opacity)
+        }
+    }
+
+    /// System status colors and variants.
+    pub mod status {
+        use super::Color;
+
+        /// Info status color.
+        pub const INFO: Color = Color::rgb(41, 187, 255);
+        /// Success status color.
+        pub const SUCCESS: Color = Color::rgb(35, 209, 139);
+        /// Warning status color.
+        pub const WARNING: Color = Color::rgb(255, 191, 0);
+        /// Error status color.
+        pub const ERROR: Color = Color::rgb(255, 46, 95);
+
+        /// Subtle variants for backgrounds
+        pub const INFO_SUBTLE: Color = Color::rgba(41, 187, 255, 38);
+        pub const SUCCESS_SUBTLE: Color = Color::rgba(35, 209, 139, 38);
+        pub const WARNING_SUBTLE: Color = Color::rgba(255, 191, 0, 38);
+        pub const ERROR_SUBTLE: Color = Color::rgba(255, 46, 95, 38);
+
+        /// Creates a status color variant with custom intensity.
+        ///
+        /// # Arguments
+        ///
+        /// * `base` - Base status color
+        /// * `intensity` - Target intensity (0-100)
+        pub fn variant(base: Color, intensity: f32) -> Color {
+            let (h, s, _) = base.to_hsl();
+            Color::from_hsl(h, s, intensity)
+        }
+    }
+
+    /// Base16 theme implementation for terminal compatibility.
+    pub mod base16 {
+        use super::Color;
+
+        pub const BASE00: Color = super::background::BASE;
+        pub const BASE01: Color = super::background::ELEVATION_1;
+        pub const BASE02: Color = super::background::ELEVATION_2;
+        pub const BASE03: Color = super::background::ELEVATION_3;
+        pub const BASE04: Color = super::text::SECONDARY;
+        pub const BASE05: Color = super::text::PRIMARY;
+        pub const BASE06: Color = super::void::GREEN;
+        pub const BASE07: Color = super::void::PURPLE;
+        pub const BASE08: Color = super::status::ERROR;
+        pub const BASE09: Color = super::status::WARNING;
+        pub const BASE0A: Color = super::status::INFO;
+        pub const BASE0B: Color = super::status::SUCCESS;
+        pub const BASE0C: Color = super::void::GREEN;
+        pub const BASE0D: Color = super::void::PURPLE;
+        pub const BASE0E: Color = super::text::DISABLED;
+        pub const BASE0F: Color = super::text::PLACEHOLDER;
+    }
+
+    /// Semantic color mapping for common UI elements.
+    pub mod semantic {
+        use super::Color;
+
+        // Interactive elements
+        pub const LINK: Color = super::void::PURPLE;
+        pub fn link_hover() -> Color {
+            super::void::PURPLE.lighten(10.0)
+        }
+
+        pub const BUTTON: Color = super::void::GREEN;
+        pub fn button_hover() -> Color {
+            super::void::GREEN.lighten(10.0)
+        }
+
+        // Borders and separators
+        pub const BORDER: Color = super::background::ELEVATION_2;
+        pub const SEPARATOR: Color = super::background::ELEVATION_1;
+
+        // Selection and focus
+        pub const SELECTION: Color = super::void::PURPLE_SUBTLE;
+        pub const FOCUS_RING: Color = super::void::PURPLE;
+
+        // Overlays
+        /// Modal overlay with 70% opacity.
+        pub const MODAL_OVERLAY: Color = Color::rgba(0, 0, 0, 178);
+        /// Dropdown shadow with 80% opacity.
+        pub const DROPDOWN_SHADOW: Color = Color::rgba(0, 0, 0, 204);
+    }
+}
+
+/// Runtime color palette that can be persisted as TOML.
+///
+/// The constants in [`theme`] describe the built-in dark theme. A `Theme`
+/// holds the same colors as values, so applications can ship custom palettes
+/// and load them at startup. With the `serde` feature, a theme serializes
+/// with one table per color group, each color a hex string:
+///
+/// ```toml
+/// [background]
+/// base = "#0f1214"
+///
+/// [status]
+/// error = "#ff2e5f"
+/// ```
+///
+/// Keys missing from a file keep their dark-theme value, so a file only needs
+/// to list the colors it overrides. Unknown keys and malformed colors are
+/// rejected so typos do not go unnoticed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct Theme {
+    /// Background elevation hierarchy
+    pub background: BackgroundColors,
+    /// v01d accent colors
+    pub void: VoidColors,
+    /// Text hierarchy
+    pub text: TextColors,
+    /// System status colors
+    pub status: StatusColors,
+}
+
+/// Minimum WCAG AA contrast ratio for body text.
+const WCAG_AA_TEXT: f32 = 4.5;
+
+/// Minimum WCAG AA contrast ratio for large text and UI components.
+const WCAG_AA_LARGE: f32 = 3.0;
+
+/// A foreground/background pair of a [`Theme`] that fails WCAG AA contrast.
+///
+/// Returned by [`Theme::validate`]. Colors are named by their `section.key`
+/// as in theme files, and the `Display` output reads like
+/// `text.secondary fails contrast on background.elevation_3 (3.80:1, needs 4.5:1)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContrastWarning {
+    /// Theme key of the foreground color, such as `text.secondary`
+    pub foreground: &'static str,
+    /// Theme key of the background color, such as `background.elevation_3`
+    pub background: &'static str,
+    /// Actual contrast ratio of the pair
+    pub ratio: f32,
+    /// Contrast ratio WCAG AA requires for the pair
+    pub required: f32,
+}
+
+impl Display for ContrastWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} fails contrast on {} ({:.2}:1, needs {}:1)",
+            self.foreground, self.background, self.ratio, self.required
+        )
+    }
+}
+
+/// Background colors of a [`Theme`], stored under `[background]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct BackgroundColors {
+    /// Base background color (`base`)
+    pub base: Color,
+    /// First level elevation color (`elevation_1`)
+    pub elevation_1: Color,
+    /// Second level elevation color (`elevation_2`)
+    pub elevation_2: Color,
+    /// Third level elevation color (`elevation_3`)
+    pub elevation_3: Color,
+}
+
+/// Accent colors of a [`Theme`], stored under `[void]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct VoidColors {
+    /// Primary green color (`green`)
+    pub green: Color,
+    /// Primary purple color (`purple`)
+    pub purple: Color,
+}
+
+/// Text colors of a [`Theme`], stored under `[text]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct TextColors {
+    /// Primary text color (`primary`)
+    pub primary: Color,
+    /// Secondary text color (`secondary`)
+    pub secondary: Color,
+    /// Disabled text color (`disabled`)
+    pub disabled: Color,
+    /// Placeholder text color (`placeholder`)
+    pub placeholder: Color,
+}
+
+/// Status colors of a [`Theme`], stored under `[status]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct StatusColors {
+    /// Info status color (`info`)
+    pub info: Color,
+    /// Success status color (`success`)
+    pub success: Color,
+    /// Warning status color (`warning`)
+    pub warning: Color,
+    /// Error status color (`error`)
+    pub error: Color,
+}
+
+impl Theme {
+    /// The built-in dark theme, matching the constants in [`theme`].
+    pub const DARK: Theme = Theme {
+        background: BackgroundColors {
+            base: theme::background::BASE,
+            elevation_1: theme::background::ELEVATION_1,
+            elevation_2: theme::background::ELEVATION_2,
+            elevation_3: theme::background::ELEVATION_3,
+        },
+        void: VoidColors {
+            green: theme::void::GREEN,
+            purple: theme::void::PURPLE,
+        },
+        text: TextColors {
+            primary: theme::text::PRIMARY,
+            secondary: theme::text::SECONDARY,
+            disabled: theme::text::DISABLED,
+            placeholder: theme::text::PLACEHOLDER,
+        },
+        status: StatusColors {
+            info: theme::status::INFO,
+            success: theme::status::SUCCESS,
+            warning: theme::status::WARNING,
+            error: theme::status::ERROR,
+        },
+    };
+
+    /// A high-contrast theme for low-vision users.
+    ///
+    /// White text and saturated accent and status colors on near-black
+    /// backgrounds. Primary and secondary text, accents and status colors
+    /// all reach the WCAG AAA ratio of 7:1 against every background
+    /// elevation. The elevations stay close together so borders and text
+    /// carry the structure rather than subtle shading.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::Theme;
+    ///
+    /// let theme = Theme::HIGH_CONTRAST;
+    /// assert!(theme.validate().is_empty());
+    /// assert!(theme.text.primary.contrast_ratio(&theme.background.base) >= 7.0);
+    /// ```
+    pub const HIGH_CONTRAST: Theme = Theme {
+        background: BackgroundColors {
+            base: Color::rgb(0x00, 0x00, 0x00),
+            elevation_1: Color::rgb(0x0d, 0x0d, 0x0d),
+            elevation_2: Color::rgb(0x17, 0x17, 0x17),
+            elevation_3: Color::rgb(0x21, 0x21, 0x21),
+        },
+        void: VoidColors {
+            green: Color::rgb(0x5c, 0xff, 0xa8),
+            purple: Color::rgb(0xd4, 0xaa, 0xff),
+        },
+        text: TextColors {
+            primary: Color::rgb(0xff, 0xff, 0xff),
+            secondary: Color::rgb(0xe6, 0xe6, 0xe6),
+            disabled: Color::rgb(0x8c, 0x8c, 0x8c),
+            placeholder: Color::rgb(0xa6, 0xa6, 0xa6),
+        },
+        status: StatusColors {
+            info: Color::rgb(0x7c, 0xc8, 0xff),
+            success: Color::rgb(0x7d, 0xff, 0x8a),
+            warning: Color::rgb(0xff, 0xe1, 0x4d),
+            error: Color::rgb(0xff, 0x8a, 0x8a),
+        },
+    };
+
+    /// Parses a theme from TOML, starting from the dark theme.
+    ///
+    /// Accepts any TOML document of the shape produced by
+    /// [`to_toml_string`](Self::to_toml_string). Colors must be hex strings
+    /// as understood by [`Color::from_hex`].
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - TOML document to parse
+    ///
+    /// # Errors
+    ///
+    /// Returns an initialization error pointing at the offending text if the
+    /// document is not valid TOML, a key is unknown or a color cannot be
+    /// parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::{theme, Color, Theme};
+    ///
+    /// let theme = Theme::from_toml_str("[void]\ngreen = \"#00ff00\"").unwrap();
+    /// assert_eq!(theme.void.green, Color::rgb(0, 255, 0));
+    /// assert_eq!(theme.void.purple, theme::void::PURPLE);
+    ///
+    /// let reloaded = Theme::from_toml_str(&theme.to_toml_string()).unwrap();
+    /// assert_eq!(reloaded, theme);
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn from_toml_str(src: &str) -> OxittyResult<Theme> {
+        Self::parse_toml(src, Path::new(""))
+    }
+
+    /// Reads and parses a theme file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the TOML file to load
+    ///
+    /// # Errors
+    ///
+    /// Returns an initialization error if the file cannot be read or does
+    /// not parse as described in [`from_toml_str`](Self::from_toml_str).
+    #[cfg(feature = "serde")]
+    pub fn load_from_path(path: impl AsRef<Path>) -> OxittyResult<Theme> {
+        let path = path.as_ref();
+        let src = fs::read_to_string(path).map_err(|e| {
+            OxittyError::init(
+                path,
+                path.display().to_string(),
+                (0, 0),
+                format!("Failed to read theme file: {}", e),
+            )
+        })?;
+
+        Self::parse_toml(&src, path)
+    }
+
+    /// Serializes every color of the theme to TOML.
+    ///
+    /// The output lists one table per color group and round-trips through
+    /// [`from_toml_str`](Self::from_toml_str).
+    #[cfg(feature = "serde")]
+    pub fn to_toml_string(&self) -> String {
+        toml::to_string(self).expect("theme colors always serialize as strings")
+    }
+
+    /// Deserializes `src`, reporting errors against `path`.
+    #[cfg(feature = "serde")]
+    fn parse_toml(src: &str, path: &Path) -> OxittyResult<Theme> {
+        toml::from_str(src).map_err(|e| {
+            let span = e.span().unwrap_or(0..0);
+            OxittyError::init_at(path, src, span.start, span.len(), e.message()).into()
+        })
+    }
+
+    /// Interpolates every color between this theme and `other`.
+    ///
+    /// Each color is blended with [`Color::mix`], so rendering with the
+    /// result of increasing `t` over a few frames animates a theme switch
+    /// instead of swapping instantly. While [`reduce_motion`] is on, any
+    /// `t` above `0.0` gives `other`, so the switch happens on the first
+    /// frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Theme reached at `t = 1.0`
+    /// * `t` - Interpolation parameter, clamped to 0.0-1.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::{Color, Theme};
+    ///
+    /// let mut target = Theme::DARK;
+    /// target.void.green = Color::rgb(0, 0, 255);
+    ///
+    /// let halfway = Theme::DARK.lerp(&target, 0.5);
+    /// assert_eq!(halfway.void.green, Theme::DARK.void.green.mix(&target.void.green, 0.5));
+    /// assert_eq!(Theme::DARK.lerp(&target, 1.0), target);
+    /// ```
+    pub fn lerp(&self, other: &Theme, t: f32) -> Theme {
+        let t = if reduce_motion() && t > 0.0 {
+            1.0
+        } else {
+            t.clamp(0.0, 1.0)
+        };
+        let mut theme = *self;
+
+        for (section, key, to) in other.entries() {
+            if let Some(color) = theme.color_mut(section, key) {
+                *color = color.mix(&to, t);
             }
         }
+
+        theme
     }
 
-    /// v01d colors and variants.
-    pub mod void {
-        use super::Color;
+    /// Checks key color pairs against WCAG AA contrast requirements.
+    ///
+    /// Primary and secondary text are checked against every background
+    /// elevation at the 4.5:1 body text ratio. Status and accent colors,
+    /// which mark UI elements rather than body text, are checked against
+    /// the base background at 3:1. Disabled and placeholder text are exempt,
+    /// as WCAG does not require inactive content to be legible.
+    ///
+    /// Failing pairs are reported rather than rejected, so a theme that
+    /// does not meet AA can still be used.
+    ///
+    /// # Returns
+    ///
+    /// One warning per failing pair, empty if every pair passes
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::colors::{Color, Theme};
+    ///
+    /// let mut theme = Theme::DARK;
+    /// theme.text.primary = Color::rgb(40, 44, 48);
+    ///
+    /// for warning in theme.validate() {
+    ///     println!("{}", warning);
+    /// }
+    /// assert!(theme.validate().iter().any(|w| w.foreground == "text.primary"));
+    /// ```
+    pub fn validate(&self) -> Vec<ContrastWarning> {
+        let backgrounds = [
+            ("background.base", self.background.base),
+            ("background.elevation_1", self.background.elevation_1),
+            ("background.elevation_2", self.background.elevation_2),
+            ("background.elevation_3", self.background.elevation_3),
+        ];
+        let text = [
+            ("text.primary", self.text.primary),
+            ("text.secondary", self.text.secondary),
+        ];
+        let accents = [
+            ("void.green", self.void.green),
+            ("void.purple", self.void.purple),
+            ("status.info", self.status.info),
+            ("status.success", self.status.success),
+            ("status.warning", self.status.warning),
+            ("status.error", self.status.error),
+        ];
 
-        /// Primary green color.
-        pub const GREEN: Color = Color::rgb(0, 228, 154);
-        /// Subtle green color (15% opacity).
-        pub const GREEN_SUBTLE: Color = Color::rgba(0, 228, 154, 38);
-        /// Primary purple color.
-        pub const PURPLE: Color = Color::rgb(184, 110, 255);
-        /// Subtle purple color (15% opacity).
-        pub const PURPLE_SUBTLE: Color = Color::rgba(184, 110, 255, 38);
+        let text_pairs = text.into_iter().flat_map(|fg| {
+            backgrounds
+                .into_iter()
+                .map(move |bg| (fg, bg, WCAG_AA_TEXT))
+        });
+        let accent_pairs = accents
+            .into_iter()
+            .map(|fg| (fg, backgrounds[0], WCAG_AA_LARGE));
 
-        /// Creates a custom variant of the v01d green color.
-        ///
-        /// # Arguments
-        ///
-        /// * `lightness` - Target lightness value (0-100)
-        pub fn green_variant(lightness: f32) -> Color {
-            let (h, s, _) = GREEN.to_hsl();
-            Color::from_hsl(h, s, lightness)
+        text_pairs
+            .chain(accent_pairs)
+            .filter_map(|((foreground, fg), (background, bg), required)| {
+                let ratio = fg.contrast_ratio(&bg);
+                (ratio < required).then_some(ContrastWarning {
+                    foreground,
+                    background,
+                    ratio,
+                    required,
+                })
+            })
+            .collect()
+    }
+
+    /// Lists every color with its section and key, in file order.
+    fn entries(&self) -> [(&'static str, &'static str, Color); 14] {
+        [
+            ("background", "base", self.background.base),
+            ("background", "elevation_1", self.background.elevation_1),
+            ("background", "elevation_2", self.background.elevation_2),
+            ("background", "elevation_3", self.background.elevation_3),
+            ("void", "green", self.void.green),
+            ("void", "purple", self.void.purple),
+            ("text", "primary", self.text.primary),
+            ("text", "secondary", self.text.secondary),
+            ("text", "disabled", self.text.disabled),
+            ("text", "placeholder", self.text.placeholder),
+            ("status", "info", self.status.info),
+            ("status", "success", self.status.success),
+            ("status", "warning", self.status.warning),
+            ("status", "error", self.status.error),
+        ]
+    }
+
+    /// Returns the color stored under `section.key`, if the key exists.
+    fn color_mut(&mut self, section: &str, key: &str) -> Option<&mut Color> {
+        let color = match (section, key) {
+            ("background", "base") => &mut self.background.base,
+            ("background", "elevation_1") => &mut self.background.elevation_1,
+            ("background", "elevation_2") => &mut self.background.elevation_2,
+            ("background", "elevation_3") => &mut self.background.elevation_3,
+            ("void", "green") => &mut self.void.green,
+            ("void", "purple") => &mut self.void.purple,
+            ("text", "primary") => &mut self.text.primary,
+            ("text", "secondary") => &mut self.text.secondary,
+            ("text", "disabled") => &mut self.text.disabled,
+            ("text", "placeholder") => &mut self.text.placeholder,
+            ("status", "info") => &mut self.status.info,
+            ("status", "success") => &mut self.status.success,
+            ("status", "warning") => &mut self.status.warning,
+            ("status", "error") => &mut self.status.error,
+            _ => return None,
+        };
+
+        Some(color)
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::DARK
+    }
+}
+
+impl Default for BackgroundColors {
+    fn default() -> Self {
+        Theme::DARK.background
+    }
+}
+
+impl Default for VoidColors {
+    fn default() -> Self {
+        Theme::DARK.void
+    }
+}
+
+impl Default for TextColors {
+    fn default() -> Self {
+        Theme::DARK.text
+    }
+}
+
+impl Default for StatusColors {
+    fn default() -> Self {
+        Theme::DARK.status
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use theme::{background, semantic, status, void};
+
+    /// Helper function to round HSL values for testing.
+    fn round_hsl(hsl: (f32, f32, f32)) -> (f32, f32, f32) {
+        (
+            (hsl.0 * 10.0).round() / 10.0,
+            (hsl.1 * 10.0).round() / 10.0,
+            (hsl.2 * 10.0).round() / 10.0,
+        )
+    }
+
+    #[test]
+    fn test_color_conversions() {
+        let color = Color::rgb(255, 128, 0);
+        let owo = color.to_owo_rgb();
+        let ratatui = color.to_ratatui();
+
+        assert_eq!(owo, owo_colors::Rgb(255, 128, 0));
+        assert_eq!(ratatui, RatatuiColor::Rgb(255, 128, 0));
+    }
+
+    #[test]
+    fn test_color_animator_converges() {
+        let from = Color::rgb(10, 20, 30);
+        let to = Color::rgba(200, 100, 0, 128);
+        let mut animator = ColorAnimator::new(from);
+        assert!(animator.is_settled());
+        assert_eq!(animator.step(Duration::from_millis(16)), from);
+
+        animator.set_target(to);
+        let frame = Duration::from_millis(16);
+        let mut previous = animator.current().distance(&to);
+        let mut frames = 0;
+        while !animator.is_settled() {
+            let distance = animator.step(frame).distance(&to);
+            assert!(distance <= previous);
+            previous = distance;
+            frames += 1;
+            assert!(frames <= 60, "animation did not settle within a second");
         }
+        assert!(frames > 10, "animation settled in {frames} frames");
+        assert_eq!(animator.current(), to);
 
-        /// Creates a custom variant of the v01d purple color.
-        ///
-        /// # Arguments
-        ///
-        /// * `lightness` - Target lightness value (0-100)
-        pub fn purple_variant(lightness: f32) -> Color {
-            let (h, s, _) = PURPLE.to_hsl();
-            Color::from_hsl(h, s, lightness)
+        // Settled animators stop changing
+        assert_eq!(animator.step(frame), to);
+
+        // A zero rate freezes the animation
+        let mut frozen = ColorAnimator::new(from).with_rate(-1.0);
+        frozen.set_target(to);
+        assert_eq!(frozen.step(Duration::from_secs(10)), from);
+        frozen.snap_to(to);
+        assert!(frozen.is_settled());
+    }
+
+    #[test]
+    fn test_reduce_motion_snaps_animations() {
+        let from = Color::rgb(10, 20, 30);
+        let to = Color::rgb(200, 100, 0);
+
+        with_reduce_motion(true, || {
+            assert!(reduce_motion());
+
+            let mut animator = ColorAnimator::new(from);
+            animator.set_target(to);
+            assert_eq!(animator.step(Duration::from_millis(1)), to);
+            assert!(animator.is_settled());
+
+            let mut target = Theme::DARK;
+            target.void.green = Color::rgb(0, 0, 255);
+            assert_eq!(Theme::DARK.lerp(&target, 0.1), target);
+            assert_eq!(Theme::DARK.lerp(&target, 0.0), Theme::DARK);
+
+            // Nested overrides restore the outer one
+            with_reduce_motion(false, || {
+                let mut animator = ColorAnimator::new(from);
+                animator.set_target(to);
+                assert_ne!(animator.step(Duration::from_millis(1)), to);
+            });
+            assert!(reduce_motion());
+        });
+    }
+
+    #[test]
+    fn test_u32_packing() {
+        let color = Color::rgba(1, 2, 3, 4);
+        assert_eq!(color.to_u32(), 0x0401_0203);
+        assert_eq!(Color::from_u32(color.to_u32()), color);
+
+        for color in [
+            Color::TRANSPARENT,
+            Color::WHITE,
+            theme::semantic::MODAL_OVERLAY,
+        ] {
+            assert_eq!(Color::from_u32(color.to_u32()), color);
         }
+        assert_eq!(Color::from_u32(u32::MAX), Color::WHITE);
+        assert_eq!(Color::from_u32(0), Color::TRANSPARENT);
     }
 
-    /// Text hierarchy and variations.
-    pub mod text {
-        use super::Color;
+    #[test]
+    fn test_hex_conversion() {
+        let color = Color::rgb(255, 128, 0);
+        assert_eq!(color.to_hex(), "#ff8000");
 
-        /// Primary text color.
-        pub const PRIMARY: Color = Color::rgb(230, 237, 243);
-        /// Secondary text color.
-        pub const SECONDARY: Color = Color::rgb(139, 148, 158);
-        /// Disabled text color.
-        pub const DISABLED: Color = Color::rgb(106, 115, 125);
-        /// Placeholder text color.
-        pub const PLACEHOLDER: Color = Color::rgb(88, 96, 105);
+        let parsed = Color::from_hex("#ff8000").unwrap();
+        assert_eq!(color, parsed);
 
-        /// Creates a custom text color with specified opacity.
-        ///
-        /// # Arguments
-        ///
-        /// * `base` - Base color to modify
-        /// * `opacity` - Target opacity (0-255)
-        pub fn with_opacity(base: Color, opacity: u8) -> Color {
-            base.with_alpha(opacity)
+        // Fixed-width form always carries alpha and round-trips
+        assert_eq!(color.to_hex_rgba(), "#ff8000ff");
+        assert_eq!(Color::from_hex(&color.to_hex_rgba()), Some(color));
+        let transparent = Color::rgba(255, 128, 0, 64);
+        assert_eq!(transparent.to_hex_rgba(), "#ff800040");
+        assert_eq!(
+            Color::from_hex(&transparent.to_hex_rgba()),
+            Some(transparent)
+        );
+
+        // Short forms repeat each digit
+        assert_eq!(Color::from_hex("f808"), Some(Color::rgba(255, 136, 0, 136)));
+        assert_eq!(Color::from_hex("#f80"), Some(Color::rgb(255, 136, 0)));
+
+        // Test invalid hex strings
+        assert!(Color::from_hex("invalid").is_none());
+        assert!(Color::from_hex("#12345").is_none());
+        assert!(Color::from_hex("+f0").is_none());
+        assert!(Color::from_hex("ffé").is_none());
+    }
+
+    #[test]
+    fn test_from_x11() {
+        assert_eq!(
+            Color::from_x11("rgb:ffff/0000/0000"),
+            Some(Color::rgb(255, 0, 0))
+        );
+        assert_eq!(
+            Color::from_x11("RGB:0000/ffff/0000"),
+            Some(Color::rgb(0, 255, 0))
+        );
+        // Every digit count scales to the same 8-bit range
+        assert_eq!(
+            Color::from_x11("rgb:f/ff/fff"),
+            Some(Color::rgb(255, 255, 255))
+        );
+        assert_eq!(
+            Color::from_x11("rgb:1e1e/2e2e/3e3e"),
+            Some(Color::rgb(30, 46, 62))
+        );
+
+        assert!(Color::from_x11("rgb:ffff/0000").is_none());
+        assert!(Color::from_x11("rgb:ffff/0000/0000/0000").is_none());
+        assert!(Color::from_x11("rgb:fffff/0/0").is_none());
+        assert!(Color::from_x11("rgb:/0/0").is_none());
+        assert!(Color::from_x11("rgba:ffff/0000/0000").is_none());
+        assert!(Color::from_x11("ffff/0000/0000").is_none());
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("rgb:ffff/0000/0000".parse(), Ok(Color::rgb(255, 0, 0)));
+        assert_eq!(" #fff ".parse(), Ok(Color::rgb(255, 255, 255)));
+        assert_eq!("00ff0080".parse(), Ok(Color::rgba(0, 255, 0, 128)));
+
+        let err = "blue".parse::<Color>().unwrap_err();
+        assert_eq!(err.input, "blue");
+        assert!(err.to_string().starts_with("invalid color `blue`"));
+    }
+
+    #[test]
+    fn test_color_manipulation() {
+        let red = Color::rgb(255, 0, 0);
+        assert_eq!((0.0, 100.0, 50.0), round_hsl(red.to_hsl()));
+
+        let blue = Color::rgb(0, 0, 255);
+        assert_eq!((240.0, 100.0, 50.0), round_hsl(blue.to_hsl()));
+
+        let lightened = red.lighten(20.0);
+        let (_, _, l) = lightened.to_hsl();
+        assert!(l > 50.0);
+
+        let darkened = red.darken(20.0);
+        let (_, _, l) = darkened.to_hsl();
+        assert!(l < 50.0);
+    }
+
+    #[test]
+    fn test_hsv_saturation_keeps_brightness() {
+        let pale = Color::rgba(240, 220, 220, 200);
+
+        // HSV keeps the brightest channel and moves the others evenly
+        let hsv = pale.saturate_hsv(20.0);
+        assert_eq!(hsv.rgba_components(), (240, 172, 172, 200));
+        assert_eq!(pale.desaturate_hsv(100.0), Color::rgba(240, 240, 240, 200));
+        assert_eq!(pale.saturate_hsv(20.0).desaturate_hsv(20.0), pale);
+
+        // HSL starts from a high saturation for such a light color, and the
+        // same step also brightens it
+        let (_, hsl_saturation, _) = pale.to_hsl();
+        assert!(hsl_saturation > 35.0);
+        let hsl = pale.saturate(20.0);
+        assert!(hsl.r > pale.r);
+        assert_eq!(pale.saturate(-100.0).rgb_components(), (230, 230, 230));
+
+        // Gray has no hue to saturate towards
+        let gray = Color::rgb(128, 128, 128);
+        assert_eq!(gray.saturate_hsv(50.0), gray);
+        assert_eq!(Color::rgb(0, 0, 0).saturate_hsv(50.0), Color::rgb(0, 0, 0));
+    }
+
+    #[test]
+    fn test_absolute_hsl_setters() {
+        let color = Color::rgba(200, 100, 100, 128);
+
+        let black = color.with_lightness(0.0);
+        assert_eq!(black.rgba_components(), (0, 0, 0, 128));
+
+        let gray = color.with_saturation(0.0);
+        let (r, g, b) = gray.rgb_components();
+        assert!(r == g && g == b);
+        let (_, _, original_l) = color.to_hsl();
+        let (_, gray_s, gray_l) = gray.to_hsl();
+        assert_eq!(gray_s, 0.0);
+        assert!((gray_l - original_l).abs() < 1.0);
+        assert_eq!(gray.rgba_components().3, 128);
+
+        let rotated = Color::rgb(255, 0, 0).with_hue(480.0);
+        assert_eq!(rotated.rgb_components(), (0, 255, 0));
+    }
+
+    #[test]
+    fn test_hsl_hue_wrapping() {
+        assert_eq!(
+            Color::from_hsl(-30.0, 100.0, 50.0),
+            Color::from_hsl(330.0, 100.0, 50.0)
+        );
+        assert_eq!(
+            Color::from_hsl(-390.0, 100.0, 50.0),
+            Color::from_hsl(330.0, 100.0, 50.0)
+        );
+        assert_eq!(
+            Color::from_hsl(360.0, 100.0, 50.0),
+            Color::from_hsl(0.0, 100.0, 50.0)
+        );
+
+        // 300-360 is the magenta-to-red sector
+        assert_eq!(
+            Color::from_hsl(300.0, 100.0, 50.0).rgb_components(),
+            (255, 0, 255)
+        );
+        let (r, g, b) = Color::from_hsl(330.0, 100.0, 50.0).rgb_components();
+        assert_eq!((r, g), (255, 0));
+        assert!(b > 100 && b < 155);
+    }
+
+    #[test]
+    fn test_hue_diff_and_complementary() {
+        let red = Color::rgb(255, 0, 0);
+        let cyan = Color::rgb(0, 255, 255);
+        let orange = Color::rgb(255, 128, 0);
+
+        assert!((red.hue_diff(&cyan) - 180.0).abs() < 0.5);
+        assert!(red.hue_diff(&orange) < 35.0);
+        assert_eq!(red.hue_diff(&red), 0.0);
+
+        // Distance takes the short way round and is symmetric
+        let rose = Color::from_hsl(350.0, 100.0, 50.0);
+        let amber = Color::from_hsl(10.0, 100.0, 50.0);
+        assert!((rose.hue_diff(&amber) - 20.0).abs() < 1.0);
+        assert_eq!(rose.hue_diff(&amber), amber.hue_diff(&rose));
+
+        assert!(red.is_complementary(&cyan, 1.0));
+        assert!(Color::BLUE.is_complementary(&Color::rgb(255, 255, 0), 1.0));
+        assert!(!red.is_complementary(&orange, 10.0));
+    }
+
+    #[test]
+    fn test_hsl_round_trip() {
+        let colors = [
+            Color::rgb(255, 0, 0),
+            Color::rgb(0, 255, 0),
+            Color::rgb(0, 0, 255),
+            Color::rgb(255, 255, 0),
+            Color::rgb(0, 255, 255),
+            Color::rgb(255, 0, 255),
+            Color::rgb(255, 255, 255),
+            Color::rgb(0, 0, 0),
+            Color::rgb(128, 128, 128),
+            void::GREEN,
+            void::PURPLE,
+            status::ERROR,
+        ];
+
+        for color in colors {
+            let (h, s, l) = color.to_hsl();
+            assert_eq!(Color::from_hsl(h, s, l), color, "round trip of {}", color);
         }
     }
 
-    /// System status colors and variants.
-    pub mod status {
-        use super::Color;
+    #[test]
+    fn test_out_of_range_hsl_is_clamped() {
+        assert_eq!(
+            Color::from_hsl(0.0, 200.0, 200.0),
+            Color::rgb(255, 255, 255)
+        );
+        assert_eq!(Color::from_hsl(0.0, -50.0, -50.0), Color::rgb(0, 0, 0));
+        assert_eq!(Color::from_hsl(120.0, 500.0, 50.0), Color::rgb(0, 255, 0));
 
-        /// Info status color.
-        pub const INFO: Color = Color::rgb(41, 187, 255);
-        /// Success status color.
-        pub const SUCCESS: Color = Color::rgb(35, 209, 139);
-        /// Warning status color.
-        pub const WARNING: Color = Color::rgb(255, 191, 0);
-        /// Error status color.
-        pub const ERROR: Color = Color::rgb(255, 46, 95);
+        assert_eq!(f32_to_u8(300.0), 255);
+        assert_eq!(f32_to_u8(-12.0), 0);
+        assert_eq!(f32_to_u8(254.6), 255);
+    }
 
-        /// Subtle variants for backgrounds
-        pub const INFO_SUBTLE: Color = Color::rgba(41, 187, 255, 38);
-        pub const SUCCESS_SUBTLE: Color = Color::rgba(35, 209, 139, 38);
-        pub const WARNING_SUBTLE: Color = Color::rgba(255, 191, 0, 38);
-        pub const ERROR_SUBTLE: Color = Color::rgba(255, 46, 95, 38);
+    #[test]
+    fn test_oklab_mixing() {
+        let black = Color::rgb(0, 0, 0);
+        let white = Color::rgb(255, 255, 255);
 
-        /// Creates a status color variant with custom intensity.
-        ///
-        /// # Arguments
-        ///
-        /// * `base` - Base status color
-        /// * `intensity` - Target intensity (0-100)
-        pub fn variant(base: Color, intensity: f32) -> Color {
-            let (h, s, _) = base.to_hsl();
-            Color::from_hsl(h, s, intensity)
+        // Endpoints survive the round trip through Oklab
+        assert_eq!(black.mix_oklab(&white, 0.0), black);
+        assert_eq!(black.mix_oklab(&white, 1.0), white);
+        for color in [void::GREEN, void::PURPLE, status::INFO] {
+            assert_eq!(color.mix_oklab(&color, 0.5), color);
         }
+
+        // Oklab L = 0.5 is a perceptual mid gray, well below the ~188
+        // produced by averaging in linear light
+        let mid = black.mix_oklab(&white, 0.5);
+        let (r, g, b) = mid.rgb_components();
+        assert!(r == g && g == b);
+        assert!((95..=103).contains(&r), "unexpected mid gray {}", r);
+        assert!(r < 188);
+
+        // Blending complementary hues keeps chroma instead of collapsing to gray
+        let blue = Color::rgb(0, 0, 255);
+        let yellow = Color::rgb(255, 255, 0);
+        let (_, s, _) = blue.mix_oklab(&yellow, 0.5).to_hsl();
+        let (_, linear_s, _) = blue.mix(&yellow, 0.5).to_hsl();
+        assert!(s > linear_s);
+    }
+
+    #[test]
+    fn test_readable_foreground() {
+        let white = Color::rgb(255, 255, 255);
+
+        assert!(background::BASE.is_dark());
+        assert!(white.is_light());
+
+        let on_base = background::BASE.readable_foreground();
+        assert!(on_base.is_light());
+        assert_eq!(on_base, theme::text::PRIMARY);
+
+        let on_white = white.readable_foreground();
+        assert!(on_white.is_dark());
+        assert_eq!(on_white, background::BASE);
+
+        // Bright saturated accents still get dark text
+        assert!(status::WARNING.readable_foreground().is_dark());
+    }
+
+    #[test]
+    fn test_average_and_weighted() {
+        assert_eq!(
+            Color::average(&[Color::RED, Color::BLUE]),
+            Color::rgb(128, 0, 128)
+        );
+        assert_eq!(Color::average(&[Color::GREEN]), Color::GREEN);
+        assert_eq!(Color::average(&[]), Color::TRANSPARENT);
+
+        // Alpha is averaged like any other channel
+        let faded = Color::average(&[Color::rgba(0, 0, 0, 0), Color::rgba(0, 0, 0, 255)]);
+        assert_eq!(faded.a, 128);
+
+        // Weights are relative
+        let a = Color::weighted(&[(Color::BLACK, 1.0), (Color::WHITE, 3.0)]);
+        let b = Color::weighted(&[(Color::BLACK, 0.25), (Color::WHITE, 0.75)]);
+        assert_eq!(a, b);
+        assert_eq!(a, Color::BLACK.mix(&Color::WHITE, 0.75));
+
+        // Negative and NaN weights are ignored
+        let ignored = Color::weighted(&[
+            (Color::RED, 2.0),
+            (Color::BLUE, -1.0),
+            (Color::GREEN, f32::NAN),
+        ]);
+        assert_eq!(ignored, Color::RED);
+        assert_eq!(Color::weighted(&[(Color::RED, 0.0)]), Color::TRANSPARENT);
     }
 
-    /// Base16 theme implementation for terminal compatibility.
-    pub mod base16 {
-        use super::Color;
+    #[test]
+    fn test_gradient() {
+        assert!(Color::RED.gradient(&Color::BLUE, 0).is_empty());
+        assert_eq!(Color::RED.gradient(&Color::BLUE, 1), vec![Color::RED]);
 
-        pub const BASE00: Color = super::background::BASE;
-        pub const BASE01: Color = super::background::ELEVATION_1;
-        pub const BASE02: Color = super::background::ELEVATION_2;
-        pub const BASE03: Color = super::background::ELEVATION_3;
-        pub const BASE04: Color = super::text::SECONDARY;
-        pub const BASE05: Color = super::text::PRIMARY;
-        pub const BASE06: Color = super::void::GREEN;
-        pub const BASE07: Color = super::void::PURPLE;
-        pub const BASE08: Color = super::status::ERROR;
-        pub const BASE09: Color = super::status::WARNING;
-        pub const BASE0A: Color = super::status::INFO;
-        pub const BASE0B: Color = super::status::SUCCESS;
-        pub const BASE0C: Color = super::void::GREEN;
-        pub const BASE0D: Color = super::void::PURPLE;
-        pub const BASE0E: Color = super::text::DISABLED;
-        pub const BASE0F: Color = super::text::PLACEHOLDER;
+        let ramp = Color::RED.gradient(&Color::BLUE, 5);
+        assert_eq!(ramp.len(), 5);
+        assert_eq!(ramp[0], Color::RED);
+        assert_eq!(ramp[2], Color::rgb(128, 0, 128));
+        assert_eq!(ramp[4], Color::BLUE);
     }
 
-    /// Semantic color mapping for common UI elements.
-    pub mod semantic {
-        use super::Color;
+    #[test]
+    fn test_multi_gradient() {
+        let stops = [Color::BLUE, Color::GREEN, Color::RED];
+        assert_eq!(Color::multi_gradient(&stops, 0.5), Color::GREEN);
+        assert_eq!(
+            Color::multi_gradient(&stops, 0.25),
+            Color::BLUE.mix(&Color::GREEN, 0.5)
+        );
+        assert_eq!(
+            Color::multi_gradient(&stops, 0.75),
+            Color::GREEN.mix(&Color::RED, 0.5)
+        );
 
-        // Interactive elements
-        pub const LINK: Color = super::void::PURPLE;
-        pub fn link_hover() -> Color {
-            super::void::PURPLE.lighten(10.0)
-        }
+        // Ends and out-of-range positions clamp to the outer stops
+        assert_eq!(Color::multi_gradient(&stops, 0.0), Color::BLUE);
+        assert_eq!(Color::multi_gradient(&stops, -1.0), Color::BLUE);
+        assert_eq!(Color::multi_gradient(&stops, f32::NAN), Color::BLUE);
+        assert_eq!(Color::multi_gradient(&stops, 1.0), Color::RED);
+        assert_eq!(Color::multi_gradient(&stops, 3.0), Color::RED);
 
-        pub const BUTTON: Color = super::void::GREEN;
-        pub fn button_hover() -> Color {
-            super::void::GREEN.lighten(10.0)
-        }
+        assert_eq!(Color::multi_gradient(&[Color::WHITE], 0.7), Color::WHITE);
+        assert_eq!(Color::multi_gradient(&[], 0.5), Color::TRANSPARENT);
+    }
 
-        // Borders and separators
-        pub const BORDER: Color = super::background::ELEVATION_2;
-        pub const SEPARATOR: Color = super::background::ELEVATION_1;
+    #[test]
+    fn test_premultiply() {
+        let half_white = Color::rgba(255, 255, 255, 128);
+        let premultiplied = half_white.premultiply();
+        assert!(premultiplied.approx_eq(&Color::rgba(128, 128, 128, 128), 1));
+        assert!(premultiplied.unpremultiply().approx_eq(&half_white, 1));
 
-        // Selection and focus
-        pub const SELECTION: Color = super::void::PURPLE_SUBTLE;
-        pub const FOCUS_RING: Color = super::void::PURPLE;
+        // Opaque colors are unchanged both ways
+        let color = Color::rgb(12, 34, 56);
+        assert_eq!(color.premultiply(), color);
+        assert_eq!(color.unpremultiply(), color);
 
-        // Overlays
-        /// Modal overlay with 70% opacity.
-        pub const MODAL_OVERLAY: Color = Color::rgba(0, 0, 0, 178);
-        /// Dropdown shadow with 80% opacity.
-        pub const DROPDOWN_SHADOW: Color = Color::rgba(0, 0, 0, 204);
+        // Round trips stay within rounding error for visible alphas
+        let color = Color::rgba(200, 100, 50, 200);
+        assert!(color.premultiply().unpremultiply().approx_eq(&color, 1));
+
+        // Zero alpha never divides by zero
+        assert_eq!(Color::rgba(255, 0, 0, 0).premultiply(), Color::TRANSPARENT);
+        assert_eq!(
+            Color::rgba(255, 0, 0, 0).unpremultiply(),
+            Color::TRANSPARENT
+        );
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use pretty_assertions::assert_eq;
-    use theme::{background, semantic, status, void};
+    #[test]
+    fn test_color_constants() {
+        assert_eq!(Color::TRANSPARENT.rgba_components(), (0, 0, 0, 0));
+        assert_eq!(Color::WHITE.to_hex(), "#ffffff");
+        assert_eq!(Color::BLACK.to_hex(), "#000000");
+        assert_eq!(Color::RED, Color::rgb(255, 0, 0));
+        assert_eq!(Color::GREEN, Color::rgb(0, 255, 0));
+        assert_eq!(Color::BLUE, Color::rgb(0, 0, 255));
+        assert_eq!(Color::WHITE.invert(), Color::BLACK);
+    }
 
-    /// Helper function to round HSL values for testing.
-    fn round_hsl(hsl: (f32, f32, f32)) -> (f32, f32, f32) {
-        (
-            (hsl.0 * 10.0).round() / 10.0,
-            (hsl.1 * 10.0).round() / 10.0,
-            (hsl.2 * 10.0).round() / 10.0,
-        )
+    #[test]
+    fn test_mix_premultiplied_avoids_halo() {
+        let clear_red = Color::rgba(255, 0, 0, 0);
+        let blue = Color::rgb(0, 0, 255);
+
+        // Straight alpha lets the invisible red bleed into the result
+        assert_eq!(clear_red.mix(&blue, 0.5), Color::rgba(128, 0, 128, 128));
+        // Premultiplied alpha only carries the visible color
+        assert_eq!(
+            clear_red.mix_premultiplied(&blue, 0.5),
+            Color::rgba(0, 0, 255, 128)
+        );
+
+        // Opaque inputs blend the same either way
+        let red = Color::rgb(255, 0, 0);
+        assert_eq!(red.mix_premultiplied(&blue, 0.25), red.mix(&blue, 0.25));
+
+        // Partly transparent inputs are weighted by their alpha
+        let faint_white = Color::rgba(255, 255, 255, 51);
+        let black = Color::rgb(0, 0, 0);
+        assert_eq!(
+            faint_white.mix_premultiplied(&black, 0.5).rgba_components(),
+            (43, 43, 43, 153)
+        );
+
+        assert_eq!(
+            clear_red.mix_premultiplied(&Color::rgba(0, 255, 0, 0), 0.5),
+            Color::rgba(0, 0, 0, 0)
+        );
     }
 
     #[test]
-    fn test_color_conversions() {
-        let color = Color::rgb(255, 128, 0);
-        let owo = color.to_owo_rgb();
-        let ratatui = color.to_ratatui();
+    fn test_color_operators() {
+        // Addition saturates instead of wrapping
+        for other in [Color::BLACK, Color::rgb(10, 20, 30), Color::WHITE] {
+            assert_eq!(Color::WHITE + other, Color::WHITE);
+        }
+        assert_eq!(Color::BLACK - Color::rgb(1, 2, 3), Color::BLACK);
 
-        assert_eq!(owo, owo_colors::Rgb(255, 128, 0));
-        assert_eq!(ratatui, RatatuiColor::Rgb(255, 128, 0));
+        // Scaling by zero is black, alpha is kept
+        let color = Color::rgba(120, 60, 30, 200);
+        assert_eq!((color * 0.0).rgba_components(), (0, 0, 0, 200));
+        assert_eq!(color * 1.0, color);
+        assert_eq!((color * 4.0).rgb_components(), (255, 240, 120));
+        assert_eq!((color * -1.0).rgb_components(), (0, 0, 0));
+
+        // Add and Sub keep the left-hand alpha
+        let tint = Color::rgba(10, 10, 10, 0);
+        assert_eq!((color + tint).rgba_components(), (130, 70, 40, 200));
+        assert_eq!((color - tint).rgba_components(), (110, 50, 20, 200));
     }
 
     #[test]
-    fn test_hex_conversion() {
-        let color = Color::rgb(255, 128, 0);
-        assert_eq!(color.to_hex(), "#ff8000");
+    fn test_distance_and_approx_eq() {
+        let base = Color::rgba(100, 150, 200, 128);
+        let off_by_one = Color::rgba(101, 149, 201, 127);
 
-        let parsed = Color::from_hex("#ff8000").unwrap();
-        assert_eq!(color, parsed);
+        assert!(base.approx_eq(&off_by_one, 1));
+        assert!(!base.approx_eq(&off_by_one, 0));
+        assert!(base.approx_eq(&base, 0));
 
-        // Test invalid hex strings
-        assert!(Color::from_hex("invalid").is_none());
-        assert!(Color::from_hex("#12345").is_none());
+        // A single channel outside the tolerance fails the comparison
+        assert!(!base.approx_eq(&Color::rgba(100, 150, 203, 128), 2));
+
+        assert_eq!(base.distance(&base), 0.0);
+        assert!((base.distance(&off_by_one) - 3f32.sqrt()).abs() < 1e-6);
+        assert_eq!(base.distance(&off_by_one), off_by_one.distance(&base));
+        assert!(
+            (Color::rgb(0, 0, 0).distance(&Color::rgb(255, 255, 255)) - 255.0 * 3f32.sqrt()).abs()
+                < 1e-3
+        );
     }
 
     #[test]
-    fn test_color_manipulation() {
+    fn test_mix_and_lerp_direction() {
         let red = Color::rgb(255, 0, 0);
-        assert_eq!((0.0, 100.0, 50.0), round_hsl(red.to_hsl()));
-
         let blue = Color::rgb(0, 0, 255);
-        assert_eq!((240.0, 100.0, 50.0), round_hsl(blue.to_hsl()));
 
-        let lightened = red.lighten(20.0);
-        let (_, _, l) = lightened.to_hsl();
-        assert!(l > 50.0);
+        // A quarter of the way from red to blue is still mostly red
+        assert_eq!(red.mix(&blue, 0.25), Color::rgb(191, 0, 64));
+        assert_eq!(Color::lerp(red, blue, 0.25), Color::rgb(191, 0, 64));
+        assert_eq!(Color::lerp(blue, red, 0.25), Color::rgb(64, 0, 191));
 
-        let darkened = red.darken(20.0);
-        let (_, _, l) = darkened.to_hsl();
-        assert!(l < 50.0);
+        assert_eq!(Color::lerp(red, blue, 0.0), red);
+        assert_eq!(Color::lerp(red, blue, 1.0), blue);
+        assert_eq!(Color::lerp(red, blue, 2.0), blue);
+
+        // Channels round to nearest, so even blends land on 128 either way
+        assert_eq!(
+            Color::BLACK.mix(&Color::WHITE, 0.5),
+            Color::rgb(128, 128, 128)
+        );
+        assert_eq!(
+            Color::WHITE.mix(&Color::BLACK, 0.5),
+            Color::rgb(128, 128, 128)
+        );
+        assert_eq!(
+            Color::BLACK.mix(&Color::rgb(1, 3, 5), 0.5),
+            Color::rgb(1, 2, 3)
+        );
     }
 
     #[test]
@@ -885,16 +4106,16 @@ mod tests {
         let purple = red.mix(&blue, 0.5);
 
         let (r, g, b) = purple.rgb_components();
-        assert_eq!(r, 127);
+        assert_eq!(r, 128);
         assert_eq!(g, 0);
-        assert_eq!(b, 127);
+        assert_eq!(b, 128);
 
         // Test alpha mixing
         let transparent = Color::rgba(255, 0, 0, 128);
         let opaque = Color::rgb(0, 0, 255);
         let mixed = transparent.mix(&opaque, 0.5);
         let (_, _, _, a) = mixed.rgba_components();
-        assert_eq!(a, 191); // (128 + 255) / 2 floored = 191
+        assert_eq!(a, 192); // (128 + 255) / 2 rounded = 192
     }
 
     #[test]
@@ -921,6 +4142,24 @@ mod tests {
         assert!(l > l1 && l < l2);
     }
 
+    #[test]
+    fn test_elevation_ramp() {
+        let ramp = background::elevation_ramp(6);
+        assert_eq!(ramp.len(), 6);
+        assert_eq!(ramp[0], background::BASE);
+        assert_eq!(ramp[5], background::ELEVATION_3);
+
+        let lightness: Vec<f32> = ramp.iter().map(|color| color.to_hsl().2).collect();
+        assert!(lightness.windows(2).all(|pair| pair[0] < pair[1]));
+
+        assert!(background::elevation_ramp(0).is_empty());
+        assert_eq!(background::elevation_ramp(1), vec![background::BASE]);
+        assert_eq!(
+            background::elevation_ramp(2),
+            vec![background::BASE, background::ELEVATION_3]
+        );
+    }
+
     #[test]
     fn test_semantic_colors() {
         let normal = semantic::BUTTON;
@@ -945,6 +4184,518 @@ mod tests {
         assert!(warning.contains("\x1b["));
     }
 
+    #[test]
+    fn test_from_ratatui() {
+        assert_eq!(
+            Color::from_ratatui(RatatuiColor::Indexed(9)),
+            Some(Color::rgb(255, 0, 0))
+        );
+        assert_eq!(
+            Color::from_ratatui(RatatuiColor::Red),
+            Some(Color::rgb(205, 0, 0))
+        );
+        assert_eq!(
+            Color::from_ratatui(RatatuiColor::Indexed(1)),
+            Color::from_ratatui(RatatuiColor::Red)
+        );
+        assert_eq!(Color::from_ratatui(RatatuiColor::Reset), None);
+
+        // Every named color round-trips through its nearest ANSI match
+        for (named, _) in ANSI16_PALETTE {
+            let color = Color::from_ratatui(named).unwrap();
+            assert_eq!(color.to_ansi16(), named);
+        }
+
+        // Ends of the color cube and grayscale ramp
+        assert_eq!(Color::from_ansi256(16), Color::rgb(0, 0, 0));
+        assert_eq!(Color::from_ansi256(231), Color::rgb(255, 255, 255));
+        assert_eq!(Color::from_ansi256(255), Color::rgb(238, 238, 238));
+    }
+
+    #[test]
+    fn test_over() {
+        let subtle = status::ERROR_SUBTLE.over(&background::BASE);
+        assert_eq!(subtle.a, 255);
+        // 38/255 of the way from the background towards the status color
+        assert_eq!(subtle.rgb_components(), (51, 22, 31));
+
+        assert_eq!(Color::TRANSPARENT.over(&background::BASE), background::BASE);
+        assert_eq!(
+            Color::TRANSPARENT.over(&Color::TRANSPARENT),
+            Color::TRANSPARENT
+        );
+
+        // Translucent over translucent stays translucent
+        let layered = Color::rgba(255, 0, 0, 128).over(&Color::rgba(0, 0, 255, 128));
+        assert_eq!(layered.a, 192);
+        assert!(layered.r > layered.b);
+    }
+
+    #[test]
+    fn test_theme_lerp() {
+        let dark = Theme::DARK;
+        let mut other = Theme::DARK;
+        other.background.base = Color::rgb(0xf5, 0xf5, 0xf5);
+        other.text.primary = Color::rgb(0x10, 0x10, 0x10);
+        other.status.error = Color::rgb(0xc0, 0x00, 0x20);
+
+        assert_eq!(dark.lerp(&other, 0.0), dark);
+        assert_eq!(dark.lerp(&other, 1.0), other);
+
+        // Out-of-range parameters are clamped
+        assert_eq!(dark.lerp(&other, -1.0), dark);
+        assert_eq!(dark.lerp(&other, 2.0), other);
+
+        let halfway = dark.lerp(&other, 0.5);
+        assert_eq!(
+            halfway.background.base,
+            dark.background.base.mix(&other.background.base, 0.5)
+        );
+        assert_eq!(halfway.void, dark.void);
+    }
+
+    #[test]
+    fn test_theme_validate() {
+        assert_eq!(Theme::DARK.validate(), []);
+
+        let mut low = Theme::DARK;
+        low.text.secondary = Color::rgb(50, 54, 58);
+        low.status.error = Color::rgb(40, 20, 25);
+
+        let warnings = low.validate();
+        let secondary: Vec<_> = warnings
+            .iter()
+            .filter(|w| w.foreground == "text.secondary")
+            .collect();
+        assert_eq!(secondary.len(), 4);
+        assert!(secondary.iter().all(|w| w.ratio < w.required));
+        assert!(warnings
+            .iter()
+            .any(|w| w.foreground == "status.error" && w.background == "background.base"));
+
+        let message = secondary[3].to_string();
+        assert!(message.starts_with("text.secondary fails contrast on background.elevation_3"));
+        assert!(message.ends_with("needs 4.5:1)"));
+    }
+
+    #[test]
+    fn test_high_contrast_theme_meets_aaa() {
+        const WCAG_AAA: f32 = 7.0;
+
+        let theme = Theme::HIGH_CONTRAST;
+        assert_eq!(theme.validate(), []);
+
+        let backgrounds = [
+            theme.background.base,
+            theme.background.elevation_1,
+            theme.background.elevation_2,
+            theme.background.elevation_3,
+        ];
+        let foregrounds = [
+            theme.text.primary,
+            theme.text.secondary,
+            theme.void.green,
+            theme.void.purple,
+            theme.status.info,
+            theme.status.success,
+            theme.status.warning,
+            theme.status.error,
+        ];
+        for bg in backgrounds {
+            for fg in foregrounds {
+                let ratio = fg.contrast_ratio(&bg);
+                assert!(ratio >= WCAG_AAA, "{fg} on {bg} is only {ratio:.2}:1");
+            }
+        }
+
+        // Disabled text stays legible but recedes behind secondary text
+        let base = theme.background.base;
+        assert!(theme.text.disabled.contrast_ratio(&base) >= WCAG_AA_TEXT);
+        assert!(
+            theme.text.disabled.contrast_ratio(&base) < theme.text.secondary.contrast_ratio(&base)
+        );
+    }
+
+    #[test]
+    fn test_theme_style_combines_color_and_attributes() {
+        let style = with_color_support(ColorSupport::TrueColor, || {
+            ThemeStyle::new().fg(theme::status::ERROR).bold()
+        });
+        let text = style.apply("Test").to_string();
+
+        let params = text
+            .strip_prefix("\x1b[")
+            .and_then(|rest| rest.split_once('m'))
+            .map(|(params, _)| params)
+            .expect("styled text should start with an SGR sequence");
+        assert!(params.contains("38;2;255;46;95"));
+        assert!(params.split(';').any(|p| p == "1"));
+        assert!(text.contains("Test"));
+        assert_eq!(owo_colors::Style::from(style), style.style());
+    }
+
+    #[test]
+    fn test_theme_style_follows_color_support() {
+        let build = || {
+            ThemeStyle::new()
+                .fg(theme::text::PRIMARY)
+                .bg(theme::background::BASE)
+        };
+
+        let ansi = with_color_support(ColorSupport::Ansi16, build)
+            .apply("x")
+            .to_string();
+        // Light gray on black
+        assert_eq!(ansi, "\x1b[37;40mx\x1b[0m");
+
+        let gray = with_color_support(ColorSupport::Grayscale, build)
+            .apply("x")
+            .to_string();
+        let level = theme::text::PRIMARY.to_gray_level();
+        assert!(gray.contains(&format!("38;5;{level}")));
+
+        let rgb = with_color_support(ColorSupport::TrueColor, build)
+            .apply("x")
+            .to_string();
+        assert!(rgb.contains("38;2;") && rgb.contains("48;2;"));
+    }
+
+    #[test]
+    fn test_hyperlink_framing() {
+        let link = "docs".hyperlink("https://example.com/a").to_string();
+        let (r, g, b) = theme::semantic::LINK.rgb_components();
+        let label = format!("{}", "docs".truecolor(r, g, b));
+
+        assert_eq!(
+            link,
+            format!("\x1b]8;;https://example.com/a\x1b\\{label}\x1b]8;;\x1b\\")
+        );
+
+        // Control characters cannot close the sequence early
+        let link = "x"
+            .hyperlink("https://example.com/\x1b\\\x07evil\n")
+            .to_string();
+        assert!(link.starts_with("\x1b]8;;https://example.com/\\evil\x1b\\"));
+        assert_eq!(
+            link.matches('\x1b').count(),
+            4 + label.matches('\x1b').count()
+        );
+        assert!(!link.contains('\x07'));
+    }
+
+    #[test]
+    fn test_muted_and_emphasized() {
+        let primary = "x".primary().to_string();
+        let muted = "x".muted().to_string();
+        let emphasized = "x".emphasized().to_string();
+
+        assert!(muted.contains("\x1b[38;2;"));
+        assert_ne!(muted, primary);
+        assert_ne!(emphasized, primary);
+        assert_ne!(muted, emphasized);
+
+        // Recolored on the lightness axis, in the expected direction
+        let (_, _, l) = theme::text::PRIMARY.to_hsl();
+        let (_, _, muted_l) = theme::text::PRIMARY.darken(EMPHASIS_SHIFT).to_hsl();
+        let (_, _, emphasized_l) = theme::text::PRIMARY.lighten(EMPHASIS_SHIFT).to_hsl();
+        assert!(muted_l < l && l < emphasized_l);
+    }
+
+    #[test]
+    fn test_emphasis_alongside_ratatui_stylize() {
+        use ratatui::style::{Modifier, Stylize};
+
+        // With both traits in scope `dim` is unambiguously ratatui's
+        let span = "x".dim();
+        assert!(span.style.add_modifier.contains(Modifier::DIM));
+        assert!("x".muted().to_string().contains("\x1b[38;2;"));
+    }
+
+    #[test]
+    fn test_perceptual_lightness_steps() {
+        let lightness = |c: Color| srgb_to_oklab(c.r, c.g, c.b).0;
+        let delta = |from: Color, to: Color| (lightness(from) - lightness(to)).abs();
+
+        let yellow = Color::rgb(255, 255, 0);
+        let blue = Color::rgb(0, 0, 255);
+
+        // HSL darkening changes perceived lightness very unevenly across hues
+        let hsl_gap = (delta(yellow, yellow.darken(20.0)) - delta(blue, blue.darken(20.0))).abs();
+        let perceptual_gap = (delta(yellow, yellow.darken_perceptual(20.0))
+            - delta(blue, blue.darken_perceptual(20.0)))
+        .abs();
+        assert!(perceptual_gap < hsl_gap / 4.0);
+        assert!((delta(blue, blue.darken_perceptual(20.0)) - 0.2).abs() < 0.02);
+
+        // Lightening moves the other way, clamps at white and keeps alpha
+        let dim = Color::rgba(40, 40, 120, 77);
+        let lighter = dim.lighten_perceptual(10.0);
+        assert!(lightness(lighter) > lightness(dim));
+        assert_eq!(lighter.a, 77);
+        assert_eq!(Color::WHITE.lighten_perceptual(50.0), Color::WHITE);
+        assert_eq!(Color::BLACK.darken_perceptual(50.0), Color::BLACK);
+    }
+
+    #[test]
+    fn test_f32_round_trip() {
+        let color = Color::from_rgb_f32(1.0, 0.5, 0.0);
+        assert_eq!(color.rgba_components(), (255, 128, 0, 255));
+
+        let (r, g, b) = color.to_rgb_f32();
+        assert_eq!((r, b), (1.0, 0.0));
+        assert!((g - 0.5).abs() <= 1.0 / 255.0);
+        assert_eq!(Color::from_rgb_f32(r, g, b), color);
+
+        let translucent = Color::rgba(12, 34, 56, 78);
+        let (r, g, b, a) = translucent.to_rgba_f32();
+        assert_eq!(Color::from_rgba_f32(r, g, b, a), translucent);
+
+        // Out-of-range and NaN components are clamped
+        assert_eq!(
+            Color::from_rgba_f32(2.0, -1.0, f32::NAN, 1.5),
+            Color::rgb(255, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_oklch_round_trip() {
+        // A mid-tone teal well inside sRGB
+        let (l, c, h) = (0.7, 0.1, 200.0);
+        let color = Color::from_oklch(l, c, h);
+        let (l2, c2, h2) = color.to_oklch();
+        assert!((l - l2).abs() < 0.01, "lightness {l2}");
+        assert!((c - c2).abs() < 0.01, "chroma {c2}");
+        assert!((h - h2).abs() < 2.0, "hue {h2}");
+
+        // Existing colors survive the trip through OKLCH
+        for color in [void::GREEN, void::PURPLE, status::ERROR, background::BASE] {
+            let (l, c, h) = color.to_oklch();
+            assert!(Color::from_oklch(l, c, h).approx_eq(&color, 1));
+        }
+
+        // Hue wraps and grays report hue 0
+        assert_eq!(Color::from_oklch(0.7, 0.1, -160.0), color);
+        assert_eq!(Color::rgb(90, 90, 90).to_oklch().2, 0.0);
+    }
+
+    #[test]
+    fn test_oklch_gamut_mapping_reduces_chroma() {
+        // Far more chroma than sRGB can show at this lightness
+        let color = Color::from_oklch(0.8, 0.5, 140.0);
+        let (l, c, h) = color.to_oklch();
+
+        assert!((l - 0.8).abs() < 0.01, "lightness {l}");
+        assert!((h - 140.0).abs() < 3.0, "hue {h}");
+        assert!(c < 0.5 && c > 0.1, "chroma {c}");
+    }
+
+    #[test]
+    fn test_clamp_to_gamut() {
+        // Vivid green, blue and magenta well beyond sRGB
+        for (l, c, h) in [(0.8, 0.5, 140.0), (0.45, 0.6, 265.0), (0.6, 0.45, 330.0)] {
+            let (l2, c2, h2) = Color::clamp_to_gamut(l, c, h);
+            assert_eq!((l2, h2), (l, h));
+            assert!(c2 > 0.05 && c2 < c, "chroma {c2}");
+
+            // The mapped color is inside sRGB, right at its edge
+            let hue = h2.to_radians();
+            let (r, g, b) = oklab_to_linear(l2, c2 * hue.cos(), c2 * hue.sin());
+            assert!([r, g, b].iter().all(|c| (-1e-3..=1.0 + 1e-3).contains(c)));
+            assert!([r, g, b].iter().any(|c| *c < 0.01 || *c > 0.99));
+
+            // Its hue survives the round trip through 8-bit RGB
+            let (_, _, h3) = Color::from_oklch(l, c, h).to_oklch();
+            assert!((h3 - h).abs() < 3.0, "hue {h3} for {h}");
+        }
+
+        // In-gamut colors are left alone; grays and bad inputs are tamed
+        assert_eq!(Color::clamp_to_gamut(0.7, 0.1, 200.0), (0.7, 0.1, 200.0));
+        assert_eq!(Color::clamp_to_gamut(1.5, -0.2, -90.0), (1.0, 0.0, 270.0));
+    }
+
+    #[test]
+    fn test_with_rgb_keeps_alpha() {
+        let overlay = Color::rgba(1, 2, 3, 128);
+        assert_eq!(overlay.with_rgb(10, 20, 30), Color::rgba(10, 20, 30, 128));
+        assert_eq!(
+            Color::rgb(1, 2, 3).with_rgb(4, 5, 6).rgba_components().3,
+            255
+        );
+    }
+
+    #[test]
+    fn test_swizzle() {
+        use Channel::*;
+
+        let color = Color::rgb(1, 2, 3);
+        assert_eq!(color.swizzle([B, G, R]), Color::rgb(3, 2, 1));
+        assert_eq!(color.swizzle([R, G, B]), color);
+        assert_eq!(color.swizzle([G, G, G]), Color::rgb(2, 2, 2));
+
+        // Alpha is preserved and swizzles compose
+        let translucent = Color::rgba(1, 2, 3, 40);
+        let rotated = translucent.swizzle([G, B, R]);
+        assert_eq!(rotated, Color::rgba(2, 3, 1, 40));
+        assert_eq!(rotated.swizzle([G, B, R]).swizzle([G, B, R]), translucent);
+    }
+
+    #[test]
+    fn test_grayscale_and_alpha_predicates() {
+        assert!(Color::WHITE.is_grayscale());
+        assert!(Color::rgba(40, 40, 40, 0).is_grayscale());
+        assert!(!Color::RED.is_grayscale());
+        assert!(!Color::rgb(10, 10, 11).is_grayscale());
+        assert!(!theme::void::PURPLE.is_grayscale());
+
+        assert!(Color::TRANSPARENT.is_transparent());
+        assert!(Color::rgba(255, 255, 255, 0).is_transparent());
+        assert!(!Color::rgba(0, 0, 0, 1).is_transparent());
+        assert!(!theme::semantic::MODAL_OVERLAY.is_transparent());
+
+        assert!(Color::BLACK.is_opaque());
+        assert!(theme::void::PURPLE.is_opaque());
+        assert!(!Color::TRANSPARENT.is_opaque());
+        assert!(!theme::semantic::MODAL_OVERLAY.is_opaque());
+
+        // Usable in constant contexts
+        const { assert!(Color::rgb(7, 7, 7).is_grayscale()) };
+    }
+
+    #[test]
+    fn test_from_temperature() {
+        // Daylight is near white
+        let daylight = Color::from_temperature(6500.0);
+        let (r, g, b) = daylight.rgb_components();
+        assert_eq!(r, 255);
+        assert!(g >= 250 && b >= 245, "{daylight:?}");
+
+        // Low temperatures are distinctly warm
+        let (r, g, b) = Color::from_temperature(2000.0).rgb_components();
+        assert_eq!(r, 255);
+        assert!(g < 160 && b < 40, "({r}, {g}, {b})");
+
+        // High temperatures lean blue
+        let (r, _, b) = Color::from_temperature(20000.0).rgb_components();
+        assert!(b > r);
+
+        // Out of range and NaN inputs clamp to the ends
+        assert_eq!(
+            Color::from_temperature(0.0),
+            Color::from_temperature(1000.0)
+        );
+        assert_eq!(
+            Color::from_temperature(f32::NAN),
+            Color::from_temperature(1000.0)
+        );
+        assert_eq!(
+            Color::from_temperature(1e9),
+            Color::from_temperature(40000.0)
+        );
+    }
+
+    #[test]
+    fn test_alpha_f32() {
+        let red = Color::rgb(255, 0, 0);
+        let half = red.with_alpha_f32(0.5);
+        assert_eq!(half.rgba_components(), (255, 0, 0, 128));
+        assert!((half.alpha_f32() - 0.5).abs() <= 1.0 / 255.0);
+
+        // Every 8-bit alpha survives the round trip exactly
+        for alpha in 0..=255u8 {
+            let color = red.with_alpha(alpha);
+            assert_eq!(red.with_alpha_f32(color.alpha_f32()), color);
+        }
+
+        assert_eq!(red.with_alpha_f32(1.5).a, 255);
+        assert_eq!(red.with_alpha_f32(-0.5).a, 0);
+        assert_eq!(red.with_alpha_f32(f32::NAN).a, 0);
+    }
+
+    #[test]
+    fn test_ansi16_mapping() {
+        assert_eq!(Color::RED.to_ansi16(), RatatuiColor::LightRed);
+        assert_eq!(Color::rgb(200, 10, 10).to_ansi16(), RatatuiColor::Red);
+        assert_eq!(
+            Color::rgb(120, 125, 130).to_ansi16(),
+            RatatuiColor::DarkGray
+        );
+        assert_eq!(theme::background::BASE.to_ansi16(), RatatuiColor::Black);
+        assert_eq!(theme::text::PRIMARY.to_ansi16(), RatatuiColor::Gray);
+        assert_eq!(
+            theme::status::INFO.to_ratatui_for(ColorSupport::TrueColor),
+            theme::status::INFO.to_ratatui()
+        );
+    }
+
+    #[test]
+    fn test_gray_levels_follow_luminance() {
+        // Ordered from darkest to lightest
+        let colors = [
+            theme::background::BASE,
+            theme::background::ELEVATION_1,
+            theme::background::ELEVATION_3,
+            theme::text::PLACEHOLDER,
+            theme::text::DISABLED,
+            theme::status::ERROR,
+            theme::text::SECONDARY,
+            theme::status::INFO,
+            theme::void::GREEN,
+            theme::text::PRIMARY,
+        ];
+        let levels: Vec<u8> = colors.iter().map(Color::to_gray_level).collect();
+        assert!(levels.iter().all(|level| (232..=255).contains(level)));
+        assert!(
+            levels.windows(2).all(|pair| pair[0] < pair[1]),
+            "{levels:?}"
+        );
+
+        // Every ramp entry maps to itself
+        for index in 232..=255 {
+            assert_eq!(Color::from_ansi256(index).to_gray_level(), index);
+        }
+        assert_eq!(
+            theme::text::PRIMARY.to_ratatui_for(ColorSupport::Grayscale),
+            RatatuiColor::Indexed(255)
+        );
+    }
+
+    #[test]
+    fn test_color_support_detection_and_override() {
+        use ColorSupport::*;
+
+        assert_eq!(ColorSupport::from_env(None, Some("linux")), Ansi16);
+        assert_eq!(ColorSupport::from_env(None, Some("xterm-16color")), Ansi16);
+        assert_eq!(
+            ColorSupport::from_env(Some("truecolor"), Some("linux")),
+            TrueColor
+        );
+        assert_eq!(
+            ColorSupport::from_env(None, Some("xterm-256color")),
+            TrueColor
+        );
+        assert_eq!(ColorSupport::from_env(None, None), TrueColor);
+
+        // Overrides nest and are restored afterwards
+        let outer = color_support();
+        let inner = with_color_support(Ansi16, || {
+            with_color_support(TrueColor, color_support);
+            color_support()
+        });
+        assert_eq!(inner, Ansi16);
+        assert_eq!(color_support(), outer);
+    }
+
+    #[test]
+    fn test_css_strings() {
+        let color = Color::rgba(18, 52, 86, 51);
+        assert_eq!(color.to_css_rgb(), "rgb(18, 52, 86)");
+        assert_eq!(color.to_css_rgba(), "rgba(18, 52, 86, 0.200)");
+
+        assert_eq!(Color::WHITE.to_css_rgb(), "rgb(255, 255, 255)");
+        assert_eq!(Color::WHITE.to_css_rgba(), "rgba(255, 255, 255, 1.000)");
+        assert_eq!(Color::TRANSPARENT.to_css_rgba(), "rgba(0, 0, 0, 0.000)");
+    }
+
     #[test]
     fn test_color_display() {
         let rgb = Color::rgb(255, 128, 64);
@@ -953,4 +4704,76 @@ mod tests {
         let rgba = Color::rgba(255, 128, 64, 128);
         assert_eq!(rgba.to_string(), "rgba(255, 128, 64, 0.5)");
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_theme_partial_override() {
+        let toml = "# Only the base background changes\n[background]\nbase = \"#010203\"\n";
+        let theme = Theme::from_toml_str(toml).unwrap();
+
+        assert_eq!(theme.background.base, Color::rgb(1, 2, 3));
+        let expected = Theme {
+            background: BackgroundColors {
+                base: Color::rgb(1, 2, 3),
+                ..Theme::DARK.background
+            },
+            ..Theme::default()
+        };
+        assert_eq!(theme, expected);
+
+        // Dotted keys and trailing comments work the same way
+        let theme = Theme::from_toml_str("status.error = \"#ff000080\" # faded").unwrap();
+        assert_eq!(theme.status.error, Color::rgba(255, 0, 0, 128));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_theme_toml_syntax() {
+        let expected = Color::rgb(0x11, 0x22, 0x33);
+
+        // Comment after a table header
+        let theme = Theme::from_toml_str("[background] # overrides\nbase = \"#112233\"").unwrap();
+        assert_eq!(theme.background.base, expected);
+
+        // Literal string
+        let theme = Theme::from_toml_str("[background]\nbase = '#112233'").unwrap();
+        assert_eq!(theme.background.base, expected);
+
+        // Inline table
+        let theme = Theme::from_toml_str("background = { base = \"#112233\" }").unwrap();
+        assert_eq!(theme.background.base, expected);
+        assert_eq!(
+            theme.background.elevation_1,
+            Theme::DARK.background.elevation_1
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_theme_toml_round_trip() {
+        let mut theme = Theme::DARK;
+        theme.void.green = Color::rgb(10, 20, 30);
+        theme.text.placeholder = Color::rgba(1, 2, 3, 4);
+
+        let toml = theme.to_toml_string();
+        assert!(toml.starts_with("[background]\nbase = \"#0f1214\"\n"));
+        assert_eq!(Theme::from_toml_str(&toml).unwrap(), theme);
+
+        let path = std::env::temp_dir().join(format!("oxitty-theme-{}.toml", std::process::id()));
+        std::fs::write(&path, &toml).unwrap();
+        let loaded = Theme::load_from_path(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.unwrap(), theme);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_theme_toml_errors() {
+        assert!(Theme::from_toml_str("[background]\nbase = \"#nothex\"").is_err());
+        assert!(Theme::from_toml_str("[background]\nbase = #010203").is_err());
+        assert!(Theme::from_toml_str("[background]\nbsae = \"#010203\"").is_err());
+        assert!(Theme::from_toml_str("base = \"#010203\"").is_err());
+        assert!(Theme::from_toml_str("[text]\nprimary").is_err());
+        assert!(Theme::load_from_path("/nonexistent/oxitty-theme.toml").is_err());
+    }
 }
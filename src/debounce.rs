@@ -0,0 +1,227 @@
+//! Debouncing of rapid inputs
+//!
+//! A [`Debouncer`] collapses a burst of items into the last one, released
+//! once no new item has arrived for a quiet period. It measures time through
+//! a [`Clock`], so it works under any runtime and can be tested with a
+//! [`ManualClock`](crate::clock::ManualClock).
+//!
+//! Event handlers can [`push`](Debouncer::push) into a shared debouncer and
+//! either poll it with [`try_next`](Debouncer::try_next) from a tick handler
+//! or await [`next`](Debouncer::next) from a background task.
+//!
+//! # Examples
+//!
+//! Debouncing search-as-you-type with a tick handler:
+//!
+//! ```rust
+//! use std::{sync::Arc, time::Duration};
+//! use oxitty::{clock::SystemClock, debounce::Debouncer};
+//!
+//! let query = Arc::new(Debouncer::new(
+//!     Duration::from_millis(200),
+//!     Arc::new(SystemClock),
+//! ));
+//!
+//! // In a key handler, record the latest filter text
+//! query.push(String::from("oxi"));
+//!
+//! // In a tick handler, run the search once typing has paused
+//! if let Some(text) = query.try_next() {
+//!     println!("searching for {text}");
+//! }
+//! ```
+
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use event_listener::Event;
+
+use crate::clock::Clock;
+
+/// Holds back rapidly pushed items until a quiet period has passed.
+///
+/// Each [`push`](Self::push) replaces the pending item and restarts the
+/// quiet period, so only the last item of a burst is ever released.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::{sync::Arc, time::Duration};
+/// use oxitty::{clock::ManualClock, debounce::Debouncer};
+///
+/// let clock = Arc::new(ManualClock::new());
+/// let debouncer = Debouncer::new(Duration::from_millis(100), clock.clone());
+///
+/// debouncer.push("a");
+/// debouncer.push("ab");
+/// assert_eq!(debouncer.try_next(), None);
+///
+/// clock.advance(Duration::from_millis(100));
+/// assert_eq!(debouncer.try_next(), Some("ab"));
+/// ```
+#[derive(Debug)]
+pub struct Debouncer<T> {
+    /// Quiet period required before an item is released
+    interval: Duration,
+    /// Time source for the quiet period
+    clock: Arc<dyn Clock>,
+    /// Latest item and when it was pushed
+    pending: Mutex<Option<(T, Instant)>>,
+    /// Wakes [`next`](Self::next) callers after every push
+    pushed: Event,
+}
+
+impl<T> Debouncer<T> {
+    /// Creates a debouncer releasing items after `interval` without pushes.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - Quiet period required before an item is released
+    /// * `clock` - Time source used to measure the quiet period
+    pub fn new(interval: Duration, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            interval,
+            clock,
+            pending: Mutex::new(None),
+            pushed: Event::new(),
+        }
+    }
+
+    /// Returns the quiet period.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Records a new item, replacing any pending one and restarting the quiet period.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - Latest input
+    pub fn push(&self, item: T) {
+        let now = self.clock.now();
+        *self.pending.lock().unwrap_or_else(|e| e.into_inner()) = Some((item, now));
+        self.pushed.notify(usize::MAX);
+    }
+
+    /// Returns whether an item is waiting for its quiet period to pass.
+    pub fn is_pending(&self) -> bool {
+        self.pending
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .is_some()
+    }
+
+    /// Takes the pending item if its quiet period has passed.
+    ///
+    /// Never waits, which makes it suitable for polling from a tick handler.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(item)` - The last item pushed, no newer than the quiet period
+    /// * `None` - Nothing pending, or the quiet period is still running
+    pub fn try_next(&self) -> Option<T> {
+        self.take_if_quiet().ok()
+    }
+
+    /// Waits for the next item and returns it once its quiet period has passed.
+    ///
+    /// Items pushed while waiting replace the pending one and extend the
+    /// wait, so a steady stream of pushes delays the result until it pauses.
+    pub async fn next(&self) -> T {
+        loop {
+            match self.take_if_quiet() {
+                Ok(item) => return item,
+                Err(Some(remaining)) => self.clock.sleep(remaining).await,
+                Err(None) => {
+                    // Register before re-checking so a push in between still wakes us
+                    let listener = self.pushed.listen();
+                    if self.is_pending() {
+                        continue;
+                    }
+                    listener.await;
+                }
+            }
+        }
+    }
+
+    /// Takes the pending item if it has been quiet for the full interval.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(item)` - The released item
+    /// * `Err(Some(remaining))` - An item is pending for `remaining` longer
+    /// * `Err(None)` - Nothing is pending
+    fn take_if_quiet(&self) -> Result<T, Option<Duration>> {
+        let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+        let (_, pushed_at) = pending.as_ref().ok_or(None)?;
+
+        let quiet = self.clock.now().saturating_duration_since(*pushed_at);
+        if quiet < self.interval {
+            return Err(Some(self.interval - quiet));
+        }
+
+        let (item, _) = pending.take().ok_or(None)?;
+        Ok(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ManualClock;
+    use smol::block_on;
+
+    #[test]
+    fn test_only_last_item_emerges() {
+        let clock = Arc::new(ManualClock::new());
+        let interval = Duration::from_millis(100);
+        let debouncer = Debouncer::new(interval, clock.clone());
+
+        // Three inputs, each arriving before the previous one settled
+        for text in ["o", "ox", "oxi"] {
+            debouncer.push(text);
+            clock.advance(Duration::from_millis(30));
+        }
+
+        assert_eq!(block_on(debouncer.next()), "oxi");
+        // Released a full interval after the last push
+        assert_eq!(clock.elapsed(), Duration::from_millis(60) + interval);
+        assert!(!debouncer.is_pending());
+        assert_eq!(debouncer.try_next(), None);
+    }
+
+    #[test]
+    fn test_try_next_waits_for_quiet_period() {
+        let clock = Arc::new(ManualClock::new());
+        let debouncer = Debouncer::new(Duration::from_millis(50), clock.clone());
+
+        debouncer.push(1);
+        clock.advance(Duration::from_millis(40));
+        assert_eq!(debouncer.try_next(), None);
+
+        // A new push restarts the quiet period
+        debouncer.push(2);
+        clock.advance(Duration::from_millis(40));
+        assert_eq!(debouncer.try_next(), None);
+
+        clock.advance(Duration::from_millis(10));
+        assert_eq!(debouncer.try_next(), Some(2));
+        assert_eq!(debouncer.try_next(), None);
+    }
+
+    #[test]
+    fn test_next_waits_for_push() {
+        let clock = Arc::new(ManualClock::new());
+        let debouncer = Debouncer::new(Duration::from_millis(10), clock.clone());
+
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                std::thread::sleep(Duration::from_millis(20));
+                debouncer.push("late");
+            });
+            assert_eq!(block_on(debouncer.next()), "late");
+        });
+    }
+}
@@ -174,6 +174,54 @@ pub enum OxittyError {
         #[label("channel closed")]
         err_span: SourceSpan,
     },
+
+    /// Represents a terminal that went away, such as a dropped SSH session.
+    ///
+    /// Used instead of [`OxittyError::Terminal`] when writing a frame fails
+    /// because the other end is gone. There is nobody left to show anything
+    /// to, so [`App::run`](crate::App::run) treats it as a request to quit.
+    ///
+    /// # Fields
+    /// * `src` - The source code context where the error occurred
+    /// * `err_span` - The span in the source code pointing to the error location
+    /// * `msg` - A detailed error message describing what went wrong
+    #[diagnostic(code(oxitty::disconnected), url(docsrs))]
+    Disconnected {
+        #[source_code]
+        src: String,
+        #[label("terminal disconnected here")]
+        err_span: SourceSpan,
+        msg: String,
+    },
+}
+
+/// Lightweight category of an [`OxittyError`].
+///
+/// Returned by [`OxittyError::kind`] so callers can branch on the kind of
+/// failure without matching on the variants and their fields.
+///
+/// # Examples
+///
+/// ```
+/// use oxitty::error::{ErrorKind, OxittyError};
+///
+/// let error = OxittyError::channel_closed("event channel", (0, 0));
+/// assert_eq!(error.kind(), ErrorKind::Channel);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// [`OxittyError::Terminal`]
+    Terminal,
+    /// [`OxittyError::Io`]
+    Io,
+    /// [`OxittyError::InitError`]
+    Init,
+    /// [`OxittyError::Event`]
+    Event,
+    /// [`OxittyError::ChannelClosed`]
+    Channel,
+    /// [`OxittyError::Disconnected`]
+    Disconnected,
 }
 
 /// A type alias for Results using OxittyError.
@@ -200,6 +248,7 @@ impl Display for OxittyError {
             OxittyError::InitError { msg, .. } => write!(f, "Initialization error: {}", msg),
             OxittyError::Event { msg, .. } => write!(f, "Event error: {}", msg),
             OxittyError::ChannelClosed { .. } => write!(f, "Channel closed"),
+            OxittyError::Disconnected { msg, .. } => write!(f, "Terminal disconnected: {}", msg),
         }
     }
 }
@@ -207,6 +256,18 @@ impl Display for OxittyError {
 impl Error for OxittyError {}
 
 impl OxittyError {
+    /// Returns the category of this error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            OxittyError::Terminal { .. } => ErrorKind::Terminal,
+            OxittyError::Io { .. } => ErrorKind::Io,
+            OxittyError::InitError { .. } => ErrorKind::Init,
+            OxittyError::Event { .. } => ErrorKind::Event,
+            OxittyError::ChannelClosed { .. } => ErrorKind::Channel,
+            OxittyError::Disconnected { .. } => ErrorKind::Disconnected,
+        }
+    }
+
     /// Creates a new terminal error.
     ///
     /// # Arguments
@@ -305,6 +366,45 @@ impl OxittyError {
         }
     }
 
+    /// Creates an initialization error pointing into a loaded config file.
+    ///
+    /// A convenience over [`init`](Self::init) for config parsers: pass the
+    /// full file contents and the byte range of the offending text, and
+    /// miette underlines it when the error is reported. The range is clamped
+    /// to the end of `config_src`, so a bad offset never hides the message.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the config file
+    /// * `config_src` - Full contents of the config file
+    /// * `offset` - Byte offset of the offending text
+    /// * `len` - Byte length of the offending text
+    /// * `msg` - Detailed error message
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxitty::error::OxittyError;
+    ///
+    /// let config = "tick_rate = 50\nfps = fast\n";
+    /// let offset = config.find("fast").unwrap();
+    ///
+    /// let error = OxittyError::init_at("app.toml", config, offset, 4, "Expected a number");
+    /// ```
+    pub fn init_at(
+        path: impl Into<PathBuf>,
+        config_src: impl Into<String>,
+        offset: usize,
+        len: usize,
+        msg: impl Into<String>,
+    ) -> Self {
+        let src = config_src.into();
+        let offset = offset.min(src.len());
+        let len = len.min(src.len() - offset);
+
+        Self::init(path, src, (offset, len), msg)
+    }
+
     /// Creates a new event error.
     ///
     /// # Arguments
@@ -359,6 +459,65 @@ impl OxittyError {
             err_span: err_span.into(),
         }
     }
+
+    /// Creates a new disconnected terminal error.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - Source code context where the error occurred
+    /// * `err_span` - Location in the source code where the error occurred
+    /// * `msg` - Detailed error message
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxitty::error::{ErrorKind, OxittyError};
+    ///
+    /// let error = OxittyError::disconnected(
+    ///     "rendering",
+    ///     (0, 0),
+    ///     "Broken pipe"
+    /// );
+    /// assert_eq!(error.kind(), ErrorKind::Disconnected);
+    /// ```
+    pub fn disconnected(
+        src: impl Into<String>,
+        err_span: impl Into<SourceSpan>,
+        msg: impl Into<String>,
+    ) -> Self {
+        Self::Disconnected {
+            src: src.into(),
+            err_span: err_span.into(),
+            msg: msg.into(),
+        }
+    }
+}
+
+/// Recovers the [`OxittyError`] behind a [`miette::Report`].
+///
+/// [`OxittyResult`] erases the concrete error type, so errors returned by
+/// this crate arrive as reports; this downcasts them back.
+///
+/// # Arguments
+///
+/// * `err` - The report to inspect
+///
+/// # Returns
+///
+/// * `Some(error)` - The report wraps an [`OxittyError`]
+/// * `None` - The report holds some other error type
+///
+/// # Examples
+///
+/// ```
+/// use oxitty::error::{as_oxitty, ErrorKind, OxittyError, OxittyResult};
+///
+/// let result: OxittyResult<()> = Err(OxittyError::io("read", (0, 4), "disk gone").into());
+/// let report = result.unwrap_err();
+/// assert_eq!(as_oxitty(&report).map(OxittyError::kind), Some(ErrorKind::Io));
+/// ```
+pub fn as_oxitty(err: &miette::Report) -> Option<&OxittyError> {
+    err.downcast_ref::<OxittyError>()
 }
 
 #[cfg(test)]
@@ -383,4 +542,72 @@ mod tests {
             _ => panic!("Wrong error variant"),
         }
     }
+
+    #[test]
+    fn test_error_kind() {
+        let cases = [
+            (
+                OxittyError::terminal("src", (0, 0), "msg"),
+                ErrorKind::Terminal,
+            ),
+            (OxittyError::io("src", (0, 0), "msg"), ErrorKind::Io),
+            (
+                OxittyError::init("path", "src", (0, 0), "msg"),
+                ErrorKind::Init,
+            ),
+            (OxittyError::event("src", (0, 0), "msg"), ErrorKind::Event),
+            (
+                OxittyError::channel_closed("src", (0, 0)),
+                ErrorKind::Channel,
+            ),
+            (
+                OxittyError::disconnected("src", (0, 0), "msg"),
+                ErrorKind::Disconnected,
+            ),
+        ];
+
+        for (error, kind) in cases {
+            assert_eq!(error.kind(), kind);
+        }
+    }
+
+    #[test]
+    fn test_init_at_span() {
+        let config = "tick_rate = 50\nfps = fast\n";
+        let offset = config.find("fast").unwrap();
+        let err = OxittyError::init_at("app.toml", config, offset, 4, "Expected a number");
+
+        let label = err
+            .labels()
+            .and_then(|mut labels| labels.next())
+            .expect("init errors carry a label");
+        assert_eq!((label.offset(), label.len()), (offset, 4));
+        assert_eq!(&config[label.offset()..][..label.len()], "fast");
+
+        match err {
+            OxittyError::InitError { path, src, .. } => {
+                assert_eq!(path, PathBuf::from("app.toml"));
+                assert_eq!(src, config);
+            }
+            _ => panic!("Wrong error variant"),
+        }
+
+        // Out-of-range spans are clamped to the source
+        let err = OxittyError::init_at("app.toml", config, offset, 100, "msg");
+        let label = err.labels().and_then(|mut labels| labels.next()).unwrap();
+        assert_eq!(label.offset() + label.len(), config.len());
+        let err = OxittyError::init_at("app.toml", config, 1000, 5, "msg");
+        let label = err.labels().and_then(|mut labels| labels.next()).unwrap();
+        assert_eq!((label.offset(), label.len()), (config.len(), 0));
+    }
+
+    #[test]
+    fn test_as_oxitty() {
+        let report: miette::Report = OxittyError::channel_closed("event channel", (0, 0)).into();
+        let error = as_oxitty(&report).expect("report wraps an OxittyError");
+        assert_eq!(error.kind(), ErrorKind::Channel);
+
+        let foreign = miette::miette!("something else");
+        assert!(as_oxitty(&foreign).is_none());
+    }
 }
@@ -12,6 +12,8 @@
 //! - [`Event`]: Represents different types of terminal and custom events
 //! - [`EventHandler`]: Manages event processing and distribution
 //! - [`CloneableAny`]: Enables type-safe cloning of custom event types
+//! - [`CommandSender`]: Sends typed commands into the loop as custom events
+//! - [`Tagged`]: Custom payload labelled with the event source it came from
 //!
 //! # Examples
 //!
@@ -32,6 +34,7 @@
 //!         Event::Mouse(mouse) => println!("Mouse: {:?}", mouse),
 //!         Event::Resize(w, h) => println!("Resize: {}x{}", w, h),
 //!         Event::Custom(_) => println!("Custom event"),
+//!         Event::Tick(elapsed) => println!("Tick after {:?}", elapsed),
 //!         Event::Quit => println!("Quit"),
 //!     }
 //! }
@@ -39,17 +42,30 @@
 //! # }
 //! ```
 
-use crossterm::event::{Event as CrosstermEvent, KeyEvent, MouseEvent};
-use smol::channel::{bounded, Receiver, Sender};
+use crossterm::event::{
+    Event as CrosstermEvent, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseEvent,
+    MouseEventKind,
+};
+use ratatui::layout::{Position, Rect};
 use std::{
     any::Any,
     clone::Clone,
-    fmt::Debug,
-    sync::atomic::{AtomicBool, Ordering},
-    time::Duration,
+    collections::VecDeque,
+    fmt::{self, Debug},
+    io,
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+        Arc, Mutex, MutexGuard,
+    },
+    time::{Duration, Instant},
 };
 
-use crate::error::{OxittyError, OxittyResult};
+use crate::{
+    clock::{Clock, SystemClock},
+    error::{OxittyError, OxittyResult},
+    runtime::{self, bounded, Receiver, Sender, Stream, TryRecvError, TrySendError},
+};
 
 /// Maximum number of pending events in the channel.
 ///
@@ -57,339 +73,3528 @@ use crate::error::{OxittyError, OxittyResult};
 /// for reasonable event buffering.
 const MAX_EVENTS: usize = 1024;
 
-/// Terminal events that can occur during application execution.
+/// Longest single blocking poll performed by [`EventHandler::run`].
 ///
-/// This enum represents all possible event types that can flow through the event system,
-/// including keyboard input, mouse interactions, terminal resizes, custom events,
-/// and quit signals.
+/// Polling in slices no longer than this keeps `stop()` responsive even when
+/// the configured tick rate is long.
+const STOP_CHECK_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Number of times an interrupted poll or read is retried before giving up.
+///
+/// Signals such as `SIGWINCH` can interrupt the underlying system call; those
+/// failures are transient and should not bring down the event loop.
+const MAX_POLL_RETRIES: u32 = 3;
+
+/// Upper bound for [`EventHandler::set_max_events_per_tick`].
+///
+/// Keeps a flood of input such as a large paste from starving the render loop.
+const MAX_EVENT_BATCH: usize = 64;
+
+/// Upper bound for [`EventHandler::set_recent_capacity`].
+const MAX_RECENT_EVENTS: usize = MAX_EVENTS;
+
+/// Number of lines a single wheel notch scrolls while Control is held.
+const FAST_SCROLL_STEP: i32 = 5;
+
+/// Normalizes how a shifted character key is represented.
+///
+/// Terminals disagree on how they report Shift with a letter: most send the
+/// uppercase character with SHIFT set (`Char('Q')` + SHIFT), some send the
+/// uppercase character alone (`Char('Q')`), and others, such as those using
+/// the kitty keyboard protocol or Windows consoles, send the lowercase
+/// character with SHIFT set (`Char('q')` + SHIFT). Since the character
+/// already encodes the shift, this folds all three into the uppercase
+/// character without SHIFT so bindings match on every terminal. Other
+/// modifiers and non-character keys are left untouched.
+///
+/// # Arguments
+///
+/// * `code` - The key code as reported by the terminal
+/// * `modifiers` - The modifiers as reported by the terminal
 ///
 /// # Examples
 ///
 /// ```rust
-/// use oxitty::event::Event;
-/// use crossterm::event::{KeyEvent, KeyCode, KeyModifiers};
+/// use crossterm::event::{KeyCode, KeyModifiers};
+/// use oxitty::event::normalize_key;
 ///
-/// let key_event = Event::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::empty()));
-/// let resize_event = Event::Resize(80, 24);
-/// let quit_event = Event::Quit;
+/// let expected = (KeyCode::Char('Q'), KeyModifiers::NONE);
+/// assert_eq!(normalize_key(KeyCode::Char('q'), KeyModifiers::SHIFT), expected);
+/// assert_eq!(normalize_key(KeyCode::Char('Q'), KeyModifiers::SHIFT), expected);
+/// assert_eq!(normalize_key(KeyCode::Char('Q'), KeyModifiers::NONE), expected);
 /// ```
-#[derive(Debug, Clone)]
-pub enum Event {
-    /// Key press events containing keyboard input information
-    Key(KeyEvent),
-    /// Mouse interaction events containing position and button information
-    Mouse(MouseEvent),
-    /// Terminal resize events containing new dimensions (width, height)
-    Resize(u16, u16),
-    /// Custom events for application-specific needs.
-    /// Can contain any type implementing CloneableAny + Send
-    Custom(Box<dyn CloneableAny + Send>),
-    /// Event indicating the event loop should terminate
-    Quit,
+pub fn normalize_key(code: KeyCode, modifiers: KeyModifiers) -> (KeyCode, KeyModifiers) {
+    match code {
+        KeyCode::Char(c) if modifiers.contains(KeyModifiers::SHIFT) => {
+            let mut upper = c.to_uppercase();
+            let c = match (upper.next(), upper.next()) {
+                (Some(u), None) => u,
+                _ => c,
+            };
+            (KeyCode::Char(c), modifiers - KeyModifiers::SHIFT)
+        }
+        _ => (code, modifiers),
+    }
 }
 
-/// A trait for cloning `Any` trait objects in a type-safe manner.
+/// Builds a `(KeyCode, KeyModifiers)` binding from a readable key combination.
+///
+/// Modifiers are written by name and joined with `+`, followed by the key:
+/// a character literal, a [`KeyCode`] variant such as `Enter`, or a variant
+/// with an argument such as `F(5)`. The supported modifiers are `Ctrl`,
+/// `Shift`, `Alt`, `Super`, `Hyper` and `Meta`; any other name fails to
+/// compile.
 ///
-/// This trait enables custom event types to be cloned while maintaining type safety
-/// through the type system. It extends `Any` to allow for dynamic typing while
-/// requiring `Clone` semantics.
+/// The result plugs into [`App::quit_on`](crate::App::quit_on) and
+/// [`KeyEventExt::matches`].
 ///
 /// # Examples
 ///
 /// ```rust
-/// use oxitty::event::CloneableAny;
-///
-/// #[derive(Debug, Clone)]
-/// struct CustomEvent {
-///     message: String,
-/// }
+/// use crossterm::event::{KeyCode, KeyModifiers};
+/// use oxitty::key;
 ///
-/// // Implementation is automatically provided for types that are
-/// // 'static + Any + Clone + Send + Debug
+/// assert_eq!(key!('q'), (KeyCode::Char('q'), KeyModifiers::NONE));
+/// assert_eq!(key!(Alt + Enter), (KeyCode::Enter, KeyModifiers::ALT));
+/// assert_eq!(
+///     key!(Ctrl + Shift + 'p'),
+///     (KeyCode::Char('p'), KeyModifiers::CONTROL | KeyModifiers::SHIFT)
+/// );
+/// assert_eq!(key!(Shift + F(5)), (KeyCode::F(5), KeyModifiers::SHIFT));
 /// ```
-pub trait CloneableAny: Any + Debug {
-    /// Clones the `Any` trait object and returns a boxed clone.
-    fn clone_box(&self) -> Box<dyn CloneableAny + Send>;
+#[macro_export]
+macro_rules! key {
+    (@build [$($modifier:ident)*] $c:literal) => {
+        (
+            $crate::__private::KeyCode::Char($c),
+            $crate::__private::KeyModifiers::NONE $(.union($crate::key!(@modifier $modifier)))*,
+        )
+    };
+    (@build [$($modifier:ident)*] $code:ident) => {
+        (
+            $crate::__private::KeyCode::$code,
+            $crate::__private::KeyModifiers::NONE $(.union($crate::key!(@modifier $modifier)))*,
+        )
+    };
+    (@build [$($modifier:ident)*] $code:ident($($arg:tt)*)) => {
+        (
+            $crate::__private::KeyCode::$code($($arg)*),
+            $crate::__private::KeyModifiers::NONE $(.union($crate::key!(@modifier $modifier)))*,
+        )
+    };
+    (@build [$($modifier:ident)*] $next:ident + $($rest:tt)+) => {
+        $crate::key!(@build [$($modifier)* $next] $($rest)+)
+    };
+    (@modifier Ctrl) => { $crate::__private::KeyModifiers::CONTROL };
+    (@modifier Shift) => { $crate::__private::KeyModifiers::SHIFT };
+    (@modifier Alt) => { $crate::__private::KeyModifiers::ALT };
+    (@modifier Super) => { $crate::__private::KeyModifiers::SUPER };
+    (@modifier Hyper) => { $crate::__private::KeyModifiers::HYPER };
+    (@modifier Meta) => { $crate::__private::KeyModifiers::META };
+    ($($key:tt)+) => {
+        $crate::key!(@build [] $($key)+)
+    };
 }
 
-impl<T> CloneableAny for T
-where
-    T: 'static + std::any::Any + Clone + Send + Debug,
-{
-    fn clone_box(&self) -> Box<dyn CloneableAny + Send> {
-        Box::new(self.clone())
-    }
+/// Key binding matching for [`KeyEvent`].
+pub trait KeyEventExt {
+    /// Returns whether the key has exactly the given code and modifiers.
+    ///
+    /// Every modifier in `modifiers` must be held and no other may be, so a
+    /// `Ctrl+P` binding rejects `Ctrl+Alt+P`. Both sides are passed through
+    /// [`normalize_key`], so a Shift binding matches however the terminal
+    /// reports shifted letters. The event kind is not checked; combine with
+    /// [`Event::is_key_press`] to ignore releases.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - Key code of the binding
+    /// * `modifiers` - Modifiers the binding requires
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    /// use oxitty::{event::KeyEventExt, key};
+    ///
+    /// let (code, modifiers) = key!(Ctrl + Shift + 'p');
+    ///
+    /// let pressed = KeyEvent::new(KeyCode::Char('P'), KeyModifiers::CONTROL | KeyModifiers::SHIFT);
+    /// assert!(pressed.matches(code, modifiers));
+    ///
+    /// let missing_shift = KeyEvent::new(KeyCode::Char('p'), KeyModifiers::CONTROL);
+    /// assert!(!missing_shift.matches(code, modifiers));
+    /// ```
+    fn matches(&self, code: KeyCode, modifiers: KeyModifiers) -> bool;
 }
 
-impl Clone for Box<dyn CloneableAny + Send> {
-    fn clone(&self) -> Box<dyn CloneableAny + Send> {
-        self.clone_box()
+impl KeyEventExt for KeyEvent {
+    fn matches(&self, code: KeyCode, modifiers: KeyModifiers) -> bool {
+        normalize_key(self.code, self.modifiers) == normalize_key(code, modifiers)
     }
 }
 
-/// Handles event processing and distribution in an asynchronous manner.
-///
-/// `EventHandler` provides a non-blocking interface for processing terminal
-/// and custom events. It uses a bounded channel to prevent memory exhaustion
-/// and provides efficient event delivery.
-///
-/// # Thread Safety
+/// Terminal events that can occur during application execution.
 ///
-/// The handler is thread-safe and can be shared across threads using an `Arc`.
-/// The internal channel ensures thread-safe communication.
+/// This enum represents all possible event types that can flow through the event system,
+/// including keyboard input, mouse interactions, terminal resizes, custom events,
+/// and quit signals.
 ///
 /// # Examples
 ///
 /// ```rust
-/// use std::time::Duration;
-/// use oxitty::event::EventHandler;
+/// use oxitty::event::Event;
+/// use crossterm::event::{KeyEvent, KeyCode, KeyModifiers};
 ///
-/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-/// let handler = EventHandler::new();
+/// let key_event = Event::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::empty()));
+/// let resize_event = Event::Resize(80, 24);
+/// let quit_event = Event::Quit;
+/// ```
 ///
-/// // Process events with 50ms tick rate
-/// handler.run(Duration::from_millis(50)).await?;
+/// # Serialization
 ///
-/// // Check for events without blocking
-/// while let Some(event) = handler.try_recv()? {
-///     println!("Event: {:?}", event);
-/// }
+/// With the `serde` feature enabled, events implement `Serialize` and
+/// `Deserialize` for recording or forwarding input. Custom payloads are
+/// type-erased and cannot be restored: a [`Custom`](Self::Custom) event
+/// serializes as a placeholder carrying only its
+/// [`custom_type_name`](Self::custom_type_name), and deserializing that
+/// placeholder fails.
 ///
-/// // Shutdown gracefully
-/// handler.stop();
-/// # Ok(())
-/// # }
-/// ```
-#[derive(Debug)]
-pub struct EventHandler {
-    /// Sender for event channel
-    tx: Sender<Event>,
-    /// Receiver for event channel
-    rx: Receiver<Event>,
-    /// Flag indicating if the event handler is running
-    running: AtomicBool,
+/// # Equality
+///
+/// Custom events compare equal when both payloads are [`Comparable`]
+/// wrappers around the same concrete type and that type's `PartialEq`
+/// reports them equal. Other payloads never compare equal.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    /// Key press events containing keyboard input information
+    Key(KeyEvent),
+    /// Mouse interaction events containing position and button information
+    Mouse(MouseEvent),
+    /// Terminal resize events containing new dimensions (width, height)
+    Resize(u16, u16),
+    /// Custom events for application-specific needs.
+    /// Can contain any type implementing CloneableAny + Send
+    Custom(Box<dyn CloneableAny + Send>),
+    /// Periodic tick carrying the time elapsed since the previous one.
+    /// Only sent once enabled with [`EventHandler::set_tick_events`]
+    Tick(Duration),
+    /// Event indicating the event loop should terminate
+    Quit,
 }
 
-impl EventHandler {
-    /// Creates a new event handler with a bounded channel.
+impl Event {
+    /// Creates a key press event without modifiers.
     ///
-    /// The channel capacity is limited by `MAX_EVENTS` to prevent memory
-    /// exhaustion while maintaining reasonable event buffering.
+    /// # Arguments
+    ///
+    /// * `code` - The pressed key
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use oxitty::event::EventHandler;
+    /// use oxitty::{event::Event, prelude::KeyCode};
     ///
-    /// let handler = EventHandler::new();
-    /// assert!(handler.is_running());
+    /// assert!(Event::key(KeyCode::Enter).is_enter());
     /// ```
-    pub fn new() -> Self {
-        let (tx, rx) = bounded(MAX_EVENTS);
-        Self {
-            tx,
-            rx,
-            running: AtomicBool::new(true),
-        }
+    pub fn key(code: KeyCode) -> Self {
+        Self::key_mod(code, KeyModifiers::NONE)
     }
 
-    /// Attempts to send an event through the channel without blocking.
+    /// Creates a key press event with the given modifiers held.
     ///
     /// # Arguments
     ///
-    /// * `event` - The event to send through the channel
-    ///
-    /// # Returns
+    /// * `code` - The pressed key
+    /// * `modifiers` - Modifier keys held during the press
     ///
-    /// Returns `Ok(())` if sent successfully, or an error if the channel
-    /// is full or closed.
+    /// # Examples
     ///
-    /// # Errors
+    /// ```rust
+    /// use oxitty::{event::Event, prelude::{KeyCode, KeyModifiers}};
     ///
-    /// Returns a `ChannelClosed` error if the channel has been closed.
-    pub fn try_send(&self, event: Event) -> OxittyResult<()> {
-        self.tx
-            .try_send(event)
-            .map_err(|_| OxittyError::channel_closed("event channel", (0, 0)).into())
+    /// assert!(Event::key_mod(KeyCode::Char('c'), KeyModifiers::CONTROL).is_ctrl_c());
+    /// ```
+    pub fn key_mod(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self::Key(KeyEvent::new(code, modifiers))
     }
 
-    /// Non-blocking attempt to receive an event from the channel.
+    /// Creates a key press event typing the character `c`.
     ///
-    /// # Returns
+    /// No modifiers are set, not even Shift for uppercase characters;
+    /// [`char`](Self::char) and [`is_char`](Self::is_char) accept the event
+    /// either way.
     ///
-    /// * `Ok(Some(Event))` - An event was available
-    /// * `Ok(None)` - No events were ready
-    /// * `Err(_)` - The channel has been closed
+    /// # Arguments
+    ///
+    /// * `c` - The typed character
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use oxitty::event::EventHandler;
+    /// use oxitty::event::Event;
     ///
-    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// let handler = EventHandler::new();
-    ///
-    /// if let Some(event) = handler.try_recv()? {
-    ///     println!("Received: {:?}", event);
-    /// }
-    /// # Ok(())
-    /// # }
+    /// assert!(Event::from_char('q').is_char('q'));
     /// ```
-    pub fn try_recv(&self) -> OxittyResult<Option<Event>> {
-        match self.rx.try_recv() {
-            Ok(event) => Ok(Some(event)),
-            Err(smol::channel::TryRecvError::Empty) => Ok(None),
-            Err(_) => Err(OxittyError::channel_closed("event channel", (0, 0)).into()),
-        }
+    pub fn from_char(c: char) -> Self {
+        Self::key(KeyCode::Char(c))
     }
 
-    /// Starts the event polling task.
+    /// Creates a terminal resize event.
     ///
-    /// Runs an asynchronous loop that polls for terminal events and
-    /// distributes them through the channel. The loop continues until
-    /// `stop()` is called.
+    /// # Arguments
+    ///
+    /// * `width` - New width in columns
+    /// * `height` - New height in rows
+    pub fn resize(width: u16, height: u16) -> Self {
+        Self::Resize(width, height)
+    }
+
+    /// Translates a mouse event into coordinates local to `area`.
+    ///
+    /// The left and top edges of `area` are inclusive and the right and
+    /// bottom edges exclusive, matching [`Rect::contains`].
     ///
     /// # Arguments
     ///
-    /// * `tick_rate` - Duration to wait between polling attempts
+    /// * `area` - The widget area to translate into
     ///
     /// # Returns
     ///
-    /// Returns `Ok(())` when stopped cleanly, or an error if event
-    /// polling fails.
-    pub async fn run(&self, tick_rate: Duration) -> OxittyResult<()> {
-        while self.running.load(Ordering::Acquire) {
-            // Poll for crossterm events
-            if self.poll_events(tick_rate)? {
-                match self.read_event()? {
-                    CrosstermEvent::Key(key) => {
-                        self.try_send(Event::Key(key))?;
-                    }
-                    CrosstermEvent::Mouse(mouse) => {
-                        self.try_send(Event::Mouse(mouse))?;
-                    }
-                    CrosstermEvent::Resize(width, height) => {
-                        self.try_send(Event::Resize(width, height))?;
-                    }
-                    _ => {}
-                }
+    /// * `Some((column, row))` - Position relative to `area`'s origin
+    /// * `None` - Not a mouse event, or the cursor is outside `area`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crossterm::event::{KeyModifiers, MouseEvent, MouseEventKind};
+    /// use oxitty::event::Event;
+    /// use ratatui::layout::Rect;
+    ///
+    /// let click = Event::Mouse(MouseEvent {
+    ///     kind: MouseEventKind::Moved,
+    ///     column: 12,
+    ///     row: 7,
+    ///     modifiers: KeyModifiers::empty(),
+    /// });
+    ///
+    /// assert_eq!(click.mouse_in(Rect::new(10, 5, 20, 10)), Some((2, 2)));
+    /// assert_eq!(click.mouse_in(Rect::new(0, 0, 5, 5)), None);
+    /// ```
+    pub fn mouse_in(&self, area: Rect) -> Option<(u16, u16)> {
+        match self {
+            Event::Mouse(mouse) if area.contains(Position::new(mouse.column, mouse.row)) => {
+                Some((mouse.column - area.x, mouse.row - area.y))
             }
-
-            // Allow other tasks to run
-            smol::future::yield_now().await;
+            _ => None,
         }
-
-        Ok(())
     }
 
-    /// Polls for terminal events.
-    ///
-    /// # Arguments
+    /// Returns the vertical scroll amount of a mouse wheel event.
     ///
-    /// * `tick_rate` - Maximum duration to wait for an event
+    /// Holding Control multiplies the step for fast scrolling.
     ///
     /// # Returns
     ///
-    /// * `Ok(true)` - An event is available
-    /// * `Ok(false)` - No event available within tick rate
-    /// * `Err(_)` - Polling failed
-    fn poll_events(&self, tick_rate: Duration) -> OxittyResult<bool> {
-        crossterm::event::poll(tick_rate).map_err(|e| {
-            OxittyError::terminal(
-                "event polling",
-                (0, 0),
-                format!("Failed to poll events: {}", e),
-            )
-            .into()
-        })
+    /// * `Some(n)` - Positive for `ScrollUp`, negative for `ScrollDown`
+    /// * `None` - Not a vertical scroll event
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crossterm::event::{KeyModifiers, MouseEvent, MouseEventKind};
+    /// use oxitty::event::Event;
+    ///
+    /// let scroll = Event::Mouse(MouseEvent {
+    ///     kind: MouseEventKind::ScrollDown,
+    ///     column: 0,
+    ///     row: 0,
+    ///     modifiers: KeyModifiers::empty(),
+    /// });
+    ///
+    /// assert_eq!(scroll.scroll_delta(), Some(-1));
+    /// assert_eq!(Event::Resize(80, 24).scroll_delta(), None);
+    /// ```
+    pub fn scroll_delta(&self) -> Option<i32> {
+        match self {
+            Event::Mouse(mouse) => match mouse.kind {
+                MouseEventKind::ScrollUp => Some(Self::scroll_step(mouse)),
+                MouseEventKind::ScrollDown => Some(-Self::scroll_step(mouse)),
+                _ => None,
+            },
+            _ => None,
+        }
     }
 
-    /// Reads a terminal event.
+    /// Returns the horizontal scroll amount of a mouse wheel event.
+    ///
+    /// Holding Control multiplies the step for fast scrolling.
     ///
     /// # Returns
     ///
-    /// Returns the next available terminal event or an error if
-    /// reading fails.
+    /// * `Some(n)` - Positive for `ScrollRight`, negative for `ScrollLeft`
+    /// * `None` - Not a horizontal scroll event
+    pub fn horizontal_scroll_delta(&self) -> Option<i32> {
+        match self {
+            Event::Mouse(mouse) => match mouse.kind {
+                MouseEventKind::ScrollRight => Some(Self::scroll_step(mouse)),
+                MouseEventKind::ScrollLeft => Some(-Self::scroll_step(mouse)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns `true` for a `Ctrl+C` key press.
     ///
-    /// # Errors
+    /// The CONTROL modifier is required; a plain `c` does not match.
     ///
-    /// Returns a terminal error if reading fails.
-    fn read_event(&self) -> OxittyResult<CrosstermEvent> {
-        crossterm::event::read().map_err(|e| {
-            OxittyError::terminal(
-                "event reading",
-                (0, 0),
-                format!("Failed to read event: {}", e),
-            )
-            .into()
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    /// use oxitty::event::Event;
+    ///
+    /// let ctrl_c = Event::Key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+    /// assert!(ctrl_c.is_ctrl_c());
+    ///
+    /// let plain_c = Event::Key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::empty()));
+    /// assert!(!plain_c.is_ctrl_c());
+    /// ```
+    pub fn is_ctrl_c(&self) -> bool {
+        self.key_event().is_some_and(|key| {
+            matches!(key.code, KeyCode::Char('c' | 'C'))
+                && key.modifiers.contains(KeyModifiers::CONTROL)
         })
     }
 
-    /// Stops the event handler gracefully.
+    /// Returns `true` only for a key event of kind [`KeyEventKind::Press`].
     ///
-    /// Sets the running flag to false, which will cause the event
-    /// polling loop to terminate after the current iteration.
-    pub fn stop(&self) {
-        self.running.store(false, Ordering::Release);
+    /// Terminals using the kitty keyboard protocol report a release, and
+    /// repeats while a key is held, in addition to the press. Acting only on
+    /// presses keeps such terminals from triggering an action twice. Legacy
+    /// terminals cannot report the kind, and crossterm delivers their keys
+    /// as presses, so this is `true` for every key they send.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+    /// use oxitty::event::Event;
+    ///
+    /// let press = Event::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    /// assert!(press.is_key_press());
+    ///
+    /// let release = Event::Key(KeyEvent::new_with_kind(
+    ///     KeyCode::Enter,
+    ///     KeyModifiers::NONE,
+    ///     KeyEventKind::Release,
+    /// ));
+    /// assert!(!release.is_key_press());
+    /// assert_eq!(release.key_kind(), Some(KeyEventKind::Release));
+    /// ```
+    pub fn is_key_press(&self) -> bool {
+        self.key_kind() == Some(KeyEventKind::Press)
     }
 
-    /// Checks if the event handler is currently running.
+    /// Returns whether a key event is a press, repeat or release.
     ///
     /// # Returns
     ///
-    /// Returns true if the handler is running, false otherwise.
+    /// * `Some(kind)` - The kind reported for a key event
+    /// * `None` - Not a key event
+    pub fn key_kind(&self) -> Option<KeyEventKind> {
+        self.key_event().map(|key| key.kind)
+    }
+
+    /// Returns `true` for an Enter key press, regardless of modifiers.
+    pub fn is_enter(&self) -> bool {
+        self.key_event()
+            .is_some_and(|key| key.code == KeyCode::Enter)
+    }
+
+    /// Returns `true` for an Escape key press, regardless of modifiers.
+    pub fn is_escape(&self) -> bool {
+        self.key_event().is_some_and(|key| key.code == KeyCode::Esc)
+    }
+
+    /// Returns `true` if this is a key press typing the character `c`.
+    ///
+    /// See [`Event::char`] for how modifiers are treated.
+    ///
+    /// # Arguments
+    ///
+    /// * `c` - The character to compare against
+    pub fn is_char(&self, c: char) -> bool {
+        self.char() == Some(c)
+    }
+
+    /// Returns the character typed by a key press.
+    ///
+    /// Shift is allowed since it is part of how uppercase and symbol
+    /// characters are typed, and shifted letters are reported uppercase as
+    /// described in [`normalize_key`]. Key presses with Control or Alt held
+    /// are shortcuts rather than text and yield `None`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(c)` - The typed character
+    /// * `None` - Not a character key press, or a Control/Alt shortcut
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    /// use oxitty::event::Event;
+    ///
+    /// let a = Event::Key(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::empty()));
+    /// assert_eq!(a.char(), Some('a'));
+    /// assert!(a.is_char('a'));
+    ///
+    /// let alt_a = Event::Key(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::ALT));
+    /// assert_eq!(alt_a.char(), None);
+    /// ```
+    pub fn char(&self) -> Option<char> {
+        let key = self.key_event()?;
+        match normalize_key(key.code, key.modifiers) {
+            (KeyCode::Char(c), modifiers)
+                if !modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                Some(c)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the type name of a custom event's payload.
+    ///
+    /// Lets tests check which custom type was emitted without downcasting.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(name)` - The payload's [`CloneableAny::type_name`]
+    /// * `None` - Not a custom event
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::event::Event;
+    ///
+    /// let event = Event::Custom(Box::new(42u32));
+    /// assert_eq!(event.custom_type_name(), Some("u32"));
+    /// assert_eq!(Event::Quit.custom_type_name(), None);
+    /// ```
+    pub fn custom_type_name(&self) -> Option<&'static str> {
+        match self {
+            // Dispatch on the payload, not the box, to get the concrete type
+            Event::Custom(payload) => Some((**payload).type_name()),
+            _ => None,
+        }
+    }
+
+    /// Returns the key event if this is a key press.
+    fn key_event(&self) -> Option<&KeyEvent> {
+        match self {
+            Event::Key(key) => Some(key),
+            _ => None,
+        }
+    }
+
+    /// Returns the magnitude of a single scroll step for a mouse event.
+    fn scroll_step(mouse: &MouseEvent) -> i32 {
+        if mouse.modifiers.contains(KeyModifiers::CONTROL) {
+            FAST_SCROLL_STEP
+        } else {
+            1
+        }
+    }
+}
+
+/// A trait for cloning `Any` trait objects in a type-safe manner.
+///
+/// This trait enables custom event types to be cloned while maintaining
+/// type safety through the type system. It extends `Any` to allow for
+/// dynamic typing while requiring `Clone` semantics. Equality is optional:
+/// payloads wrapped in [`Comparable`] compare by value, all others compare
+/// unequal.
+///
+/// # Examples
+///
+/// ```rust
+/// use oxitty::event::{CloneableAny, Comparable};
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct CustomEvent {
+///     message: String,
+/// }
+///
+/// // Implementation is automatically provided for types that are
+/// // 'static + Any + Clone + Send + Debug
+/// let a = CustomEvent { message: "saved".into() };
+/// assert!(!a.dyn_eq(&a.clone()));
+///
+/// let a = Comparable::new(a);
+/// assert!(a.dyn_eq(&a.clone()));
+/// assert!(!a.dyn_eq(&Comparable::new(42u32)));
+/// ```
+pub trait CloneableAny: Any + Debug {
+    /// Clones the `Any` trait object and returns a boxed clone.
+    fn clone_box(&self) -> Box<dyn CloneableAny + Send>;
+
+    /// Compares with another type-erased value.
+    ///
+    /// Only [`Comparable`] payloads compare by value; by default, and
+    /// whenever `other` is of a different concrete type, this returns
+    /// `false`.
+    fn dyn_eq(&self, other: &dyn CloneableAny) -> bool {
+        let _ = other;
+        false
+    }
+
+    /// Returns the name of the concrete type, as given by
+    /// [`std::any::type_name`].
+    ///
+    /// The name is meant for diagnostics and test assertions; its exact
+    /// format is not guaranteed to be stable across compiler versions.
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+}
+
+impl<T> CloneableAny for T
+where
+    T: 'static + std::any::Any + Clone + Send + Debug,
+{
+    fn clone_box(&self) -> Box<dyn CloneableAny + Send> {
+        Box::new(self.clone())
+    }
+
+    fn dyn_eq(&self, other: &dyn CloneableAny) -> bool {
+        // Only `Comparable` knows the equality of the value it wraps
+        let this: &dyn Any = self;
+        let other: &dyn Any = other;
+        match (
+            this.downcast_ref::<Comparable>(),
+            other.downcast_ref::<Comparable>(),
+        ) {
+            (Some(this), Some(other)) => (this.eq)(&*this.value, &*other.value),
+            _ => false,
+        }
+    }
+}
+
+/// A custom event payload that compares equal by value.
+///
+/// Payloads only need `Clone` and `Debug` to travel in [`Event::Custom`],
+/// so they cannot be compared in general. Wrapping a `PartialEq` payload
+/// opts it in to [`CloneableAny::dyn_eq`], and with it to equality of
+/// [`Event`] and [`Tagged`], for deduplication or test assertions.
+///
+/// # Examples
+///
+/// ```rust
+/// use oxitty::event::{Comparable, Event};
+///
+/// let saved = Event::Custom(Box::new(Comparable::new("saved")));
+/// assert_eq!(saved, Event::Custom(Box::new(Comparable::new("saved"))));
+/// assert_ne!(saved, Event::Custom(Box::new("saved")));
+///
+/// let Event::Custom(payload) = saved else { unreachable!() };
+/// let any: &dyn std::any::Any = &*payload;
+/// let comparable = any.downcast_ref::<Comparable>().unwrap();
+/// assert_eq!(comparable.downcast_ref::<&str>(), Some(&"saved"));
+/// ```
+#[derive(Clone)]
+pub struct Comparable {
+    /// The wrapped payload
+    value: Box<dyn CloneableAny + Send>,
+    /// `PartialEq` of the wrapped type, `false` for any other type
+    eq: fn(&dyn CloneableAny, &dyn CloneableAny) -> bool,
+}
+
+impl Comparable {
+    /// Wraps a payload that compares with `PartialEq`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The custom event payload
+    pub fn new<T: CloneableAny + PartialEq + Send>(value: T) -> Self {
+        Self {
+            value: Box::new(value),
+            eq: |a, b| {
+                let (a, b): (&dyn Any, &dyn Any) = (a, b);
+                matches!((a.downcast_ref::<T>(), b.downcast_ref::<T>()), (Some(a), Some(b)) if a == b)
+            },
+        }
+    }
+
+    /// Returns the wrapped payload as `T`, or `None` if it has another type.
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        let value: &dyn Any = &*self.value;
+        value.downcast_ref()
+    }
+
+    /// Unwraps the payload.
+    pub fn into_inner(self) -> Box<dyn CloneableAny + Send> {
+        self.value
+    }
+}
+
+impl Debug for Comparable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Comparable").field(&self.value).finish()
+    }
+}
+
+impl Clone for Box<dyn CloneableAny + Send> {
+    fn clone(&self) -> Box<dyn CloneableAny + Send> {
+        // Dispatch on the inner value: the box itself also implements
+        // `CloneableAny`, and calling it would recurse forever
+        (**self).clone_box()
+    }
+}
+
+impl PartialEq for Box<dyn CloneableAny + Send> {
+    fn eq(&self, other: &Self) -> bool {
+        // Compare the inner values, not the boxes, for the same reason as `clone`
+        (**self).dyn_eq(&**other)
+    }
+}
+
+/// A custom event payload labelled with the source it came from.
+///
+/// Events from sources registered with
+/// [`App::add_event_source_tagged`](crate::App::add_event_source_tagged)
+/// reach custom handlers wrapped in this type, so identical payloads from
+/// different producers, such as a file watcher and a network client, can
+/// be told apart.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::any::Any;
+/// use oxitty::event::Tagged;
+///
+/// let tagged = Tagged::new("watcher", Box::new(42u32));
+/// assert_eq!(tagged.source(), "watcher");
+/// assert_eq!(tagged.downcast_ref::<u32>(), Some(&42));
+///
+/// let payload: &dyn Any = tagged.payload();
+/// assert!(payload.is::<u32>());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Tagged {
+    /// Id of the source the payload came from
+    source: &'static str,
+    /// The original custom payload
+    payload: Box<dyn CloneableAny + Send>,
+}
+
+impl Tagged {
+    /// Labels a custom payload with its source.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Id of the producing source
+    /// * `payload` - The custom event payload
+    pub fn new(source: &'static str, payload: Box<dyn CloneableAny + Send>) -> Self {
+        Self { source, payload }
+    }
+
+    /// Returns the id of the source the payload came from.
+    pub fn source(&self) -> &'static str {
+        self.source
+    }
+
+    /// Returns the original custom payload.
+    pub fn payload(&self) -> &dyn CloneableAny {
+        &*self.payload
+    }
+
+    /// Returns the payload as `T`, or `None` if it has another type.
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        let payload: &dyn Any = &*self.payload;
+        payload.downcast_ref()
+    }
+
+    /// Unwraps the original custom payload.
+    pub fn into_payload(self) -> Box<dyn CloneableAny + Send> {
+        self.payload
+    }
+}
+
+impl PartialEq for Tagged {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source && (*self.payload).dyn_eq(other.payload())
+    }
+}
+
+/// Serialized form of [`Event`], with custom payloads reduced to their type name.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "Event")]
+enum EventRepr {
+    Key(KeyEvent),
+    Mouse(MouseEvent),
+    Resize(u16, u16),
+    Custom {
+        type_name: std::borrow::Cow<'static, str>,
+    },
+    Tick(Duration),
+    Quit,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Event {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let repr = match self {
+            Event::Key(key) => EventRepr::Key(*key),
+            Event::Mouse(mouse) => EventRepr::Mouse(*mouse),
+            Event::Resize(width, height) => EventRepr::Resize(*width, *height),
+            Event::Custom(payload) => EventRepr::Custom {
+                type_name: (**payload).type_name().into(),
+            },
+            Event::Tick(elapsed) => EventRepr::Tick(*elapsed),
+            Event::Quit => EventRepr::Quit,
+        };
+        repr.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Event {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match EventRepr::deserialize(deserializer)? {
+            EventRepr::Key(key) => Ok(Event::Key(key)),
+            EventRepr::Mouse(mouse) => Ok(Event::Mouse(mouse)),
+            EventRepr::Resize(width, height) => Ok(Event::Resize(width, height)),
+            EventRepr::Custom { type_name } => Err(serde::de::Error::custom(format!(
+                "custom event `{}` cannot be deserialized",
+                type_name
+            ))),
+            EventRepr::Tick(elapsed) => Ok(Event::Tick(elapsed)),
+            EventRepr::Quit => Ok(Event::Quit),
+        }
+    }
+}
+
+/// Keys that scroll wheel events are translated into.
+///
+/// Lets keyboard navigation double as scroll handling: with the default
+/// mapping, scrolling up or down moves a list selection exactly like the
+/// arrow keys. The modifiers held while scrolling are kept on the key.
+///
+/// # Examples
+///
+/// ```rust
+/// use crossterm::event::{KeyCode, KeyModifiers, MouseEvent, MouseEventKind};
+/// use oxitty::event::{Event, ScrollKeys};
+///
+/// let scroll = Event::Mouse(MouseEvent {
+///     kind: MouseEventKind::ScrollDown,
+///     column: 0,
+///     row: 0,
+///     modifiers: KeyModifiers::NONE,
+/// });
+///
+/// let key = ScrollKeys::default().translate(&scroll).unwrap();
+/// assert!(matches!(key, Event::Key(k) if k.code == KeyCode::Down));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScrollKeys {
+    /// Key sent for [`MouseEventKind::ScrollUp`]
+    pub up: KeyCode,
+    /// Key sent for [`MouseEventKind::ScrollDown`]
+    pub down: KeyCode,
+    /// Key sent for [`MouseEventKind::ScrollLeft`]
+    pub left: KeyCode,
+    /// Key sent for [`MouseEventKind::ScrollRight`]
+    pub right: KeyCode,
+}
+
+impl ScrollKeys {
+    /// Returns the key event a scroll event translates into.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - Event to translate
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Event::Key(_))` - A key press for a scroll event
+    /// * `None` - Any other event, which should be used unchanged
+    pub fn translate(&self, event: &Event) -> Option<Event> {
+        let Event::Mouse(mouse) = event else {
+            return None;
+        };
+        let code = match mouse.kind {
+            MouseEventKind::ScrollUp => self.up,
+            MouseEventKind::ScrollDown => self.down,
+            MouseEventKind::ScrollLeft => self.left,
+            MouseEventKind::ScrollRight => self.right,
+            _ => return None,
+        };
+
+        Some(Event::Key(KeyEvent::new(code, mouse.modifiers)))
+    }
+}
+
+impl Default for ScrollKeys {
+    /// Maps each scroll direction to the matching arrow key.
+    fn default() -> Self {
+        Self {
+            up: KeyCode::Up,
+            down: KeyCode::Down,
+            left: KeyCode::Left,
+            right: KeyCode::Right,
+        }
+    }
+}
+
+/// How [`EventHandler`] treats keys repeated by holding them down.
+///
+/// Terminals supporting the kitty keyboard protocol report held keys as
+/// [`KeyEventKind::Repeat`] events and releases as
+/// [`KeyEventKind::Release`]. Legacy terminals instead send a stream of
+/// identical presses, so [`Throttle`](Self::Throttle) also collapses
+/// identical consecutive presses arriving within the interval. On those
+/// terminals, deliberately pressing the same key twice within the interval
+/// is indistinguishable from holding it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RepeatFilter {
+    /// Forward every key event
+    #[default]
+    Off,
+    /// Drop all [`KeyEventKind::Repeat`] events
+    Drop,
+    /// Forward at most one event per interval for a held key
+    Throttle(Duration),
+}
+
+/// Process signal that [`EventHandler::quit_on_signals`] can turn into a quit.
+///
+/// Maps to the POSIX signal of the same name. Platforms without POSIX
+/// signals accept every variant but never deliver it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Signal {
+    /// `SIGINT`, sent by Ctrl+C outside raw mode
+    Interrupt,
+    /// `SIGTERM`, the polite request to exit sent by `kill` and service managers
+    Terminate,
+    /// `SIGHUP`, sent when the controlling terminal closes
+    Hangup,
+    /// `SIGQUIT`, sent by Ctrl+Backslash outside raw mode
+    Quit,
+}
+
+#[cfg(unix)]
+impl Signal {
+    /// Returns the platform signal number.
+    fn number(self) -> i32 {
+        use signal_hook::consts::signal;
+
+        match self {
+            Signal::Interrupt => signal::SIGINT,
+            Signal::Terminate => signal::SIGTERM,
+            Signal::Hangup => signal::SIGHUP,
+            Signal::Quit => signal::SIGQUIT,
+        }
+    }
+
+    /// Counts a handler listening for the signal.
+    ///
+    /// The first listener installs the fallback that runs the signal's
+    /// default action while nobody listens.
+    fn listen(self) -> io::Result<()> {
+        let number = self.number();
+        let mut listeners = SIGNAL_LISTENERS.lock().unwrap_or_else(|e| e.into_inner());
+        let index = match listeners.iter().position(|l| l.number == number) {
+            Some(index) => index,
+            None => {
+                let unhandled = Arc::new(AtomicBool::new(false));
+                signal_hook::flag::register_conditional_default(number, unhandled.clone())?;
+                listeners.push(SignalListeners {
+                    number,
+                    count: 0,
+                    unhandled,
+                });
+                listeners.len() - 1
+            }
+        };
+
+        let entry = &mut listeners[index];
+        entry.count += 1;
+        entry.unhandled.store(false, Ordering::Release);
+        Ok(())
+    }
+
+    /// Stops counting a handler, restoring the default action after the last one.
+    fn release(self) {
+        let number = self.number();
+        let mut listeners = SIGNAL_LISTENERS.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(entry) = listeners.iter_mut().find(|l| l.number == number) {
+            entry.count = entry.count.saturating_sub(1);
+            if entry.count == 0 {
+                entry.unhandled.store(true, Ordering::Release);
+            }
+        }
+    }
+}
+
+/// Handlers listening for each signal, across all [`EventHandler`]s.
+///
+/// Actions registered with `signal-hook` replace the signal's default
+/// action for the rest of the process, even once they are unregistered,
+/// so every signal keeps one fallback that emulates it instead.
+#[cfg(unix)]
+static SIGNAL_LISTENERS: Mutex<Vec<SignalListeners>> = Mutex::new(Vec::new());
+
+/// Listener count of one signal, see [`SIGNAL_LISTENERS`].
+#[cfg(unix)]
+struct SignalListeners {
+    /// Platform signal number
+    number: i32,
+    /// Handlers currently listening
+    count: usize,
+    /// Lets the fallback run the default action, set while `count` is zero
+    unhandled: Arc<AtomicBool>,
+}
+
+/// Signal actions installed by [`EventHandler::quit_on_signals`].
+///
+/// Dropping the registrations unregisters the actions and hands each
+/// signal back to its default action once no other handler listens.
+#[cfg(unix)]
+#[derive(Debug, Default)]
+struct SignalRegistrations(Mutex<Vec<(Signal, [signal_hook::SigId; 2])>>);
+
+#[cfg(unix)]
+impl Drop for SignalRegistrations {
+    fn drop(&mut self) {
+        let registrations = self.0.get_mut().unwrap_or_else(|e| e.into_inner());
+        for (signal, ids) in registrations.drain(..) {
+            for id in ids {
+                signal_hook::low_level::unregister(id);
+            }
+            signal.release();
+        }
+    }
+}
+
+/// What [`EventHandler::run`] does with terminal events when the channel is full.
+///
+/// Either way, a full channel never stops the polling loop; only a closed
+/// channel does.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Discard the event and count it in [`EventMetrics::dropped`]
+    #[default]
+    Drop,
+    /// Yield to other tasks until the channel has room, then forward the event
+    ///
+    /// Terminal input stays unread while waiting, so nothing is lost but the
+    /// loop falls behind a consumer that stops receiving.
+    Retry,
+}
+
+/// Repeat filter configuration and the last key it let through.
+#[derive(Debug, Default)]
+struct RepeatState {
+    /// Active filter
+    filter: RepeatFilter,
+    /// Code, modifiers and time of the last forwarded key press or repeat
+    last: Option<(KeyCode, KeyModifiers, Instant)>,
+}
+
+/// Counters describing the traffic through an [`EventHandler`].
+///
+/// Obtained from [`EventHandler::metrics`]. A growing `dropped` count means
+/// events arrive faster than the application consumes them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EventMetrics {
+    /// Terminal events read by [`EventHandler::run`]
+    pub received: u64,
+    /// Events queued on the channel, from the terminal or [`EventHandler::try_send`]
+    pub forwarded: u64,
+    /// Events discarded because the channel was full
+    pub dropped: u64,
+}
+
+/// Pre-processor applied by [`EventHandler::run`] to terminal events.
+struct EventFilter(Box<dyn Fn(Event) -> Option<Event> + Send + Sync>);
+
+/// Poll and read functions of a handler that never reads the terminal.
+type NoInput = (
+    fn(Duration) -> io::Result<bool>,
+    fn() -> io::Result<CrosstermEvent>,
+);
+
+impl fmt::Debug for EventFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("EventFilter")
+    }
+}
+
+/// Handles event processing and distribution in an asynchronous manner.
+///
+/// `EventHandler` provides a non-blocking interface for processing terminal
+/// and custom events. It uses a bounded channel to prevent memory exhaustion
+/// and provides efficient event delivery.
+///
+/// # Thread Safety
+///
+/// The handler is thread-safe and can be shared across threads using an `Arc`.
+/// The internal channel ensures thread-safe communication.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use oxitty::event::EventHandler;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let handler = EventHandler::new();
+///
+/// // Process events with 50ms tick rate
+/// handler.run(Duration::from_millis(50)).await?;
+///
+/// // Check for events without blocking
+/// while let Some(event) = handler.try_recv()? {
+///     println!("Event: {:?}", event);
+/// }
+///
+/// // Shutdown gracefully
+/// handler.stop();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct EventHandler {
+    /// Sender for event channel
+    tx: Sender<Event>,
+    /// Receiver for event channel
+    rx: Receiver<Event>,
+    /// Flag indicating if the event handler is running
+    running: AtomicBool,
+    /// Whether terminal polling is suspended until [`resume`](Self::resume)
+    paused: AtomicBool,
+    /// Time source for timeouts
+    clock: Arc<dyn Clock>,
+    /// Most terminal events forwarded per loop iteration
+    max_events_per_tick: AtomicUsize,
+    /// Current polling rate in nanoseconds, read on every loop iteration
+    tick_rate: AtomicU64,
+    /// Whether [`run`](Self::run) queues [`Event::Tick`] once per tick rate
+    tick_events: AtomicBool,
+    /// Terminal events read by the polling loop
+    received: AtomicU64,
+    /// Events queued on the channel
+    forwarded: AtomicU64,
+    /// Events discarded because the channel was full
+    dropped: AtomicU64,
+    /// Filtering of held-down keys
+    repeat: Mutex<RepeatState>,
+    /// Handling of terminal events that do not fit in the channel
+    overflow: Mutex<OverflowPolicy>,
+    /// Whether [`run`](Self::run) reads the real terminal
+    reads_terminal: bool,
+    /// Number of queued events mirrored for diagnostics, 0 when disabled
+    recent_capacity: AtomicUsize,
+    /// Most recently queued events, oldest first
+    recent: Mutex<VecDeque<Event>>,
+    /// Transforms or drops terminal events before they are queued
+    filter: Mutex<Option<Arc<EventFilter>>>,
+    /// Set by a signal registered with [`quit_on_signals`](Self::quit_on_signals)
+    signaled: Arc<AtomicBool>,
+    /// Signal actions to remove when the handler is dropped
+    #[cfg(unix)]
+    signal_registrations: SignalRegistrations,
+    /// Events skipped by [`try_recv_matching`](Self::try_recv_matching), oldest first,
+    /// shared with streams returned by [`stream`](Self::stream)
+    deferred: Arc<Mutex<VecDeque<Event>>>,
+}
+
+impl EventHandler {
+    /// Creates a new event handler with a bounded channel.
+    ///
+    /// The channel capacity is limited by `MAX_EVENTS` to prevent memory
+    /// exhaustion while maintaining reasonable event buffering.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::event::EventHandler;
+    ///
+    /// let handler = EventHandler::new();
+    /// assert!(handler.is_running());
+    /// ```
+    pub fn new() -> Self {
+        Self::with_clock(Arc::new(SystemClock))
+    }
+
+    /// Creates a new event handler that measures timeouts with `clock`.
+    ///
+    /// # Arguments
+    ///
+    /// * `clock` - Time source used by [`recv_timeout`](Self::recv_timeout)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::{sync::Arc, time::Duration};
+    /// use oxitty::{clock::ManualClock, event::EventHandler};
+    ///
+    /// let clock = Arc::new(ManualClock::new());
+    /// let handler = EventHandler::with_clock(clock.clone());
+    ///
+    /// // Times out instantly in real time
+    /// let event = smol::block_on(handler.recv_timeout(Duration::from_secs(60))).unwrap();
+    /// assert!(event.is_none());
+    /// assert_eq!(clock.elapsed(), Duration::from_secs(60));
+    /// ```
+    pub fn with_clock(clock: Arc<dyn Clock>) -> Self {
+        let (tx, rx) = bounded(MAX_EVENTS);
+        Self {
+            tx,
+            rx,
+            running: AtomicBool::new(true),
+            paused: AtomicBool::new(false),
+            clock,
+            max_events_per_tick: AtomicUsize::new(1),
+            tick_rate: AtomicU64::new(0),
+            tick_events: AtomicBool::new(false),
+            received: AtomicU64::new(0),
+            forwarded: AtomicU64::new(0),
+            dropped: AtomicU64::new(0),
+            repeat: Mutex::new(RepeatState::default()),
+            overflow: Mutex::new(OverflowPolicy::default()),
+            reads_terminal: true,
+            recent_capacity: AtomicUsize::new(0),
+            recent: Mutex::new(VecDeque::new()),
+            deferred: Arc::new(Mutex::new(VecDeque::new())),
+            filter: Mutex::new(None),
+            signaled: Arc::new(AtomicBool::new(false)),
+            #[cfg(unix)]
+            signal_registrations: SignalRegistrations::default(),
+        }
+    }
+
+    /// Creates an event handler that never reads the real terminal.
+    ///
+    /// [`run`](Self::run) idles as if the terminal produced no input, so
+    /// the only events are those sent with [`inject`](Self::inject). Use
+    /// it to exercise handler logic in tests without a TTY.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    /// use oxitty::event::{Event, EventHandler};
+    ///
+    /// let handler = EventHandler::test_handler();
+    /// handler
+    ///     .inject(Event::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)))
+    ///     .unwrap();
+    /// assert!(matches!(handler.try_recv().unwrap(), Some(Event::Key(_))));
+    /// ```
+    pub fn test_handler() -> Self {
+        Self {
+            reads_terminal: false,
+            ..Self::new()
+        }
+    }
+
+    /// Installs a pre-processor for terminal events.
+    ///
+    /// [`run`](Self::run) passes every event it reads through `filter`
+    /// before queuing it. Returning `Some` forwards the (possibly modified)
+    /// event and returning `None` drops it. Events sent with
+    /// [`try_send`](Self::try_send) or [`inject`](Self::inject) bypass the
+    /// filter. Installing a filter replaces any previous one.
+    ///
+    /// Use [`set_filter`](Self::set_filter) to install a filter on a shared
+    /// handler, such as the one returned by `App::events`.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - Function mapping each terminal event to the event to queue
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crossterm::event::{KeyCode, KeyEvent};
+    /// use oxitty::event::{Event, EventHandler};
+    ///
+    /// // Ignore the mouse and treat `q` as Escape
+    /// let handler = EventHandler::new().with_filter(|event| match event {
+    ///     Event::Mouse(_) => None,
+    ///     Event::Key(key) if key.code == KeyCode::Char('q') => {
+    ///         Some(Event::Key(KeyEvent::new(KeyCode::Esc, key.modifiers)))
+    ///     }
+    ///     other => Some(other),
+    /// });
+    /// ```
+    pub fn with_filter(
+        self,
+        filter: impl Fn(Event) -> Option<Event> + Send + Sync + 'static,
+    ) -> Self {
+        self.set_filter(filter);
+        self
+    }
+
+    /// Installs a pre-processor for terminal events on a shared handler.
+    ///
+    /// Behaves like [`with_filter`](Self::with_filter) but takes `&self`, so
+    /// it also works on a running handler. The new filter applies from the
+    /// next event [`run`](Self::run) reads.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - Function mapping each terminal event to the event to queue
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use oxitty::event::{Event, EventHandler};
+    ///
+    /// let handler = Arc::new(EventHandler::new());
+    /// handler.set_filter(|event| match event {
+    ///     Event::Mouse(_) => None,
+    ///     other => Some(other),
+    /// });
+    /// assert!(handler.has_filter());
+    /// handler.clear_filter();
+    /// assert!(!handler.has_filter());
+    /// ```
+    pub fn set_filter(&self, filter: impl Fn(Event) -> Option<Event> + Send + Sync + 'static) {
+        *self.filter.lock().unwrap_or_else(|e| e.into_inner()) =
+            Some(Arc::new(EventFilter(Box::new(filter))));
+    }
+
+    /// Removes the event filter, so terminal events are queued unchanged.
+    pub fn clear_filter(&self) {
+        *self.filter.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+
+    /// Returns whether an event filter is installed.
+    pub fn has_filter(&self) -> bool {
+        self.filter
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .is_some()
+    }
+
+    /// Sets how many terminal events [`run`](Self::run) forwards per iteration.
+    ///
+    /// After the first event arrives, up to `max - 1` further events that
+    /// are already available are read without waiting before the loop
+    /// yields, which reduces per-event overhead during pastes or fast key
+    /// repeat. The value is clamped to `1..=64` so rendering is never
+    /// starved; the default of 1 forwards a single event per iteration.
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - Maximum number of events to forward per iteration
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::event::EventHandler;
+    ///
+    /// let handler = EventHandler::new();
+    /// handler.set_max_events_per_tick(16);
+    /// assert_eq!(handler.max_events_per_tick(), 16);
+    ///
+    /// handler.set_max_events_per_tick(0);
+    /// assert_eq!(handler.max_events_per_tick(), 1);
+    /// ```
+    pub fn set_max_events_per_tick(&self, max: usize) {
+        self.max_events_per_tick
+            .store(max.clamp(1, MAX_EVENT_BATCH), Ordering::Release);
+    }
+
+    /// Returns how many terminal events are forwarded per iteration.
+    pub fn max_events_per_tick(&self) -> usize {
+        self.max_events_per_tick.load(Ordering::Acquire)
+    }
+
+    /// Changes the polling rate of a running or future [`run`](Self::run).
+    ///
+    /// The loop reads the rate before every poll, so the change takes effect
+    /// on the next poll without restarting the task. A zero rate disables
+    /// ticks; polls then wait the same short slice used to check for
+    /// [`stop`](Self::stop) instead of spinning.
+    ///
+    /// # Arguments
+    ///
+    /// * `rate` - Duration to wait between polling attempts
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use oxitty::event::EventHandler;
+    ///
+    /// let handler = EventHandler::new();
+    /// handler.set_tick_rate(Duration::from_millis(5));
+    /// assert_eq!(handler.tick_rate(), Duration::from_millis(5));
+    /// ```
+    pub fn set_tick_rate(&self, rate: Duration) {
+        let nanos = u64::try_from(rate.as_nanos()).unwrap_or(u64::MAX);
+        self.tick_rate.store(nanos, Ordering::Release);
+    }
+
+    /// Returns the current polling rate.
+    pub fn tick_rate(&self) -> Duration {
+        Duration::from_nanos(self.tick_rate.load(Ordering::Acquire))
+    }
+
+    /// Enables or disables [`Event::Tick`] events from [`run`](Self::run).
+    ///
+    /// When enabled, the polling loop queues a tick whenever at least one
+    /// [tick rate](Self::tick_rate) has passed since the previous one. Each
+    /// tick carries the time actually elapsed, measured with the handler's
+    /// clock, so animations can advance by real time even when a busy loop
+    /// makes ticks drift. Ticks bypass the [event filter](Self::with_filter)
+    /// and are dropped like any other event while the channel is full.
+    /// Disabled by default; [`App`](crate::App) fires its own ticks.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to queue tick events
+    pub fn set_tick_events(&self, enabled: bool) {
+        self.tick_events.store(enabled, Ordering::Release);
+    }
+
+    /// Returns whether [`run`](Self::run) queues [`Event::Tick`] events.
+    pub fn tick_events(&self) -> bool {
+        self.tick_events.load(Ordering::Acquire)
+    }
+
+    /// Queues [`Event::Quit`] when the process receives any of `signals`.
+    ///
+    /// The signal handler only sets a flag; [`run`](Self::run) checks it on
+    /// every polling slice, also while paused, and queues the quit event
+    /// once there is room in the channel. Server-style applications can
+    /// list [`Signal::Terminate`] so service managers stop them cleanly.
+    /// A signal arriving while an earlier one is still waiting to be
+    /// forwarded triggers its default action, so pressing Ctrl+C twice
+    /// still stops an unresponsive program. Dropping the handler removes
+    /// its registrations, and once no handler listens for a signal it
+    /// triggers its default action again. On platforms without POSIX
+    /// signals this does nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `signals` - Signals that should stop the event loop
+    ///
+    /// # Errors
+    ///
+    /// Returns an IO error if a signal handler cannot be installed, for
+    /// example for a signal the platform forbids handling.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::event::{EventHandler, Signal};
+    ///
+    /// let handler = EventHandler::new();
+    /// handler.quit_on_signals(&[Signal::Interrupt, Signal::Terminate]).unwrap();
+    /// ```
+    pub fn quit_on_signals(&self, signals: &[Signal]) -> OxittyResult<()> {
+        #[cfg(unix)]
+        for &signal in signals {
+            let ids = self.register_quit_signal(signal).map_err(|e| {
+                OxittyError::io(
+                    "signal registration",
+                    (0, 0),
+                    format!("Failed to handle {:?}: {}", signal, e),
+                )
+            })?;
+            self.signal_registrations
+                .0
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .push((signal, ids));
+        }
+        #[cfg(not(unix))]
+        let _ = signals;
+
+        Ok(())
+    }
+
+    /// Installs the actions turning `signal` into a quit, undoing them on failure.
+    #[cfg(unix)]
+    fn register_quit_signal(&self, signal: Signal) -> io::Result<[signal_hook::SigId; 2]> {
+        use signal_hook::{flag, low_level::unregister};
+
+        let number = signal.number();
+        // The fallback goes first so it sees the flag from before this signal
+        let fallback = flag::register_conditional_default(number, self.signaled.clone())?;
+        let quit = match flag::register(number, self.signaled.clone()) {
+            Ok(quit) => quit,
+            Err(e) => {
+                unregister(fallback);
+                return Err(e);
+            }
+        };
+        if let Err(e) = signal.listen() {
+            unregister(fallback);
+            unregister(quit);
+            return Err(e);
+        }
+        Ok([fallback, quit])
+    }
+
+    /// Queues [`Event::Quit`] if a registered signal arrived since the last check.
+    ///
+    /// The flag stays set while the channel is full, so the quit is retried
+    /// on the next slice rather than dropped.
+    fn forward_signal(&self) -> OxittyResult<()> {
+        if !self.signaled.load(Ordering::Acquire) || self.tx.is_full() {
+            return Ok(());
+        }
+        self.signaled.store(false, Ordering::Release);
+        self.queue(Event::Quit)
+    }
+
+    /// Sets how key events from held-down keys are filtered by
+    /// [`run`](Self::run).
+    ///
+    /// Filtering only applies to terminal input; events passed to
+    /// [`try_send`](Self::try_send) are never filtered. Intervals are measured
+    /// with the handler's [`Clock`].
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - The repeat filter to apply
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use oxitty::event::{EventHandler, RepeatFilter};
+    ///
+    /// let handler = EventHandler::new();
+    /// handler.set_repeat_filter(RepeatFilter::Throttle(Duration::from_millis(100)));
+    /// assert_eq!(
+    ///     handler.repeat_filter(),
+    ///     RepeatFilter::Throttle(Duration::from_millis(100))
+    /// );
+    /// ```
+    pub fn set_repeat_filter(&self, filter: RepeatFilter) {
+        *self.repeat.lock().unwrap_or_else(|e| e.into_inner()) = RepeatState { filter, last: None };
+    }
+
+    /// Returns the active repeat filter.
+    pub fn repeat_filter(&self) -> RepeatFilter {
+        self.repeat.lock().unwrap_or_else(|e| e.into_inner()).filter
+    }
+
+    /// Sets what [`run`](Self::run) does with terminal events while the
+    /// channel is full.
+    ///
+    /// Events passed to [`try_send`](Self::try_send) are unaffected and
+    /// always fail immediately on a full channel.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The overflow policy to apply
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::event::{EventHandler, OverflowPolicy};
+    ///
+    /// let handler = EventHandler::new();
+    /// assert_eq!(handler.overflow_policy(), OverflowPolicy::Drop);
+    ///
+    /// handler.set_overflow_policy(OverflowPolicy::Retry);
+    /// assert_eq!(handler.overflow_policy(), OverflowPolicy::Retry);
+    /// ```
+    pub fn set_overflow_policy(&self, policy: OverflowPolicy) {
+        *self.overflow.lock().unwrap_or_else(|e| e.into_inner()) = policy;
+    }
+
+    /// Returns the active overflow policy.
+    pub fn overflow_policy(&self) -> OverflowPolicy {
+        *self.overflow.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Keeps copies of the last `capacity` queued events for diagnostics.
+    ///
+    /// Channels cannot be inspected without consuming them, so a debug
+    /// overlay showing pending input reads this mirror instead. Every event
+    /// successfully queued, from the terminal or
+    /// [`try_send`](Self::try_send), is cloned into a ring buffer; receiving
+    /// events does not remove them. The capacity is capped at the channel
+    /// size, and 0 (the default) disables mirroring and discards the buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Number of events to keep
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::event::{Event, EventHandler};
+    ///
+    /// let handler = EventHandler::new();
+    /// handler.set_recent_capacity(2);
+    /// for width in 1..=3 {
+    ///     handler.try_send(Event::Resize(width, 1)).unwrap();
+    /// }
+    ///
+    /// let recent = handler.recent_events();
+    /// assert!(matches!(recent[..], [Event::Resize(2, 1), Event::Resize(3, 1)]));
+    /// ```
+    pub fn set_recent_capacity(&self, capacity: usize) {
+        let capacity = capacity.min(MAX_RECENT_EVENTS);
+        let mut recent = self.recent.lock().unwrap_or_else(|e| e.into_inner());
+        self.recent_capacity.store(capacity, Ordering::Release);
+
+        let excess = recent.len().saturating_sub(capacity);
+        recent.drain(..excess);
+        if capacity == 0 {
+            recent.shrink_to_fit();
+        }
+    }
+
+    /// Returns how many recent events are mirrored.
+    pub fn recent_capacity(&self) -> usize {
+        self.recent_capacity.load(Ordering::Acquire)
+    }
+
+    /// Returns copies of the most recently queued events, oldest first.
+    ///
+    /// Empty unless enabled with [`set_recent_capacity`](Self::set_recent_capacity).
+    pub fn recent_events(&self) -> Vec<Event> {
+        let recent = self.recent.lock().unwrap_or_else(|e| e.into_inner());
+        recent.iter().cloned().collect()
+    }
+
+    /// Returns the clock used to measure timeouts.
+    pub fn clock(&self) -> &Arc<dyn Clock> {
+        &self.clock
+    }
+
+    /// Returns the event counters accumulated since the handler was created.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::event::{Event, EventHandler};
+    ///
+    /// let handler = EventHandler::new();
+    /// handler.try_send(Event::Quit).unwrap();
+    ///
+    /// let metrics = handler.metrics();
+    /// assert_eq!(metrics.forwarded, 1);
+    /// assert_eq!(metrics.dropped, 0);
+    /// ```
+    pub fn metrics(&self) -> EventMetrics {
+        EventMetrics {
+            received: self.received.load(Ordering::Acquire),
+            forwarded: self.forwarded.load(Ordering::Acquire),
+            dropped: self.dropped.load(Ordering::Acquire),
+        }
+    }
+
+    /// Attempts to send an event through the channel without blocking.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The event to send through the channel
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if sent successfully, or an error if the channel
+    /// is full or closed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ChannelClosed` error if the channel has been closed.
+    pub fn try_send(&self, event: Event) -> OxittyResult<()> {
+        self.send(event)
+            .map_err(|_| OxittyError::channel_closed("event channel", (0, 0)).into())
+    }
+
+    /// Injects an event as if it had been received from the terminal.
+    ///
+    /// This is the supported way to feed synthetic key, mouse, resize or
+    /// custom events to an application, whether from tests or from code
+    /// driving the UI programmatically. It behaves exactly like
+    /// [`try_send`](Self::try_send).
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The event to inject
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ChannelClosed` error if the channel is full or closed.
+    pub fn inject(&self, event: Event) -> OxittyResult<()> {
+        self.try_send(event)
+    }
+
+    /// Queues an event, updating the forwarded and dropped counters.
+    fn send(&self, event: Event) -> Result<(), TrySendError<Event>> {
+        // Only pay for the clone while the diagnostics mirror is enabled
+        let copy = (self.recent_capacity() > 0).then(|| event.clone());
+        let result = self.tx.try_send(event);
+        match &result {
+            Ok(()) => self.record_forwarded(copy),
+            Err(TrySendError::Full(_)) => {
+                self.dropped.fetch_add(1, Ordering::AcqRel);
+            }
+            Err(TrySendError::Closed(_)) => {}
+        }
+        result
+    }
+
+    /// Counts a queued event and mirrors it into the recent-events buffer.
+    fn record_forwarded(&self, copy: Option<Event>) {
+        self.forwarded.fetch_add(1, Ordering::AcqRel);
+        if let Some(copy) = copy {
+            self.remember(copy);
+        }
+    }
+
+    /// Appends a queued event to the recent-events ring buffer.
+    fn remember(&self, event: Event) {
+        let mut recent = self.recent.lock().unwrap_or_else(|e| e.into_inner());
+        // Re-read under the lock in case the capacity changed meanwhile
+        let capacity = self.recent_capacity();
+        if capacity == 0 {
+            return;
+        }
+        if recent.len() >= capacity {
+            recent.pop_front();
+        }
+        recent.push_back(event);
+    }
+
+    /// Non-blocking attempt to receive an event from the channel.
+    ///
+    /// Events skipped by [`try_recv_matching`](Self::try_recv_matching) are
+    /// returned first, in the order they were queued.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Event))` - An event was available
+    /// * `Ok(None)` - No events were ready
+    /// * `Err(_)` - The channel has been closed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::event::EventHandler;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let handler = EventHandler::new();
+    ///
+    /// if let Some(event) = handler.try_recv()? {
+    ///     println!("Received: {:?}", event);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_recv(&self) -> OxittyResult<Option<Event>> {
+        if let Some(event) = self.deferred().pop_front() {
+            return Ok(Some(event));
+        }
+
+        match self.rx.try_recv() {
+            Ok(event) => Ok(Some(event)),
+            Err(TryRecvError::Empty) => Ok(None),
+            Err(_) => Err(OxittyError::channel_closed("event channel", (0, 0)).into()),
+        }
+    }
+
+    /// Non-blocking attempt to receive the next event accepted by `predicate`.
+    ///
+    /// Events that do not match are not lost: they are held back inside the
+    /// handler and returned by later calls to [`try_recv`](Self::try_recv)
+    /// or `try_recv_matching`, before anything still in the channel.
+    ///
+    /// # Ordering
+    ///
+    /// Each call returns the oldest matching event, whether held back or
+    /// still queued. Skipped events keep their relative order, so consumers
+    /// of different event types each see their own events in the order they
+    /// arrived. [`recv_timeout`](Self::recv_timeout) and
+    /// [`stream`](Self::stream) also return held-back events first. Nothing
+    /// bounds the held-back events, so
+    /// something should eventually consume what a predicate keeps skipping.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - Returns `true` for the events to receive
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Event))` - The oldest matching event
+    /// * `Ok(None)` - No matching event was ready
+    /// * `Err(_)` - No matching event was ready and the channel has been closed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    /// use oxitty::event::{Event, EventHandler};
+    ///
+    /// let handler = EventHandler::test_handler();
+    /// let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+    /// handler.inject(Event::Key(enter)).unwrap();
+    /// handler.inject(Event::Resize(80, 24)).unwrap();
+    ///
+    /// let resize = handler.try_recv_matching(|event| matches!(event, Event::Resize(..)));
+    /// assert!(matches!(resize.unwrap(), Some(Event::Resize(80, 24))));
+    ///
+    /// // The key skipped on the way is still there
+    /// assert!(matches!(handler.try_recv().unwrap(), Some(Event::Key(_))));
+    /// ```
+    pub fn try_recv_matching(
+        &self,
+        mut predicate: impl FnMut(&Event) -> bool,
+    ) -> OxittyResult<Option<Event>> {
+        let mut deferred = self.deferred();
+        if let Some(index) = deferred.iter().position(&mut predicate) {
+            return Ok(deferred.remove(index));
+        }
+
+        loop {
+            match self.rx.try_recv() {
+                Ok(event) if predicate(&event) => return Ok(Some(event)),
+                Ok(event) => deferred.push_back(event),
+                Err(TryRecvError::Empty) => return Ok(None),
+                Err(_) => return Err(OxittyError::channel_closed("event channel", (0, 0)).into()),
+            }
+        }
+    }
+
+    /// Locks the events held back by [`try_recv_matching`](Self::try_recv_matching).
+    fn deferred(&self) -> MutexGuard<'_, VecDeque<Event>> {
+        self.deferred.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Returns the number of events waiting in the channel or held back.
+    pub(crate) fn queued(&self) -> usize {
+        self.rx.len() + self.deferred().len()
+    }
+
+    /// Waits for an event, giving up once `timeout` elapses.
+    ///
+    /// Races the channel against a sleep on the handler's [`Clock`], which
+    /// makes it convenient for loops that need to run periodic work between
+    /// events.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Maximum duration to wait for an event
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Event))` - An event arrived before the timeout
+    /// * `Ok(None)` - The timeout elapsed without an event
+    /// * `Err(_)` - The channel has been closed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use oxitty::event::EventHandler;
+    ///
+    /// # fn main() -> oxitty::OxittyResult<()> {
+    /// let handler = EventHandler::new();
+    ///
+    /// let event = smol::block_on(handler.recv_timeout(Duration::from_millis(10)))?;
+    /// assert!(event.is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn recv_timeout(&self, timeout: Duration) -> OxittyResult<Option<Event>> {
+        if let Some(event) = self.deferred().pop_front() {
+            return Ok(Some(event));
+        }
+
+        let recv = async {
+            self.rx
+                .recv()
+                .await
+                .map(Some)
+                .map_err(|_| OxittyError::channel_closed("event channel", (0, 0)).into())
+        };
+        let timer = async {
+            self.clock.sleep(timeout).await;
+            Ok(None)
+        };
+
+        runtime::race(recv, timer).await
+    }
+
+    /// Returns the queued events as an asynchronous [`Stream`].
+    ///
+    /// The stream yields events in the order they were queued, starting with
+    /// any held back by [`try_recv_matching`](Self::try_recv_matching), so it
+    /// composes with stream combinators such as `filter` or `race` from
+    /// `smol::stream`. It shares the channel with [`try_recv`](Self::try_recv)
+    /// and any other stream: each event is delivered to exactly one of them.
+    /// Keep [`run`](Self::run) going, typically on a spawned task, for
+    /// terminal events to arrive.
+    ///
+    /// The handler owns the sending side of the channel, and
+    /// [`stop`](Self::stop) only ends polling. The stream therefore does not
+    /// end while the handler is alive; it waits for the next event instead.
+    /// Bound it with a combinator such as `take` or race it against a
+    /// shutdown signal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::event::{Event, EventHandler};
+    /// use smol::stream::StreamExt;
+    ///
+    /// let handler = EventHandler::test_handler();
+    /// handler.inject(Event::Resize(80, 24)).unwrap();
+    ///
+    /// let mut events = handler.stream();
+    /// let first = smol::block_on(events.next());
+    /// assert!(matches!(first, Some(Event::Resize(80, 24))));
+    /// ```
+    pub fn stream(&self) -> impl Stream<Item = Event> + Send + Unpin + 'static {
+        let deferred = self.deferred.clone();
+        // Boxed so callers can use `next` without pinning it themselves
+        Box::pin(futures_lite::stream::unfold(self.rx.clone(), move |rx| {
+            let held = deferred
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .pop_front();
+            async move {
+                match held {
+                    Some(event) => Some((event, rx)),
+                    None => rx.recv().await.ok().map(|event| (event, rx)),
+                }
+            }
+        }))
+    }
+
+    /// Starts the event polling task.
+    ///
+    /// Runs an asynchronous loop that polls for terminal events and
+    /// distributes them through the channel. The loop continues until
+    /// `stop()` is called.
+    ///
+    /// Each blocking poll is capped at a short internal interval, so `stop()`
+    /// takes effect promptly no matter how long `tick_rate` is.
+    ///
+    /// Interrupted system calls are retried up to a small bound before being
+    /// treated as fatal; any other polling or reading error ends the loop.
+    /// A full channel never stops the loop: events that do not fit are
+    /// handled according to the [`OverflowPolicy`], and only a closed channel
+    /// ends it with an error. While
+    /// [paused](Self::pause), the loop waits without reading input. A
+    /// [`test_handler`](Self::test_handler) only waits and never reads input.
+    ///
+    /// # Arguments
+    ///
+    /// * `tick_rate` - Initial duration to wait between polling attempts;
+    ///   see [`set_tick_rate`](Self::set_tick_rate) to change it later
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` when stopped cleanly, or an error if event
+    /// polling fails.
+    pub async fn run(&self, tick_rate: Duration) -> OxittyResult<()> {
+        self.set_tick_rate(tick_rate);
+        self.run_at_current_rate().await
+    }
+
+    /// Runs the event loop at the tick rate already set on the handler.
+    ///
+    /// Unlike [`run`](Self::run), starting the loop never overwrites the
+    /// rate, so a [`set_tick_rate`](Self::set_tick_rate) call made before
+    /// the spawned task gets to run is not lost.
+    pub(crate) async fn run_at_current_rate(&self) -> OxittyResult<()> {
+        if !self.reads_terminal {
+            return self.poll_loop(None::<NoInput>).await;
+        }
+
+        self.poll_loop(Some((crossterm::event::poll, crossterm::event::read)))
+            .await
+    }
+
+    /// Runs the event loop with the given poll and read functions.
+    ///
+    /// Tests substitute their own functions to simulate terminal errors.
+    #[cfg(test)]
+    pub(crate) async fn run_with<P, R>(
+        &self,
+        tick_rate: Duration,
+        poll: P,
+        read: R,
+    ) -> OxittyResult<()>
+    where
+        P: FnMut(Duration) -> io::Result<bool>,
+        R: FnMut() -> io::Result<CrosstermEvent>,
+    {
+        self.set_tick_rate(tick_rate);
+        self.poll_loop(Some((poll, read))).await
+    }
+
+    /// Polls and forwards terminal events until the handler is stopped.
+    ///
+    /// The tick rate is read before every poll. Without `input`, each poll
+    /// is waited out on the handler's [`Clock`], like an idle terminal.
+    async fn poll_loop<P, R>(&self, mut input: Option<(P, R)>) -> OxittyResult<()>
+    where
+        P: FnMut(Duration) -> io::Result<bool>,
+        R: FnMut() -> io::Result<CrosstermEvent>,
+    {
+        let mut last_tick = self.clock.now();
+
+        while self.running.load(Ordering::Acquire) {
+            let tick_rate = self.tick_rate();
+            // A zero rate disables ticks rather than polling in a hot loop
+            let mut timeout = match tick_rate {
+                Duration::ZERO => STOP_CHECK_INTERVAL,
+                rate => rate.min(STOP_CHECK_INTERVAL),
+            };
+            self.forward_signal()?;
+            if self.is_paused() {
+                // Leave input in the terminal for whoever reads it meanwhile
+                self.clock.sleep(timeout).await;
+                continue;
+            }
+
+            if let Some((poll, read)) = &mut input {
+                // Wait for the first event, then drain whatever is already queued
+                let batch = self.max_events_per_tick();
+                for _ in 0..batch {
+                    if !self.poll_events(poll, timeout)? {
+                        break;
+                    }
+                    let event = self.read_event(read)?;
+                    self.received.fetch_add(1, Ordering::AcqRel);
+                    self.forward(event).await?;
+                    timeout = Duration::ZERO;
+                }
+            } else {
+                self.clock.sleep(timeout).await;
+            }
+
+            let now = self.clock.now();
+            let elapsed = now.saturating_duration_since(last_tick);
+            if !tick_rate.is_zero() && elapsed >= tick_rate {
+                last_tick = now;
+                if self.tick_events() {
+                    self.queue(Event::Tick(elapsed))?;
+                }
+            }
+
+            // Allow other tasks to run
+            runtime::yield_now().await;
+        }
+
+        Ok(())
+    }
+
+    /// Sends a terminal event through the channel, ignoring unsupported kinds.
+    ///
+    /// Events that do not fit in a full channel are dropped, unless the
+    /// policy is [`OverflowPolicy::Retry`]; only a closed channel is an error.
+    async fn forward(&self, event: CrosstermEvent) -> OxittyResult<()> {
+        let event = match event {
+            CrosstermEvent::Key(key) if !self.accept_key(&key) => return Ok(()),
+            CrosstermEvent::Key(key) => Event::Key(key),
+            CrosstermEvent::Mouse(mouse) => Event::Mouse(mouse),
+            CrosstermEvent::Resize(width, height) => Event::Resize(width, height),
+            _ => return Ok(()),
+        };
+        // Clone the filter out so it can replace itself without deadlocking
+        let filter = self
+            .filter
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone();
+        let event = match filter.as_deref() {
+            Some(EventFilter(filter)) => match filter(event) {
+                Some(event) => event,
+                None => return Ok(()),
+            },
+            None => event,
+        };
+
+        if self.overflow_policy() == OverflowPolicy::Retry {
+            self.queue_waiting(event).await
+        } else {
+            self.queue(event)
+        }
+    }
+
+    /// Queues an event under [`OverflowPolicy::Retry`], parking until the
+    /// channel has room.
+    ///
+    /// The task sleeps while the channel is full instead of polling it, and
+    /// checks for [`stop`](Self::stop) once per [`STOP_CHECK_INTERVAL`]; a
+    /// stopped handler drops the event. Only a closed channel is an error.
+    async fn queue_waiting(&self, event: Event) -> OxittyResult<()> {
+        let copy = (self.recent_capacity() > 0).then(|| event.clone());
+        let stopped = async {
+            while self.running.load(Ordering::Acquire) {
+                self.clock.sleep(STOP_CHECK_INTERVAL).await;
+                // A manual clock's sleep is ready at once; let the send retry
+                runtime::yield_now().await;
+            }
+            None
+        };
+
+        match runtime::race(async { Some(self.tx.send(event).await) }, stopped).await {
+            Some(Ok(())) => {
+                self.record_forwarded(copy);
+                Ok(())
+            }
+            Some(Err(_)) => Err(OxittyError::channel_closed("event channel", (0, 0)).into()),
+            None => {
+                self.dropped.fetch_add(1, Ordering::AcqRel);
+                Ok(())
+            }
+        }
+    }
+
+    /// Queues an event from the polling loop, dropping it if the channel is full.
+    ///
+    /// Only a closed channel is an error.
+    fn queue(&self, event: Event) -> OxittyResult<()> {
+        match self.send(event) {
+            Ok(()) | Err(TrySendError::Full(_)) => Ok(()),
+            Err(TrySendError::Closed(_)) => {
+                Err(OxittyError::channel_closed("event channel", (0, 0)).into())
+            }
+        }
+    }
+
+    /// Applies the repeat filter to a terminal key event.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the key should be forwarded.
+    fn accept_key(&self, key: &KeyEvent) -> bool {
+        let mut state = self.repeat.lock().unwrap_or_else(|e| e.into_inner());
+        let interval = match state.filter {
+            RepeatFilter::Off => return true,
+            RepeatFilter::Drop => return key.kind != KeyEventKind::Repeat,
+            RepeatFilter::Throttle(interval) => interval,
+        };
+
+        if key.kind == KeyEventKind::Release {
+            // A released key starts over on its next press
+            if matches!(state.last, Some((code, _, _)) if code == key.code) {
+                state.last = None;
+            }
+            return true;
+        }
+
+        let now = self.clock.now();
+        let held = matches!(
+            state.last,
+            Some((code, modifiers, at))
+                if code == key.code && modifiers == key.modifiers && now - at < interval
+        );
+        if !held {
+            state.last = Some((key.code, key.modifiers, now));
+        }
+        !held
+    }
+
+    /// Polls for terminal events, retrying interrupted polls.
+    ///
+    /// # Arguments
+    ///
+    /// * `poll` - Function performing a single poll
+    /// * `tick_rate` - Maximum duration to wait for an event
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` - An event is available
+    /// * `Ok(false)` - No event available within tick rate
+    /// * `Err(_)` - Polling failed fatally or kept being interrupted
+    fn poll_events<P>(&self, poll: &mut P, tick_rate: Duration) -> OxittyResult<bool>
+    where
+        P: FnMut(Duration) -> io::Result<bool>,
+    {
+        Self::retry_interrupted(|| poll(tick_rate)).map_err(|e| {
+            OxittyError::terminal(
+                "event polling",
+                (0, 0),
+                format!("Failed to poll events: {}", e),
+            )
+            .into()
+        })
+    }
+
+    /// Reads a terminal event, retrying interrupted reads.
+    ///
+    /// # Arguments
+    ///
+    /// * `read` - Function performing a single read
+    ///
+    /// # Returns
+    ///
+    /// Returns the next available terminal event or an error if
+    /// reading fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns a terminal error if reading fails.
+    fn read_event<R>(&self, read: &mut R) -> OxittyResult<CrosstermEvent>
+    where
+        R: FnMut() -> io::Result<CrosstermEvent>,
+    {
+        Self::retry_interrupted(read).map_err(|e| {
+            OxittyError::terminal(
+                "event reading",
+                (0, 0),
+                format!("Failed to read event: {}", e),
+            )
+            .into()
+        })
+    }
+
+    /// Calls `op`, retrying up to [`MAX_POLL_RETRIES`] times while it fails
+    /// with [`io::ErrorKind::Interrupted`].
+    fn retry_interrupted<T>(mut op: impl FnMut() -> io::Result<T>) -> io::Result<T> {
+        let mut retries = 0;
+        loop {
+            match op() {
+                Err(e) if e.kind() == io::ErrorKind::Interrupted && retries < MAX_POLL_RETRIES => {
+                    retries += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Stops the event handler gracefully.
+    ///
+    /// Sets the running flag to false, which will cause the event
+    /// polling loop to terminate after the current iteration.
+    pub fn stop(&self) {
+        self.running.store(false, Ordering::Release);
+    }
+
+    /// Suspends terminal polling until [`resume`](Self::resume) is called.
+    ///
+    /// Unlike [`stop`](Self::stop), this is reversible: the polling task
+    /// keeps running but stops reading the terminal, so input is left for
+    /// another reader such as a fullscreen modal or a child process. The
+    /// channel stays open and events sent with [`try_send`](Self::try_send)
+    /// or [`inject`](Self::inject) are still queued. Takes effect within
+    /// one polling slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::event::{Event, EventHandler};
+    ///
+    /// let handler = EventHandler::new();
+    /// handler.pause();
+    /// assert!(handler.is_paused());
+    ///
+    /// // Other sources can still deliver events
+    /// handler.inject(Event::Quit).unwrap();
+    /// assert!(matches!(handler.try_recv().unwrap(), Some(Event::Quit)));
+    ///
+    /// handler.resume();
+    /// assert!(!handler.is_paused());
+    /// ```
+    pub fn pause(&self) {
+        self.paused.store(true, Ordering::Release);
+    }
+
+    /// Resumes terminal polling after [`pause`](Self::pause).
+    pub fn resume(&self) {
+        self.paused.store(false, Ordering::Release);
+    }
+
+    /// Returns whether terminal polling is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Acquire)
+    }
+
+    /// Checks if the event handler is currently running.
+    ///
+    /// # Returns
+    ///
+    /// Returns true if the handler is running, false otherwise.
     pub fn is_running(&self) -> bool {
         self.running.load(Ordering::Acquire)
     }
-}
+}
+
+impl Default for EventHandler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Typed handle for sending commands of type `T` into an event loop.
+///
+/// Each command is wrapped in [`Event::Custom`] and queued on the
+/// [`EventHandler`]'s channel, where a custom event handler downcasts it
+/// back to `T`. Subsystems can hold a sender instead of the whole handler,
+/// and the type parameter documents which commands they dispatch. Senders
+/// are cheap to clone and can be moved to other threads.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::{any::Any, sync::Arc};
+/// use oxitty::event::{CommandSender, Event, EventHandler};
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Command {
+///     Reload,
+/// }
+///
+/// let handler = Arc::new(EventHandler::new());
+/// let commands = CommandSender::<Command>::new(handler.clone());
+/// commands.send(Command::Reload).unwrap();
+///
+/// let Some(Event::Custom(payload)) = handler.try_recv().unwrap() else {
+///     panic!("expected a custom event");
+/// };
+/// let payload: &dyn Any = &*payload;
+/// assert_eq!(payload.downcast_ref::<Command>(), Some(&Command::Reload));
+/// ```
+pub struct CommandSender<T> {
+    /// Handler whose channel commands are queued on
+    events: Arc<EventHandler>,
+    /// Command type, without owning one
+    command: PhantomData<fn(T)>,
+}
+
+impl<T: CloneableAny + Send> CommandSender<T> {
+    /// Creates a sender queuing commands on the given handler.
+    ///
+    /// # Arguments
+    ///
+    /// * `events` - Event handler whose loop receives the commands
+    pub fn new(events: Arc<EventHandler>) -> Self {
+        Self {
+            events,
+            command: PhantomData,
+        }
+    }
+
+    /// Sends a command into the loop without blocking.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - Command delivered as an [`Event::Custom`] payload
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ChannelClosed` error if the channel is full or closed.
+    pub fn send(&self, command: T) -> OxittyResult<()> {
+        self.events.try_send(Event::Custom(Box::new(command)))
+    }
+}
+
+impl<T> Clone for CommandSender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            events: self.events.clone(),
+            command: PhantomData,
+        }
+    }
+}
+
+impl<T> Debug for CommandSender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CommandSender")
+            .field("command", &std::any::type_name::<T>())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ManualClock;
+    use smol::block_on;
+
+    #[test]
+    fn test_event_handler_lifecycle() {
+        let handler = EventHandler::new();
+        assert!(handler.is_running());
+
+        handler.stop();
+        assert!(!handler.is_running());
+    }
+
+    #[test]
+    fn test_event_sending() {
+        let handler = EventHandler::new();
+
+        // Test key event
+        let key_event = Event::Key(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::empty()));
+        assert!(handler.try_send(key_event).is_ok());
+
+        // Test receiving the sent event
+        let received = block_on(async { handler.try_recv() }).unwrap();
+
+        assert!(matches!(received, Some(Event::Key(_))));
+    }
+
+    #[test]
+    fn test_custom_event_clone() {
+        let event = Event::Custom(Box::new(42u32));
+        let Event::Custom(payload) = event.clone() else {
+            panic!("clone changed the variant");
+        };
+
+        let any: &dyn Any = payload.as_ref();
+        assert_eq!(any.downcast_ref::<u32>(), Some(&42));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let key = Event::Key(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL));
+        let json = serde_json::to_string(&key).unwrap();
+        match serde_json::from_str::<Event>(&json).unwrap() {
+            Event::Key(restored) => assert_eq!(restored, *key.key_event().unwrap()),
+            other => panic!("expected key event, got {:?}", other),
+        }
+
+        let json = serde_json::to_string(&Event::Resize(80, 24)).unwrap();
+        assert_eq!(json, r#"{"Resize":[80,24]}"#);
+        assert!(matches!(
+            serde_json::from_str::<Event>(&json).unwrap(),
+            Event::Resize(80, 24)
+        ));
+
+        let tick = Event::Tick(Duration::from_millis(16));
+        let json = serde_json::to_string(&tick).unwrap();
+        assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), tick);
+
+        // Custom payloads serialize as a placeholder that cannot be restored
+        let json = serde_json::to_string(&Event::Custom(Box::new(7u8))).unwrap();
+        assert_eq!(json, r#"{"Custom":{"type_name":"u8"}}"#);
+        let error = serde_json::from_str::<Event>(&json).unwrap_err();
+        assert!(error.to_string().contains("custom event `u8`"));
+    }
+
+    #[test]
+    fn test_test_handler_idles_until_stopped() {
+        let handler = Arc::new(EventHandler::test_handler());
+        let runner = handler.clone();
+        let task = runtime::spawn(async move { runner.run(Duration::from_millis(1)).await });
+
+        handler.inject(Event::Resize(3, 4)).unwrap();
+        assert!(matches!(
+            handler.try_recv().unwrap(),
+            Some(Event::Resize(3, 4))
+        ));
+
+        handler.stop();
+        smol::block_on(task).unwrap();
+        assert_eq!(handler.metrics().received, 0);
+        assert!(handler.try_recv().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_tagged_equality() {
+        let tagged = Tagged::new("watcher", Box::new(Comparable::new(1u32)));
+        assert_eq!(tagged, tagged.clone());
+        assert_ne!(
+            tagged,
+            Tagged::new("network", Box::new(Comparable::new(1u32)))
+        );
+        assert_ne!(
+            tagged,
+            Tagged::new("watcher", Box::new(Comparable::new(2u32)))
+        );
+        assert!((*tagged.into_payload()).dyn_eq(&Comparable::new(1u32)));
+    }
+
+    #[test]
+    fn test_custom_event_equality() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct Saved(u32);
+
+        let custom = |value| Event::Custom(Box::new(Comparable::new(value)));
+        let event = custom(Saved(1));
+        assert_eq!(event, event.clone());
+        assert_eq!(event, custom(Saved(1)));
+        assert_ne!(event, custom(Saved(2)));
+
+        // Same value, different type
+        assert_ne!(
+            Event::Custom(Box::new(Comparable::new(1u32))),
+            Event::Custom(Box::new(Comparable::new(1u64)))
+        );
+        assert_ne!(event, Event::Quit);
+        assert_eq!(Event::Resize(1, 2), Event::Resize(1, 2));
+
+        // Payloads that did not opt in never compare equal
+        assert_ne!(Event::Custom(Box::new(Saved(1))), custom(Saved(1)));
+        assert_ne!(
+            Event::Custom(Box::new(Saved(1))),
+            Event::Custom(Box::new(Saved(1)))
+        );
+    }
+
+    #[test]
+    fn test_custom_payload_without_partial_eq() {
+        // Channels and mutexes have no `PartialEq`
+        #[derive(Debug, Clone)]
+        struct Reply(std::sync::mpsc::Sender<u32>, Arc<Mutex<u32>>);
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let handler = EventHandler::new();
+        handler
+            .try_send(Event::Custom(Box::new(Reply(tx, Arc::new(Mutex::new(0))))))
+            .unwrap();
+
+        let Some(Event::Custom(payload)) = handler.try_recv().unwrap() else {
+            panic!("expected a custom event");
+        };
+        let payload: &dyn Any = &*payload.clone();
+        let Reply(reply, count) = payload.downcast_ref::<Reply>().unwrap();
+        *count.lock().unwrap() += 1;
+        reply.send(7).unwrap();
+        assert_eq!(rx.recv().unwrap(), 7);
+    }
+
+    #[test]
+    fn test_custom_type_name() {
+        #[derive(Debug, Clone)]
+        struct Refreshed {
+            _id: u32,
+        }
+
+        let handler = EventHandler::new();
+        handler
+            .try_send(Event::Custom(Box::new(Refreshed { _id: 7 })))
+            .unwrap();
+
+        let event = handler.try_recv().unwrap().unwrap();
+        let name = event.custom_type_name().unwrap();
+        assert_eq!(name, std::any::type_name::<Refreshed>());
+        assert!(name.ends_with("Refreshed"));
+
+        // Survives cloning and ignores non-custom events
+        assert_eq!(event.clone().custom_type_name(), Some(name));
+        assert_eq!(Event::Resize(80, 24).custom_type_name(), None);
+    }
+
+    #[test]
+    fn test_channel_capacity() {
+        let handler = EventHandler::new();
+
+        // Fill the channel to capacity
+        for _ in 0..MAX_EVENTS {
+            let event = Event::Key(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::empty()));
+            assert!(handler.try_send(event).is_ok());
+        }
+
+        // Next send should fail
+        let event = Event::Key(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::empty()));
+        assert!(handler.try_send(event).is_err());
+    }
 
-impl Default for EventHandler {
-    fn default() -> Self {
-        Self::new()
+    fn mouse_at(column: u16, row: u16) -> Event {
+        Event::Mouse(MouseEvent {
+            kind: MouseEventKind::Moved,
+            column,
+            row,
+            modifiers: KeyModifiers::empty(),
+        })
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crossterm::event::{KeyCode, KeyModifiers};
-    use smol::block_on;
+    fn mouse_kind(kind: MouseEventKind, modifiers: KeyModifiers) -> Event {
+        Event::Mouse(MouseEvent {
+            kind,
+            column: 0,
+            row: 0,
+            modifiers,
+        })
+    }
 
     #[test]
-    fn test_event_handler_lifecycle() {
+    fn test_mouse_in_area() {
+        let area = Rect::new(10, 5, 20, 10);
+
+        // Inside
+        assert_eq!(mouse_at(15, 8).mouse_in(area), Some((5, 3)));
+
+        // Outside
+        assert_eq!(mouse_at(5, 8).mouse_in(area), None);
+        assert_eq!(mouse_at(15, 20).mouse_in(area), None);
+
+        // Edges: origin is inclusive, far edges are exclusive
+        assert_eq!(mouse_at(10, 5).mouse_in(area), Some((0, 0)));
+        assert_eq!(mouse_at(29, 14).mouse_in(area), Some((19, 9)));
+        assert_eq!(mouse_at(30, 14).mouse_in(area), None);
+        assert_eq!(mouse_at(29, 15).mouse_in(area), None);
+
+        // Non-mouse events
+        assert_eq!(Event::Resize(15, 8).mouse_in(area), None);
+        assert_eq!(Event::Quit.mouse_in(area), None);
+    }
+
+    #[test]
+    fn test_scroll_delta() {
+        let none = KeyModifiers::empty();
+
+        assert_eq!(
+            mouse_kind(MouseEventKind::ScrollUp, none).scroll_delta(),
+            Some(1)
+        );
+        assert_eq!(
+            mouse_kind(MouseEventKind::ScrollDown, none).scroll_delta(),
+            Some(-1)
+        );
+        assert_eq!(
+            mouse_kind(MouseEventKind::ScrollDown, KeyModifiers::CONTROL).scroll_delta(),
+            Some(-FAST_SCROLL_STEP)
+        );
+
+        // Horizontal scrolling uses its own axis
+        assert_eq!(
+            mouse_kind(MouseEventKind::ScrollLeft, none).scroll_delta(),
+            None
+        );
+        assert_eq!(
+            mouse_kind(MouseEventKind::ScrollLeft, none).horizontal_scroll_delta(),
+            Some(-1)
+        );
+        assert_eq!(
+            mouse_kind(MouseEventKind::ScrollRight, none).horizontal_scroll_delta(),
+            Some(1)
+        );
+
+        // Non-scroll events
+        assert_eq!(mouse_at(3, 3).scroll_delta(), None);
+        assert_eq!(Event::Resize(80, 24).scroll_delta(), None);
+        assert_eq!(Event::Quit.horizontal_scroll_delta(), None);
+    }
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> Event {
+        Event::Key(KeyEvent::new(code, modifiers))
+    }
+
+    #[test]
+    fn test_key_predicates() {
+        let none = KeyModifiers::empty();
+
+        // Ctrl+C requires the CONTROL modifier
+        assert!(key(KeyCode::Char('c'), KeyModifiers::CONTROL).is_ctrl_c());
+        assert!(!key(KeyCode::Char('c'), none).is_ctrl_c());
+        assert!(!key(KeyCode::Char('x'), KeyModifiers::CONTROL).is_ctrl_c());
+
+        assert!(key(KeyCode::Enter, none).is_enter());
+        assert!(!key(KeyCode::Esc, none).is_enter());
+
+        assert!(key(KeyCode::Esc, none).is_escape());
+        assert!(!key(KeyCode::Enter, none).is_escape());
+
+        assert!(key(KeyCode::Char('a'), none).is_char('a'));
+        assert!(!key(KeyCode::Char('a'), none).is_char('b'));
+        assert!(key(KeyCode::Char('A'), KeyModifiers::SHIFT).is_char('A'));
+        assert!(!key(KeyCode::Char('a'), KeyModifiers::CONTROL).is_char('a'));
+
+        assert_eq!(key(KeyCode::Char('z'), none).char(), Some('z'));
+        assert_eq!(
+            key(KeyCode::Char('z'), KeyModifiers::SHIFT).char(),
+            Some('Z')
+        );
+        assert_eq!(key(KeyCode::Char('z'), KeyModifiers::ALT).char(), None);
+        assert_eq!(key(KeyCode::Enter, none).char(), None);
+
+        // Non-key events never match
+        assert!(!Event::Quit.is_ctrl_c());
+        assert!(!Event::Resize(80, 24).is_enter());
+        assert!(!mouse_at(0, 0).is_escape());
+        assert_eq!(Event::Quit.char(), None);
+    }
+
+    #[test]
+    fn test_event_constructors() {
+        assert_eq!(
+            Event::from_char('q'),
+            Event::Key(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE))
+        );
+        assert_eq!(Event::from_char('q').char(), Some('q'));
+        assert_eq!(
+            Event::key(KeyCode::Esc),
+            Event::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE))
+        );
+
+        let ctrl_s = Event::key_mod(KeyCode::Char('s'), KeyModifiers::CONTROL);
+        let Event::Key(key) = ctrl_s else {
+            panic!("expected a key event");
+        };
+        assert_eq!(key.code, KeyCode::Char('s'));
+        assert_eq!(key.modifiers, KeyModifiers::CONTROL);
+        assert_eq!(key.kind, KeyEventKind::Press);
+
+        assert_eq!(Event::resize(80, 24), Event::Resize(80, 24));
+    }
+
+    #[test]
+    fn test_key_kind() {
+        let of_kind = |kind| {
+            Event::Key(KeyEvent::new_with_kind(
+                KeyCode::Char('a'),
+                KeyModifiers::NONE,
+                kind,
+            ))
+        };
+
+        let press = of_kind(KeyEventKind::Press);
+        assert!(press.is_key_press());
+        assert_eq!(press.key_kind(), Some(KeyEventKind::Press));
+
+        let release = of_kind(KeyEventKind::Release);
+        assert!(!release.is_key_press());
+        assert_eq!(release.key_kind(), Some(KeyEventKind::Release));
+
+        let repeat = of_kind(KeyEventKind::Repeat);
+        assert!(!repeat.is_key_press());
+        assert_eq!(repeat.key_kind(), Some(KeyEventKind::Repeat));
+
+        // Legacy terminals report no kind, which crossterm maps to a press
+        assert!(key(KeyCode::Char('a'), KeyModifiers::NONE).is_key_press());
+
+        assert!(!Event::Quit.is_key_press());
+        assert_eq!(mouse_at(0, 0).key_kind(), None);
+    }
+
+    #[test]
+    fn test_normalize_key() {
+        let shift = KeyModifiers::SHIFT;
+        let none = KeyModifiers::NONE;
+        let upper_q = (KeyCode::Char('Q'), none);
+
+        // Every terminal's spelling of Shift+Q folds to the same pair
+        assert_eq!(normalize_key(KeyCode::Char('Q'), none), upper_q);
+        assert_eq!(normalize_key(KeyCode::Char('Q'), shift), upper_q);
+        assert_eq!(normalize_key(KeyCode::Char('q'), shift), upper_q);
+        assert_eq!(
+            normalize_key(KeyCode::Char('q'), none),
+            (KeyCode::Char('q'), none)
+        );
+
+        // Other modifiers survive; symbols drop the redundant SHIFT
+        assert_eq!(
+            normalize_key(KeyCode::Char('q'), shift | KeyModifiers::CONTROL),
+            (KeyCode::Char('Q'), KeyModifiers::CONTROL)
+        );
+        assert_eq!(
+            normalize_key(KeyCode::Char('!'), shift),
+            (KeyCode::Char('!'), none)
+        );
+
+        // Non-character keys keep SHIFT
+        assert_eq!(normalize_key(KeyCode::Tab, shift), (KeyCode::Tab, shift));
+    }
+
+    #[test]
+    fn test_recv_timeout() {
         let handler = EventHandler::new();
-        assert!(handler.is_running());
 
-        handler.stop();
-        assert!(!handler.is_running());
+        // Nothing queued: resolves to None once the timeout elapses
+        let start = std::time::Instant::now();
+        let received = block_on(handler.recv_timeout(Duration::from_millis(20))).unwrap();
+        assert!(received.is_none());
+        assert!(start.elapsed() >= Duration::from_millis(20));
+
+        // An event arriving before the deadline wins the race
+        let received = std::thread::scope(|scope| {
+            scope.spawn(|| {
+                std::thread::sleep(Duration::from_millis(10));
+                handler.try_send(Event::Resize(80, 24)).unwrap();
+            });
+            block_on(handler.recv_timeout(Duration::from_secs(5))).unwrap()
+        });
+        assert!(matches!(received, Some(Event::Resize(80, 24))));
     }
 
     #[test]
-    fn test_event_sending() {
+    fn test_interrupted_poll_is_retried() {
         let handler = EventHandler::new();
+        let mut polls = 0;
 
-        // Test key event
-        let key_event = Event::Key(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::empty()));
-        assert!(handler.try_send(key_event).is_ok());
+        let result = block_on(handler.run_with(
+            Duration::from_millis(1),
+            |_| {
+                polls += 1;
+                if polls <= 2 {
+                    Err(io::Error::from(io::ErrorKind::Interrupted))
+                } else {
+                    Ok(true)
+                }
+            },
+            || {
+                handler.stop();
+                Ok(CrosstermEvent::Resize(80, 24))
+            },
+        ));
 
-        // Test receiving the sent event
-        let received = block_on(async { handler.try_recv() }).unwrap();
+        assert!(result.is_ok());
+        assert_eq!(polls, 3);
+        assert!(matches!(
+            handler.try_recv().unwrap(),
+            Some(Event::Resize(80, 24))
+        ));
+    }
 
-        assert!(matches!(received, Some(Event::Key(_))));
+    #[test]
+    fn test_recent_events_ring_buffer() {
+        let handler = EventHandler::new();
+
+        // Disabled by default
+        handler.try_send(Event::Quit).unwrap();
+        assert!(handler.recent_events().is_empty());
+
+        handler.set_recent_capacity(3);
+        for width in 1..=5 {
+            handler.try_send(Event::Resize(width, 0)).unwrap();
+        }
+
+        // Only the newest events are kept, in order, and reading is not consuming
+        let expected = [
+            Event::Resize(3, 0),
+            Event::Resize(4, 0),
+            Event::Resize(5, 0),
+        ];
+        assert_eq!(handler.recent_events(), expected);
+        assert_eq!(handler.recent_events(), expected);
+        assert!(matches!(handler.try_recv().unwrap(), Some(Event::Quit)));
+        assert_eq!(handler.recent_events().len(), 3);
+
+        // Shrinking keeps the newest events; zero clears the buffer
+        handler.set_recent_capacity(1);
+        assert_eq!(handler.recent_events(), [Event::Resize(5, 0)]);
+        handler.set_recent_capacity(0);
+        handler.try_send(Event::Quit).unwrap();
+        assert!(handler.recent_events().is_empty());
+        assert_eq!(handler.recent_capacity(), 0);
     }
 
     #[test]
-    fn test_channel_capacity() {
+    fn test_metrics_count_dropped_events() {
         let handler = EventHandler::new();
+        for _ in 0..MAX_EVENTS {
+            handler.try_send(Event::Resize(80, 24)).unwrap();
+        }
+        assert!(handler.try_send(Event::Quit).is_err());
 
-        // Fill the channel to capacity
+        // The polling loop keeps running while the channel is full
+        let mut reads = 0;
+        let result = block_on(handler.run_with(
+            Duration::from_millis(1),
+            |_| Ok(true),
+            || {
+                reads += 1;
+                if reads == 3 {
+                    handler.stop();
+                }
+                Ok(CrosstermEvent::Resize(100, 40))
+            },
+        ));
+        assert!(result.is_ok());
+
+        assert_eq!(
+            handler.metrics(),
+            EventMetrics {
+                received: 3,
+                forwarded: MAX_EVENTS as u64,
+                dropped: 4,
+            }
+        );
+    }
+
+    #[test]
+    fn test_key_macro_requires_exact_modifiers() {
+        let (code, modifiers) = key!(Ctrl + Shift + 'p');
+        assert_eq!(code, KeyCode::Char('p'));
+        assert_eq!(modifiers, KeyModifiers::CONTROL | KeyModifiers::SHIFT);
+
+        let press = |code, modifiers| KeyEvent::new(code, modifiers);
+        let ctrl_shift = KeyModifiers::CONTROL | KeyModifiers::SHIFT;
+
+        // Every terminal spelling of Shift matches
+        assert!(press(KeyCode::Char('P'), ctrl_shift).matches(code, modifiers));
+        assert!(press(KeyCode::Char('p'), ctrl_shift).matches(code, modifiers));
+        assert!(press(KeyCode::Char('P'), KeyModifiers::CONTROL).matches(code, modifiers));
+
+        // Missing modifiers
+        assert!(!press(KeyCode::Char('p'), KeyModifiers::CONTROL).matches(code, modifiers));
+        assert!(!press(KeyCode::Char('P'), KeyModifiers::NONE).matches(code, modifiers));
+
+        // Extra modifiers
+        let extra = ctrl_shift | KeyModifiers::ALT;
+        assert!(!press(KeyCode::Char('P'), extra).matches(code, modifiers));
+
+        let (code, modifiers) = key!(Alt + Enter);
+        assert!(press(KeyCode::Enter, KeyModifiers::ALT).matches(code, modifiers));
+        assert!(!press(KeyCode::Enter, KeyModifiers::NONE).matches(code, modifiers));
+        assert!(
+            !press(KeyCode::Enter, KeyModifiers::ALT | KeyModifiers::CONTROL)
+                .matches(code, modifiers)
+        );
+    }
+
+    #[test]
+    fn test_try_recv_matching_keeps_skipped_events() {
+        let handler = EventHandler::test_handler();
+        let key = |c| Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        let click = |column| {
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(crossterm::event::MouseButton::Left),
+                column,
+                row: 0,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+        for event in [key('a'), click(1), key('b'), click(2), key('c')] {
+            handler.inject(event).unwrap();
+        }
+
+        let is_mouse = |event: &Event| matches!(event, Event::Mouse(_));
+        let column = |event: Option<Event>| match event {
+            Some(Event::Mouse(mouse)) => mouse.column,
+            other => panic!("expected a mouse event, got {other:?}"),
+        };
+        assert_eq!(column(handler.try_recv_matching(is_mouse).unwrap()), 1);
+        assert_eq!(column(handler.try_recv_matching(is_mouse).unwrap()), 2);
+        assert!(handler.try_recv_matching(is_mouse).unwrap().is_none());
+        assert_eq!(handler.queued(), 3);
+
+        // Skipped keys come back in their original order
+        for c in ['a', 'b', 'c'] {
+            let event = handler.try_recv().unwrap();
+            assert!(event.is_some_and(|event| event.is_char(c)));
+        }
+        assert!(handler.try_recv().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_stream_yields_queued_events() {
+        use smol::stream::StreamExt;
+
+        let handler = EventHandler::test_handler();
+        let stream = handler.stream();
+        for size in [(10, 5), (20, 6), (30, 7)] {
+            handler.inject(Event::Resize(size.0, size.1)).unwrap();
+        }
+        handler.inject(Event::Quit).unwrap();
+
+        let sizes: Vec<_> = block_on(
+            stream
+                .filter_map(|event| match event {
+                    Event::Resize(width, height) => Some((width, height)),
+                    _ => None,
+                })
+                .take(3)
+                .collect(),
+        );
+        assert_eq!(sizes, [(10, 5), (20, 6), (30, 7)]);
+
+        // Events the stream did not take stay queued for other receivers
+        assert!(matches!(handler.try_recv().unwrap(), Some(Event::Quit)));
+    }
+
+    #[test]
+    fn test_stream_yields_held_back_events_first() {
+        use smol::stream::StreamExt;
+
+        let handler = EventHandler::test_handler();
+        handler.inject(Event::Resize(10, 5)).unwrap();
+        handler.inject(Event::Quit).unwrap();
+        handler.inject(Event::Resize(20, 6)).unwrap();
+
+        // Holds back the first resize while looking for the quit
+        let quit = handler.try_recv_matching(|event| matches!(event, Event::Quit));
+        assert!(matches!(quit.unwrap(), Some(Event::Quit)));
+
+        let events: Vec<_> = block_on(handler.stream().take(2).collect());
+        assert!(matches!(
+            events[..],
+            [Event::Resize(10, 5), Event::Resize(20, 6)]
+        ));
+        assert_eq!(handler.queued(), 0);
+    }
+
+    #[test]
+    fn test_retry_policy_survives_full_channel() {
+        let handler = EventHandler::new();
+        handler.set_overflow_policy(OverflowPolicy::Retry);
         for _ in 0..MAX_EVENTS {
-            let event = Event::Key(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::empty()));
-            assert!(handler.try_send(event).is_ok());
+            handler.try_send(Event::Resize(80, 24)).unwrap();
         }
 
-        // Next send should fail
-        let event = Event::Key(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::empty()));
-        assert!(handler.try_send(event).is_err());
+        std::thread::scope(|scope| {
+            // A consumer that falls behind briefly, then catches up
+            scope.spawn(|| {
+                std::thread::sleep(Duration::from_millis(20));
+                while let Ok(Some(_)) = handler.try_recv() {}
+            });
+
+            let mut reads = 0;
+            let result = block_on(handler.run_with(
+                Duration::from_millis(1),
+                |_| Ok(true),
+                || {
+                    reads += 1;
+                    if reads == 3 {
+                        handler.stop();
+                    }
+                    Ok(CrosstermEvent::Resize(100, 40))
+                },
+            ));
+            assert!(result.is_ok());
+        });
+
+        // Nothing was lost while the channel was full
+        let metrics = handler.metrics();
+        assert_eq!(metrics.dropped, 0);
+        assert_eq!(metrics.received, 3);
+        assert_eq!(metrics.forwarded, MAX_EVENTS as u64 + 3);
+    }
+
+    #[test]
+    fn test_retry_policy_parks_while_channel_is_full() {
+        use std::{
+            future::Future,
+            pin::pin,
+            sync::atomic::AtomicUsize,
+            task::{Context, Wake, Waker},
+        };
+
+        /// Counts how often the polled task asks to run again
+        struct CountingWaker(AtomicUsize);
+
+        impl Wake for CountingWaker {
+            fn wake(self: Arc<Self>) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let handler = EventHandler::new();
+        handler.set_overflow_policy(OverflowPolicy::Retry);
+        for _ in 0..MAX_EVENTS {
+            handler.try_send(Event::Resize(80, 24)).unwrap();
+        }
+
+        let wakes = Arc::new(CountingWaker(AtomicUsize::new(0)));
+        let waker = Waker::from(wakes.clone());
+        let mut cx = Context::from_waker(&waker);
+        let mut reads = 0;
+        let mut run = pin!(handler.run_with(
+            Duration::from_millis(1),
+            |_| Ok(true),
+            || {
+                reads += 1;
+                Ok(CrosstermEvent::Resize(100, 40))
+            },
+        ));
+
+        // A spinning loop would wake itself straight away; a parked one
+        // only wakes for the stop check
+        assert!(run.as_mut().poll(&mut cx).is_pending());
+        assert_eq!(wakes.0.load(Ordering::SeqCst), 0);
+        std::thread::sleep(STOP_CHECK_INTERVAL / 2);
+        assert_eq!(wakes.0.load(Ordering::SeqCst), 0);
+
+        // Making room wakes the task, which delivers the pending event
+        handler.try_recv().unwrap();
+        assert!(wakes.0.load(Ordering::SeqCst) > 0);
+        handler.stop();
+        block_on(run).unwrap();
+        assert_eq!(handler.metrics().forwarded, MAX_EVENTS as u64 + 1);
+    }
+
+    #[test]
+    fn test_retry_policy_stop_check_follows_clock() {
+        let clock = Arc::new(ManualClock::new());
+        let handler = EventHandler::with_clock(clock.clone());
+        handler.set_overflow_policy(OverflowPolicy::Retry);
+        for _ in 0..MAX_EVENTS {
+            handler.try_send(Event::Resize(80, 24)).unwrap();
+        }
+
+        let stop_later = async {
+            while clock.elapsed() < STOP_CHECK_INTERVAL * 3 {
+                runtime::yield_now().await;
+            }
+            handler.stop();
+        };
+        let (queued, ()) = block_on(futures_lite::future::zip(
+            handler.forward(CrosstermEvent::Resize(100, 40)),
+            stop_later,
+        ));
+
+        // The wait is measured on the handler's clock and ends with the stop
+        assert!(queued.is_ok());
+        assert_eq!(clock.elapsed(), STOP_CHECK_INTERVAL * 3);
+        let metrics = handler.metrics();
+        assert_eq!(metrics.dropped, 1);
+        assert_eq!(metrics.forwarded, MAX_EVENTS as u64);
+    }
+
+    fn key_of_kind(c: char, kind: KeyEventKind) -> CrosstermEvent {
+        CrosstermEvent::Key(KeyEvent::new_with_kind(
+            KeyCode::Char(c),
+            KeyModifiers::NONE,
+            kind,
+        ))
+    }
+
+    /// Forwards `count` events spaced `step` apart and returns how many
+    /// reached the channel.
+    fn forwarded_burst(
+        handler: &EventHandler,
+        clock: &ManualClock,
+        count: usize,
+        step: Duration,
+        event: impl Fn(usize) -> CrosstermEvent,
+    ) -> usize {
+        for i in 0..count {
+            block_on(handler.forward(event(i))).unwrap();
+            clock.advance(step);
+        }
+
+        let mut forwarded = 0;
+        while handler.try_recv().unwrap().is_some() {
+            forwarded += 1;
+        }
+        forwarded
+    }
+
+    #[test]
+    fn test_repeat_filter() {
+        let clock = Arc::new(ManualClock::new());
+        let handler = EventHandler::with_clock(clock.clone());
+        let step = Duration::from_millis(20);
+        let held = |i: usize| {
+            let kind = if i == 0 {
+                KeyEventKind::Press
+            } else {
+                KeyEventKind::Repeat
+            };
+            key_of_kind('j', kind)
+        };
+
+        // Off by default: everything goes through
+        assert_eq!(handler.repeat_filter(), RepeatFilter::Off);
+        assert_eq!(forwarded_burst(&handler, &clock, 11, step, held), 11);
+
+        // Drop keeps only the initial press
+        handler.set_repeat_filter(RepeatFilter::Drop);
+        assert_eq!(forwarded_burst(&handler, &clock, 11, step, held), 1);
+
+        // Throttle collapses a 200ms burst to one event per 100ms
+        let throttle = RepeatFilter::Throttle(Duration::from_millis(100));
+        handler.set_repeat_filter(throttle);
+        assert_eq!(forwarded_burst(&handler, &clock, 11, step, held), 3);
+
+        // Legacy terminals: identical presses are deduplicated the same way
+        handler.set_repeat_filter(throttle);
+        let pressed = |_| key_of_kind('j', KeyEventKind::Press);
+        assert_eq!(forwarded_burst(&handler, &clock, 11, step, pressed), 3);
+
+        // Alternating keys and presses after a release are never collapsed
+        handler.set_repeat_filter(throttle);
+        let alternating = |i: usize| {
+            key_of_kind(
+                if i.is_multiple_of(2) { 'j' } else { 'k' },
+                KeyEventKind::Press,
+            )
+        };
+        assert_eq!(forwarded_burst(&handler, &clock, 4, step, alternating), 4);
+        let tapped = |i: usize| {
+            let kind = if i.is_multiple_of(2) {
+                KeyEventKind::Press
+            } else {
+                KeyEventKind::Release
+            };
+            key_of_kind('j', kind)
+        };
+        assert_eq!(forwarded_burst(&handler, &clock, 4, step, tapped), 4);
+    }
+
+    #[test]
+    fn test_tick_rate_change_applies_to_next_poll() {
+        let handler = EventHandler::new();
+        let mut timeouts = Vec::new();
+
+        let result = block_on(handler.run_with(
+            Duration::from_millis(10),
+            |timeout| {
+                timeouts.push(timeout);
+                match timeouts.len() {
+                    2 => handler.set_tick_rate(Duration::from_millis(2)),
+                    4 => handler.set_tick_rate(Duration::from_secs(60)),
+                    6 => handler.stop(),
+                    _ => {}
+                }
+                Ok(false)
+            },
+            || unreachable!("no events are reported"),
+        ));
+
+        assert!(result.is_ok());
+        assert_eq!(handler.tick_rate(), Duration::from_secs(60));
+
+        // Long rates are still polled in short slices
+        let (fast, slow) = (Duration::from_millis(2), Duration::from_millis(10));
+        let slice = STOP_CHECK_INTERVAL;
+        assert_eq!(timeouts, [slow, slow, fast, fast, slice, slice]);
+    }
+
+    #[test]
+    fn test_test_handler_idles_on_clock() {
+        let clock = Arc::new(ManualClock::new());
+        let handler = EventHandler {
+            reads_terminal: false,
+            ..EventHandler::with_clock(clock.clone())
+        };
+        handler.set_tick_events(true);
+        handler.set_tick_rate(Duration::from_millis(100));
+
+        let stop_later = async {
+            while clock.elapsed() < Duration::from_millis(300) {
+                runtime::yield_now().await;
+            }
+            handler.stop();
+        };
+        let start = std::time::Instant::now();
+        let (result, ()) = block_on(futures_lite::future::zip(
+            handler.run_at_current_rate(),
+            stop_later,
+        ));
+
+        // Idle polls pass on the manual clock without blocking the thread
+        assert!(result.is_ok());
+        assert!(start.elapsed() < Duration::from_millis(100));
+        let mut ticks = 0;
+        while let Some(event) = handler.try_recv().unwrap() {
+            assert_eq!(event, Event::Tick(Duration::from_millis(100)));
+            ticks += 1;
+        }
+        assert_eq!(ticks, 3);
+    }
+
+    #[test]
+    fn test_tick_events_report_elapsed_time() {
+        let clock = Arc::new(ManualClock::new());
+        let handler = EventHandler::with_clock(clock.clone());
+        handler.set_tick_events(true);
+        let mut polls = 0;
+
+        let result = block_on(handler.run_with(
+            Duration::from_millis(20),
+            |timeout| {
+                polls += 1;
+                // Waiting out a poll takes the full timeout, except for a
+                // slow third iteration that overruns it
+                let extra = if polls == 3 { 7 } else { 0 };
+                clock.advance(timeout + Duration::from_millis(extra));
+                if polls == 4 {
+                    handler.stop();
+                }
+                Ok(false)
+            },
+            || unreachable!("no events are reported"),
+        ));
+
+        assert!(result.is_ok());
+        let mut ticks = Vec::new();
+        while let Some(event) = handler.try_recv().unwrap() {
+            ticks.push(event);
+        }
+        assert_eq!(
+            ticks,
+            [20, 20, 27, 20].map(|ms| Event::Tick(Duration::from_millis(ms)))
+        );
+
+        // Disabled by default
+        assert!(!EventHandler::new().tick_events());
+    }
+
+    #[test]
+    fn test_zero_tick_rate_disables_ticks() {
+        let clock = Arc::new(ManualClock::new());
+        let handler = EventHandler::with_clock(clock.clone());
+        handler.set_tick_events(true);
+        let mut timeouts = Vec::new();
+
+        let result = block_on(handler.run_with(
+            Duration::ZERO,
+            |timeout| {
+                timeouts.push(timeout);
+                clock.advance(timeout);
+                if timeouts.len() == 3 {
+                    handler.stop();
+                }
+                Ok(false)
+            },
+            || unreachable!("no events are reported"),
+        ));
+
+        // Polls wait instead of spinning, and no ticks are queued
+        assert!(result.is_ok());
+        assert_eq!(timeouts, [STOP_CHECK_INTERVAL; 3]);
+        assert_eq!(handler.try_recv().unwrap(), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_quit_on_signals_queues_quit() {
+        let clock = Arc::new(ManualClock::new());
+        let handler = EventHandler::with_clock(clock.clone());
+        handler.quit_on_signals(&[Signal::Hangup]).unwrap();
+        let mut polls = 0;
+
+        let result = block_on(handler.run_with(
+            Duration::from_millis(20),
+            |timeout| {
+                polls += 1;
+                clock.advance(timeout);
+                match polls {
+                    // The handler sets the flag before `raise` returns
+                    1 => signal_hook::low_level::raise(signal_hook::consts::SIGHUP)?,
+                    3 => handler.stop(),
+                    _ => {}
+                }
+                Ok(false)
+            },
+            || unreachable!("no events are reported"),
+        ));
+
+        assert!(result.is_ok());
+        assert_eq!(handler.try_recv().unwrap(), Some(Event::Quit));
+        assert_eq!(handler.try_recv().unwrap(), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_dropped_handler_releases_signals() {
+        let unhandled = || {
+            let listeners = SIGNAL_LISTENERS.lock().unwrap();
+            let entry = listeners
+                .iter()
+                .find(|l| l.number == signal_hook::consts::SIGQUIT);
+            entry.map(|l| l.unhandled.load(Ordering::Acquire))
+        };
+        let kept = EventHandler::new();
+        kept.quit_on_signals(&[Signal::Quit]).unwrap();
+        let dropped = EventHandler::new();
+        dropped.quit_on_signals(&[Signal::Quit]).unwrap();
+        let dropped_flag = dropped.signaled.clone();
+        drop(dropped);
+
+        // Only the remaining handler still hears the signal
+        signal_hook::low_level::raise(signal_hook::consts::SIGQUIT).unwrap();
+        assert!(kept.signaled.load(Ordering::Acquire));
+        assert!(!dropped_flag.load(Ordering::Acquire));
+        assert_eq!(unhandled(), Some(false));
+
+        // Without listeners the default action applies again
+        drop(kept);
+        assert_eq!(unhandled(), Some(true));
+    }
+
+    #[test]
+    fn test_filter_drops_mouse_events() {
+        let handler = EventHandler::new().with_filter(|event| match event {
+            Event::Mouse(_) => None,
+            other => Some(other),
+        });
+        let mut reads = vec![
+            key_of_kind('a', KeyEventKind::Press),
+            CrosstermEvent::Mouse(MouseEvent {
+                kind: MouseEventKind::Moved,
+                column: 1,
+                row: 1,
+                modifiers: KeyModifiers::NONE,
+            }),
+            key_of_kind('b', KeyEventKind::Press),
+        ];
+        reads.reverse();
+        let reads = std::cell::RefCell::new(reads);
+
+        let result = block_on(handler.run_with(
+            Duration::from_millis(1),
+            |_| {
+                let pending = !reads.borrow().is_empty();
+                if !pending {
+                    handler.stop();
+                }
+                Ok(pending)
+            },
+            || Ok(reads.borrow_mut().pop().expect("polled only while pending")),
+        ));
+
+        assert!(result.is_ok());
+        let mut forwarded = Vec::new();
+        while let Some(event) = handler.try_recv().unwrap() {
+            forwarded.push(event);
+        }
+        assert_eq!(forwarded.len(), 2);
+        assert!(forwarded[0].is_char('a'));
+        assert!(forwarded[1].is_char('b'));
+        assert_eq!(handler.metrics().received, 3);
+    }
+
+    #[test]
+    fn test_pause_skips_polling() {
+        let handler = EventHandler::new();
+        let polls = AtomicUsize::new(0);
+
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                // Give the loop time to poll if pausing did not work
+                std::thread::sleep(Duration::from_millis(50));
+                assert_eq!(polls.load(Ordering::SeqCst), 1);
+                assert!(handler.is_paused());
+
+                handler.inject(Event::Quit).unwrap();
+                handler.resume();
+            });
+
+            let result = block_on(handler.run_with(
+                Duration::from_millis(1),
+                |_| {
+                    match polls.fetch_add(1, Ordering::SeqCst) + 1 {
+                        1 => handler.pause(),
+                        3 => handler.stop(),
+                        _ => {}
+                    }
+                    Ok(false)
+                },
+                || unreachable!("no events are reported"),
+            ));
+            assert!(result.is_ok());
+        });
+
+        assert_eq!(polls.load(Ordering::SeqCst), 3);
+        assert!(matches!(handler.try_recv().unwrap(), Some(Event::Quit)));
+    }
+
+    /// Runs one loop iteration over `queued` pending events and returns how
+    /// many were forwarded.
+    fn forwarded_in_one_iteration(handler: &EventHandler, queued: usize) -> usize {
+        let queued = std::cell::Cell::new(queued);
+        let mut iterations = 0;
+
+        block_on(handler.run_with(
+            Duration::from_millis(1),
+            |timeout| {
+                // Only the first poll of an iteration waits
+                if !timeout.is_zero() {
+                    iterations += 1;
+                    if iterations == 2 {
+                        handler.stop();
+                        return Ok(false);
+                    }
+                }
+                Ok(queued.get() > 0)
+            },
+            || {
+                queued.set(queued.get() - 1);
+                Ok(CrosstermEvent::Resize(80, 24))
+            },
+        ))
+        .unwrap();
+
+        let mut forwarded = 0;
+        while handler.try_recv().unwrap().is_some() {
+            forwarded += 1;
+        }
+        forwarded
+    }
+
+    #[test]
+    fn test_event_batching() {
+        // Default: one event per iteration
+        let handler = EventHandler::new();
+        assert_eq!(forwarded_in_one_iteration(&handler, 5), 1);
+
+        // Batching drains up to the limit
+        let handler = EventHandler::new();
+        handler.set_max_events_per_tick(4);
+        assert_eq!(forwarded_in_one_iteration(&handler, 5), 4);
+
+        // ...and stops early once nothing else is available
+        let handler = EventHandler::new();
+        handler.set_max_events_per_tick(4);
+        assert_eq!(forwarded_in_one_iteration(&handler, 2), 2);
+
+        // The limit is capped
+        handler.set_max_events_per_tick(usize::MAX);
+        assert_eq!(handler.max_events_per_tick(), MAX_EVENT_BATCH);
+    }
+
+    #[test]
+    fn test_fatal_poll_error_terminates() {
+        let handler = EventHandler::new();
+
+        // Non-retryable errors end the loop immediately
+        let mut polls = 0;
+        let result = block_on(handler.run_with(
+            Duration::from_millis(1),
+            |_| {
+                polls += 1;
+                Err(io::Error::other("terminal gone"))
+            },
+            || unreachable!("nothing to read after a failed poll"),
+        ));
+        assert!(result.is_err());
+        assert_eq!(polls, 1);
+
+        // Retryable errors give up once the retry budget is spent
+        let mut polls = 0;
+        let result = block_on(handler.run_with(
+            Duration::from_millis(1),
+            |_| {
+                polls += 1;
+                Err(io::Error::from(io::ErrorKind::Interrupted))
+            },
+            || unreachable!("nothing to read after a failed poll"),
+        ));
+        assert!(result.is_err());
+        assert_eq!(polls, MAX_POLL_RETRIES + 1);
+    }
+
+    #[test]
+    fn test_stop_interrupts_long_tick() {
+        let handler = EventHandler::new();
+        let start = std::time::Instant::now();
+
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                std::thread::sleep(Duration::from_millis(50));
+                handler.stop();
+            });
+            // Block in poll for the whole timeout, as a quiet terminal would
+            let result = block_on(handler.run_with(
+                Duration::from_millis(500),
+                |timeout| {
+                    std::thread::sleep(timeout);
+                    Ok(false)
+                },
+                || unreachable!("no events are reported"),
+            ));
+            assert!(result.is_ok());
+        });
+
+        assert!(start.elapsed() < Duration::from_millis(250));
     }
 }
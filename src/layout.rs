@@ -0,0 +1,122 @@
+//! Minimum-size layout guards
+//!
+//! Widgets often draw a full layout when there is room and fall back to a
+//! compact one otherwise. These helpers answer "does it fit?" up front so
+//! render code can branch once instead of repeating `area.width >= N` checks.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use oxitty::layout::{fit_or_compact, split_min};
+//! use ratatui::layout::{Constraint, Direction, Rect, Size};
+//!
+//! let area = Rect::new(0, 0, 40, 10);
+//!
+//! if fit_or_compact(area, Size::new(30, 8)) {
+//!     // Draw the full layout
+//! }
+//!
+//! let columns = [Constraint::Length(20), Constraint::Min(10)];
+//! let split = split_min(area, Direction::Horizontal, &columns).unwrap();
+//! assert_eq!(split[0].width, 20);
+//!
+//! // Not enough room for both columns
+//! assert!(split_min(Rect::new(0, 0, 25, 10), Direction::Horizontal, &columns).is_none());
+//! ```
+
+use std::rc::Rc;
+
+use ratatui::layout::{Constraint, Direction, Layout, Rect, Size};
+
+/// Returns `true` if `area` is at least `min` in both dimensions.
+///
+/// `true` means the full layout fits; `false` means the caller should fall
+/// back to its compact layout.
+///
+/// # Arguments
+///
+/// * `area` - The area available for drawing
+/// * `min` - Smallest size the full layout needs
+pub fn fit_or_compact(area: Rect, min: Size) -> bool {
+    area.width >= min.width && area.height >= min.height
+}
+
+/// Splits `area` only if every constraint's minimum fits.
+///
+/// The minimum of a constraint is its `Length` or `Min` value; `Max`,
+/// `Percentage`, `Ratio` and `Fill` can shrink to nothing and need no room.
+///
+/// # Arguments
+///
+/// * `area` - The area to split
+/// * `direction` - Axis to split along
+/// * `constraints` - Constraints for the resulting segments
+///
+/// # Returns
+///
+/// * `Some(rects)` - The split, as produced by [`Layout::split`]
+/// * `None` - The constraints' minimums exceed the area along `direction`
+pub fn split_min(
+    area: Rect,
+    direction: Direction,
+    constraints: &[Constraint],
+) -> Option<Rc<[Rect]>> {
+    let available = match direction {
+        Direction::Horizontal => area.width,
+        Direction::Vertical => area.height,
+    };
+    let required: u32 = constraints
+        .iter()
+        .map(|constraint| match constraint {
+            Constraint::Length(n) | Constraint::Min(n) => u32::from(*n),
+            _ => 0,
+        })
+        .sum();
+
+    (required <= u32::from(available))
+        .then(|| Layout::new(direction, constraints.iter().copied()).split(area))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_or_compact() {
+        let min = Size::new(20, 5);
+
+        assert!(fit_or_compact(Rect::new(0, 0, 20, 5), min));
+        assert!(fit_or_compact(Rect::new(3, 3, 21, 6), min));
+        assert!(!fit_or_compact(Rect::new(0, 0, 19, 5), min));
+        assert!(!fit_or_compact(Rect::new(0, 0, 20, 4), min));
+        assert!(fit_or_compact(Rect::ZERO, Size::new(0, 0)));
+    }
+
+    #[test]
+    fn test_split_min() {
+        let constraints = [
+            Constraint::Length(3),
+            Constraint::Min(5),
+            Constraint::Percentage(50),
+        ];
+
+        // Exactly at the minimum
+        let rects = split_min(Rect::new(0, 0, 10, 8), Direction::Vertical, &constraints).unwrap();
+        assert_eq!(rects.len(), 3);
+        assert_eq!(rects[0].height, 3);
+        assert_eq!(rects[1].height, 5);
+
+        // Above the minimum
+        let rects = split_min(Rect::new(0, 0, 10, 20), Direction::Vertical, &constraints).unwrap();
+        assert_eq!(rects[0].height, 3);
+        assert!(rects[1].height >= 5);
+
+        // Below the minimum, along either axis
+        assert!(split_min(Rect::new(0, 0, 10, 7), Direction::Vertical, &constraints).is_none());
+        assert!(split_min(Rect::new(0, 0, 7, 20), Direction::Horizontal, &constraints).is_none());
+
+        // Sums beyond u16 do not overflow
+        let huge = [Constraint::Length(u16::MAX), Constraint::Length(u16::MAX)];
+        assert!(split_min(Rect::new(0, 0, u16::MAX, 1), Direction::Horizontal, &huge).is_none());
+    }
+}
@@ -69,23 +69,29 @@
 //!         running: AtomicBool::new(true),
 //!     };
 //!
-//!     smol::block_on(async {
+//!     let exit_code = smol::block_on(async {
 //!         let mut app = App::new(state, Duration::from_millis(50))?;
 //!         app.run(|snapshot, area, frame| {
 //!             // Your render logic here
 //!         }).await
-//!     })
+//!     })?;
+//!
+//!     std::process::exit(exit_code)
 //! }
 //! ```
 //!
 //! ## Module Organization
 //!
 //! - `app`: Application orchestration and lifecycle management
+//! - `clock`: Injectable time source for deterministic timing
 //! - `colors`: Color system with theme support
 //! - `error`: Error types and handling
 //! - `event`: Event processing system
+//! - `layout`: Minimum-size guards for choosing between full and compact layouts
+//! - `runtime`: Async runtime touchpoints, isolated for swapping executors
 //! - `state`: State management traits
 //! - `tui`: Terminal interface management
+//! - `widgets`: Themed ratatui widgets such as a gradient progress bar
 //!
 //! ## Feature Highlights
 //!
@@ -107,25 +113,43 @@
 //! with support for RGBA colors, color space conversions, and semantic theming.
 
 /// Re-exports of core components
-pub use app::App;
-pub use colors::{Color, ThemeColorize};
+pub use app::{App, EventFlow};
+pub use colors::{Color, ColorAnimator, Theme, ThemeColorize, ThemeStyle};
 pub use error::{OxittyError, OxittyResult};
 pub use event::{Event, EventHandler};
 pub use state::{AtomicState, StateSnapshot};
-pub use tui::Tui;
+pub use tui::{Semantic, Severity, ThemeContext, Tui};
+
+/// Paths used by exported macros
+#[doc(hidden)]
+pub mod __private {
+    pub use crossterm::event::{KeyCode, KeyModifiers};
+}
 
 /// Application orchestration module
 pub mod app;
+/// Injectable time source
+pub mod clock;
 /// Color system and theme management
 pub mod colors;
+/// Debouncing of rapid inputs
+pub mod debounce;
 /// Error types and handling
 pub mod error;
 /// Event processing system
 pub mod event;
+/// Minimum-size layout guards
+pub mod layout;
+/// Commonly used types in one import
+pub mod prelude;
+/// Async runtime touchpoints
+pub mod runtime;
 /// State management traits
 pub mod state;
 /// Terminal interface management
 pub mod tui;
+/// Themed ratatui widgets
+pub mod widgets;
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,85 @@
+//! Commonly used types in one import
+//!
+//! Brings the application, state, event and color types most programs
+//! need into scope, along with the crossterm key types used by key
+//! handlers, so a typical application only needs `use oxitty::prelude::*;`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use oxitty::prelude::*;
+//! use ratatui::{layout::Size, widgets::Paragraph};
+//!
+//! #[derive(Debug)]
+//! struct AppState {
+//!     flags: StateFlags,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! struct AppSnapshot {
+//!     running: bool,
+//! }
+//!
+//! impl StateSnapshot for AppSnapshot {
+//!     fn should_quit(&self) -> bool {
+//!         !self.running
+//!     }
+//! }
+//!
+//! impl AtomicState for AppState {
+//!     type Snapshot = AppSnapshot;
+//!
+//!     fn snapshot(&self) -> Self::Snapshot {
+//!         AppSnapshot {
+//!             running: self.flags.get(StateFlags::RUNNING),
+//!         }
+//!     }
+//!
+//!     fn quit(&self) {
+//!         self.flags.set(StateFlags::RUNNING, false);
+//!     }
+//!
+//!     fn is_running(&self) -> bool {
+//!         self.flags.get(StateFlags::RUNNING)
+//!     }
+//! }
+//!
+//! fn handle_key(key: &KeyEvent, state: &AppState) -> EventFlow {
+//!     if key.code == KeyCode::Char('c') && key.modifiers == KeyModifiers::CONTROL {
+//!         state.quit();
+//!     }
+//!     EventFlow::Continue
+//! }
+//!
+//! fn main() -> OxittyResult<()> {
+//!     let state = AppState {
+//!         flags: StateFlags::with_flags(&[(StateFlags::RUNNING, true)]),
+//!     };
+//!     let mut tui = Tui::headless(state, Size::new(20, 1));
+//!
+//!     let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+//!     handle_key(&ctrl_c, tui.state());
+//!     assert!(!tui.state().is_running());
+//!
+//!     tui.render(|snapshot, area, frame| {
+//!         let text = if snapshot.running { "running" } else { "stopped" };
+//!         frame.render_widget(Paragraph::new(text), area);
+//!     })?;
+//!
+//!     let accent = Color::from_hex("#b86eff").unwrap();
+//!     assert_eq!(accent.rgb_components(), (184, 110, 255));
+//!     println!("{}", "done".void_purple());
+//!     Ok(())
+//! }
+//! ```
+
+pub use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+pub use crate::{
+    app::{App, EventFlow},
+    colors::{Color, ThemeColorize},
+    error::{OxittyError, OxittyResult},
+    event::{Event, EventHandler, KeyEventExt},
+    state::{AtomicState, StateFlags, StateSnapshot},
+    tui::Tui,
+};
@@ -0,0 +1,148 @@
+//! Async runtime touchpoints
+//!
+//! Every call into the async runtime goes through this module: spawning
+//! tasks, sleeping, yielding, racing futures, blocking on a future and the
+//! event channel. The crate currently runs on `smol`.
+//!
+//! The [`Task`], [`Receiver`], [`Sender`] and [`Stream`] items are smol's
+//! own types re-exported as-is, and they appear in public signatures such
+//! as [`App::add_event_source`](crate::App::add_event_source) and
+//! [`EventHandler::stream`](crate::EventHandler::stream). Another executor
+//! would need its own implementations here and would change those
+//! signatures too.
+//!
+//! # Examples
+//!
+//! Driving an [`App`](crate::App) loop headlessly on the runtime:
+//!
+//! ```rust
+//! use std::{sync::atomic::{AtomicBool, Ordering}, time::Duration};
+//! use oxitty::{runtime, App, AtomicState, Event, StateSnapshot, Tui};
+//! use ratatui::backend::TestBackend;
+//!
+//! #[derive(Debug)]
+//! struct AppState {
+//!     running: AtomicBool,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! struct AppSnapshot {
+//!     running: bool,
+//! }
+//!
+//! impl StateSnapshot for AppSnapshot {
+//!     fn should_quit(&self) -> bool {
+//!         !self.running
+//!     }
+//! }
+//!
+//! impl AtomicState for AppState {
+//!     type Snapshot = AppSnapshot;
+//!     fn snapshot(&self) -> Self::Snapshot {
+//!         AppSnapshot {
+//!             running: self.running.load(Ordering::Acquire),
+//!         }
+//!     }
+//!     fn quit(&self) {
+//!         self.running.store(false, Ordering::Release);
+//!     }
+//!     fn is_running(&self) -> bool {
+//!         self.running.load(Ordering::Acquire)
+//!     }
+//! }
+//!
+//! let state = AppState {
+//!     running: AtomicBool::new(true),
+//! };
+//! let tui = Tui::with_backend(state, TestBackend::new(20, 5)).unwrap();
+//! let mut app = App::with_tui(tui, Duration::from_millis(10));
+//! app.events().try_send(Event::Quit).unwrap();
+//!
+//! let exit_code = runtime::block_on(app.run(|_, _, _| {})).unwrap();
+//! assert_eq!(exit_code, 0);
+//! ```
+
+use std::{future::Future, time::Duration};
+
+use smol::future::FutureExt;
+
+pub use smol::Task;
+
+pub use smol::channel::{bounded, unbounded, Receiver, Sender, TryRecvError, TrySendError};
+
+pub use smol::stream::Stream;
+
+/// Spawns a future onto the global executor.
+///
+/// The returned [`Task`] cancels the future when dropped; detach or await it
+/// to let it finish.
+///
+/// # Arguments
+///
+/// * `future` - The future to run in the background
+pub fn spawn<T, F>(future: F) -> Task<T>
+where
+    T: Send + 'static,
+    F: Future<Output = T> + Send + 'static,
+{
+    smol::spawn(future)
+}
+
+/// Runs a future to completion on the current thread.
+///
+/// # Arguments
+///
+/// * `future` - The future to drive
+pub fn block_on<T>(future: impl Future<Output = T>) -> T {
+    smol::block_on(future)
+}
+
+/// Completes after `duration` has elapsed.
+///
+/// # Arguments
+///
+/// * `duration` - How long to wait
+pub async fn sleep(duration: Duration) {
+    smol::Timer::after(duration).await;
+}
+
+/// Yields once to let other tasks run.
+pub async fn yield_now() {
+    smol::future::yield_now().await;
+}
+
+/// Polls both futures and returns the output of whichever finishes first.
+///
+/// `first` is polled before `second` each time, so it wins ties.
+pub(crate) async fn race<T>(first: impl Future<Output = T>, second: impl Future<Output = T>) -> T {
+    first.or(second).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_race_prefers_first_ready() {
+        let winner = block_on(race(async { 1 }, async { 2 }));
+        assert_eq!(winner, 1);
+
+        let winner = block_on(race(
+            async {
+                sleep(Duration::from_secs(5)).await;
+                1
+            },
+            async { 2 },
+        ));
+        assert_eq!(winner, 2);
+    }
+
+    #[test]
+    fn test_spawned_task_completes() {
+        let task = spawn(async {
+            yield_now().await;
+            42
+        });
+        assert_eq!(block_on(task), 42);
+    }
+}
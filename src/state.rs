@@ -6,11 +6,14 @@
 //!
 //! # Architecture
 //!
-//! The state system uses three key components:
+//! The state system uses four key components:
 //!
 //! - [`StateFlags`]: Low-level atomic bitfield operations using a single `AtomicU64`
 //! - [`StateSnapshot`]: Zero-copy, immutable view of application state
 //! - [`AtomicState`]: Trait defining thread-safe state behavior
+//! - [`Flag`]: Type-checked flag positions, declared with [`typed_flags!`](crate::typed_flags)
+//! - [`atomic_state!`](crate::atomic_state): Generates [`AtomicState`] for a struct with a [`StateFlags`] field
+//! - [`RenderState`]: "Changed since last render" tracking for change-based redraws
 //!
 //! # Performance
 //!
@@ -84,8 +87,9 @@
 //! assert!(snapshot.should_quit());
 //! ```
 
+use event_listener::Event;
 use std::fmt::Debug;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 
 /// Thread-safe state flag container using a bitfield approach.
 /// Provides atomic operations for state transitions and snapshots.
@@ -93,6 +97,8 @@ use std::sync::atomic::{AtomicU64, Ordering};
 pub struct StateFlags {
     /// Internal bitfield storing all state flags
     flags: AtomicU64,
+    /// Wakes [`wait_for`](Self::wait_for) callers after every update
+    changed: Event,
 }
 
 impl StateFlags {
@@ -130,9 +136,56 @@ impl StateFlags {
     pub const fn new(initial: u64) -> Self {
         Self {
             flags: AtomicU64::new(initial),
+            changed: Event::new(),
         }
     }
 
+    /// Creates a new state flags container from named flag values.
+    ///
+    /// Saves building the initial bitfield by hand or calling
+    /// [`set`](Self::set) once per flag after construction. Flags not listed
+    /// start cleared, and a later entry for the same flag overrides an
+    /// earlier one. Usable in `const` and `static` initializers.
+    ///
+    /// # Arguments
+    ///
+    /// * `flags` - Flag positions (0-63) paired with their initial values
+    ///
+    /// # Panics
+    ///
+    /// Panics if a flag position is >= MAX_FLAGS
+    ///
+    /// # Examples
+    ///
+    /// Starting a state with the app running and debug output enabled:
+    ///
+    /// ```rust
+    /// use oxitty::state::StateFlags;
+    ///
+    /// let flags = StateFlags::with_flags(&[
+    ///     (StateFlags::RUNNING, true),
+    ///     (StateFlags::DEBUG, true),
+    /// ]);
+    /// assert!(flags.get(StateFlags::RUNNING));
+    /// assert!(flags.get(StateFlags::DEBUG));
+    /// assert!(!flags.get(StateFlags::PROCESSING));
+    /// ```
+    pub const fn with_flags(flags: &[(u32, bool)]) -> Self {
+        let mut bits = 0u64;
+        let mut i = 0;
+        while i < flags.len() {
+            let (flag, value) = flags[i];
+            assert!(flag < Self::MAX_FLAGS, "Flag position out of bounds");
+            if value {
+                bits |= 1 << flag;
+            } else {
+                bits &= !(1 << flag);
+            }
+            i += 1;
+        }
+        Self::new(bits)
+    }
+
     /// Creates a new state flags container with all flags set to false.
     ///
     /// # Examples
@@ -167,6 +220,12 @@ impl StateFlags {
     /// * `flag` - Flag position to modify (0-63)
     /// * `value` - New value for the flag
     ///
+    /// # Returns
+    ///
+    /// `true` if the flag transitioned, `false` if it already held `value`.
+    /// The check happens in the same atomic operation as the update, so
+    /// there's no need for a racy `get` beforehand.
+    ///
     /// # Panics
     ///
     /// Panics if flag >= MAX_FLAGS
@@ -177,8 +236,9 @@ impl StateFlags {
     /// use oxitty::state::StateFlags;
     ///
     /// let flags = StateFlags::default();
-    /// flags.set(StateFlags::RUNNING, true);
+    /// assert!(flags.set(StateFlags::RUNNING, true));
     /// assert!(flags.get(StateFlags::RUNNING));
+    /// assert!(!flags.set(StateFlags::RUNNING, true));
     /// ```
     ///
     /// # Performance Notes
@@ -190,11 +250,12 @@ impl StateFlags {
     /// - UI operations dominate performance considerations
     /// - Modern CPUs optimize `SeqCst` operations effectively
     #[inline]
-    pub fn set(&self, flag: u32, value: bool) {
+    pub fn set(&self, flag: u32, value: bool) -> bool {
         debug_assert!(flag < Self::MAX_FLAGS, "Flag position out of bounds");
         let mask = 1u64 << flag;
 
-        self.flags
+        let previous = self
+            .flags
             .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |current| {
                 Some(if value {
                     current | mask
@@ -203,6 +264,136 @@ impl StateFlags {
                 })
             })
             .expect("fetch_update cannot fail with Some");
+        self.changed.notify(usize::MAX);
+
+        ((previous & mask) != 0) != value
+    }
+
+    /// Flips a specific flag's value with sequential consistency.
+    ///
+    /// # Arguments
+    ///
+    /// * `flag` - Flag position to flip (0-63)
+    ///
+    /// # Returns
+    ///
+    /// The new value of the flag.
+    ///
+    /// # Panics
+    ///
+    /// Panics if flag >= MAX_FLAGS
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::state::StateFlags;
+    ///
+    /// let flags = StateFlags::default();
+    /// assert!(flags.toggle(StateFlags::DEBUG));
+    /// assert!(!flags.toggle(StateFlags::DEBUG));
+    /// ```
+    #[inline]
+    pub fn toggle(&self, flag: u32) -> bool {
+        debug_assert!(flag < Self::MAX_FLAGS, "Flag position out of bounds");
+        let mask = 1u64 << flag;
+
+        let previous = self.flags.fetch_xor(mask, Ordering::SeqCst);
+        self.changed.notify(usize::MAX);
+        (previous & mask) == 0
+    }
+
+    /// Atomically sets a flag to `new` only if it currently equals `expected`.
+    ///
+    /// This is a test-and-set primitive for exclusive transitions, such as
+    /// letting exactly one thread move `RENDERING` from `false` to `true`.
+    ///
+    /// # Arguments
+    ///
+    /// * `flag` - Flag position to modify (0-63)
+    /// * `expected` - Value the flag must currently hold
+    /// * `new` - Value to store if the flag matched
+    ///
+    /// # Returns
+    ///
+    /// `true` if the flag matched `expected` and was updated, `false` otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if flag >= MAX_FLAGS
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::state::StateFlags;
+    ///
+    /// let flags = StateFlags::default();
+    /// assert!(flags.compare_and_set(StateFlags::RENDERING, false, true));
+    /// assert!(!flags.compare_and_set(StateFlags::RENDERING, false, true));
+    /// ```
+    #[inline]
+    pub fn compare_and_set(&self, flag: u32, expected: bool, new: bool) -> bool {
+        debug_assert!(flag < Self::MAX_FLAGS, "Flag position out of bounds");
+        let mask = 1u64 << flag;
+
+        let updated = self
+            .flags
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |current| {
+                if ((current & mask) != 0) != expected {
+                    return None;
+                }
+                Some(if new { current | mask } else { current & !mask })
+            })
+            .is_ok();
+        if updated {
+            self.changed.notify(usize::MAX);
+        }
+        updated
+    }
+
+    /// Atomically applies a custom transition to the whole bitfield.
+    ///
+    /// `f` receives the current bitfield and returns the bitfield to store,
+    /// or `None` to leave the flags untouched. This exposes the underlying
+    /// compare-and-swap loop, so `f` may run more than once if another thread
+    /// changes the flags concurrently and should not have side effects.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Transition from the current bitfield to the new one
+    ///
+    /// # Returns
+    ///
+    /// `true` if `f` returned `Some` and the new bitfield was committed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::state::StateFlags;
+    ///
+    /// let running = 1 << StateFlags::RUNNING;
+    /// let processing = 1 << StateFlags::PROCESSING;
+    ///
+    /// // Start processing only while running
+    /// let start = |bits: u64| (bits & running != 0).then_some(bits | processing);
+    ///
+    /// let flags = StateFlags::default();
+    /// assert!(!flags.transact(start));
+    /// assert!(!flags.get(StateFlags::PROCESSING));
+    ///
+    /// flags.set(StateFlags::RUNNING, true);
+    /// assert!(flags.transact(start));
+    /// assert!(flags.get(StateFlags::PROCESSING));
+    /// ```
+    #[inline]
+    pub fn transact(&self, f: impl FnMut(u64) -> Option<u64>) -> bool {
+        let committed = self
+            .flags
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, f)
+            .is_ok();
+        if committed {
+            self.changed.notify(usize::MAX);
+        }
+        committed
     }
 
     /// Gets the current value of a specific flag with sequential consistency.
@@ -288,6 +479,136 @@ impl StateFlags {
         FlagsSnapshot(self.flags.load(Ordering::SeqCst))
     }
 
+    /// Returns the raw bitfield with sequential consistency.
+    ///
+    /// Equivalent to `snapshot().raw()`, for code that manipulates the
+    /// whole word, such as custom transactional updates built on
+    /// [`swap`](Self::swap).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::state::StateFlags;
+    ///
+    /// let flags = StateFlags::new(1 << StateFlags::DEBUG);
+    /// assert_eq!(flags.load(), 1 << StateFlags::DEBUG);
+    /// ```
+    #[inline]
+    pub fn load(&self) -> u64 {
+        self.flags.load(Ordering::SeqCst)
+    }
+
+    /// Replaces every flag at once and returns the previous bitfield.
+    ///
+    /// The exchange is a single atomic operation, so no thread can observe
+    /// or lose an update between reading the old value and installing the
+    /// new one. Useful for bulk resets and migrating between flag layouts.
+    ///
+    /// # Arguments
+    ///
+    /// * `new` - Bitfield to install
+    ///
+    /// # Returns
+    ///
+    /// The bitfield held immediately before the exchange.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::state::StateFlags;
+    ///
+    /// let flags = StateFlags::new((1 << StateFlags::RUNNING) | (1 << StateFlags::HAS_ERROR));
+    ///
+    /// // Clear everything but keep the previous state for inspection
+    /// let old = flags.swap(0);
+    /// assert_eq!(old, (1 << StateFlags::RUNNING) | (1 << StateFlags::HAS_ERROR));
+    /// assert_eq!(flags.load(), 0);
+    /// ```
+    #[inline]
+    pub fn swap(&self, new: u64) -> u64 {
+        let old = self.flags.swap(new, Ordering::SeqCst);
+        self.changed.notify(usize::MAX);
+        old
+    }
+
+    /// Sets every flag in a mask with a single atomic OR.
+    ///
+    /// Flags outside the mask are left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `mask` - Bitfield of the flags to set
+    ///
+    /// # Returns
+    ///
+    /// The bitfield held immediately before the update.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::state::StateFlags;
+    ///
+    /// let flags = StateFlags::new(1 << StateFlags::RUNNING);
+    /// let busy = (1 << StateFlags::PROCESSING) | (1 << StateFlags::RENDERING);
+    ///
+    /// assert_eq!(flags.set_mask(busy), 1 << StateFlags::RUNNING);
+    /// assert_eq!(flags.load(), busy | (1 << StateFlags::RUNNING));
+    /// ```
+    #[inline]
+    pub fn set_mask(&self, mask: u64) -> u64 {
+        let old = self.flags.fetch_or(mask, Ordering::SeqCst);
+        self.changed.notify(usize::MAX);
+        old
+    }
+
+    /// Clears every flag in a mask with a single atomic AND.
+    ///
+    /// Flags outside the mask are left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `mask` - Bitfield of the flags to clear
+    ///
+    /// # Returns
+    ///
+    /// The bitfield held immediately before the update.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::state::StateFlags;
+    ///
+    /// let busy = (1 << StateFlags::PROCESSING) | (1 << StateFlags::RENDERING);
+    /// let flags = StateFlags::new(busy | (1 << StateFlags::RUNNING));
+    ///
+    /// flags.clear_mask(busy);
+    /// assert_eq!(flags.load(), 1 << StateFlags::RUNNING);
+    /// ```
+    #[inline]
+    pub fn clear_mask(&self, mask: u64) -> u64 {
+        let old = self.flags.fetch_and(!mask, Ordering::SeqCst);
+        self.changed.notify(usize::MAX);
+        old
+    }
+
+    /// Gets the current value of a typed flag.
+    ///
+    /// Type-checked counterpart of [`get`](Self::get) for flags declared
+    /// with [`typed_flags!`](crate::typed_flags).
+    #[inline]
+    pub fn get_typed<F: Flag>(&self, flag: F) -> bool {
+        self.get(flag.position())
+    }
+
+    /// Sets the value of a typed flag.
+    ///
+    /// Type-checked counterpart of [`set`](Self::set), returning whether the
+    /// flag transitioned.
+    #[inline]
+    pub fn set_typed<F: Flag>(&self, flag: F, value: bool) -> bool {
+        self.set(flag.position(), value)
+    }
+
     /// Updates multiple flags atomically with sequential consistency.
     ///
     /// This method ensures that all specified flag updates happen in a single
@@ -350,6 +671,51 @@ impl StateFlags {
                 Some((current & !mask) | (new_values & mask))
             })
             .expect("fetch_update cannot fail with Some");
+        self.changed.notify(usize::MAX);
+    }
+
+    /// Waits until a flag holds the given value.
+    ///
+    /// Resolves immediately if the flag already matches. Otherwise the
+    /// future sleeps until the flags are next updated through this container
+    /// and re-checks, so spurious wakeups from changes to unrelated flags are
+    /// handled transparently.
+    ///
+    /// # Arguments
+    ///
+    /// * `flag` - Flag position to watch (0-63)
+    /// * `value` - Value to wait for
+    ///
+    /// # Panics
+    ///
+    /// Panics if flag >= MAX_FLAGS
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::state::StateFlags;
+    ///
+    /// let flags = StateFlags::new(1 << StateFlags::RUNNING);
+    ///
+    /// std::thread::scope(|scope| {
+    ///     scope.spawn(|| flags.set(StateFlags::RUNNING, false));
+    ///     smol::block_on(flags.wait_for(StateFlags::RUNNING, false));
+    /// });
+    /// assert!(!flags.get(StateFlags::RUNNING));
+    /// ```
+    pub async fn wait_for(&self, flag: u32, value: bool) {
+        loop {
+            if self.get(flag) == value {
+                return;
+            }
+
+            // Register before re-checking so an update in between still wakes us
+            let listener = self.changed.listen();
+            if self.get(flag) == value {
+                return;
+            }
+            listener.await;
+        }
     }
 }
 
@@ -381,6 +747,35 @@ impl FlagsSnapshot {
         (self.0 & (1u64 << flag)) != 0
     }
 
+    /// Gets the value of a typed flag in the snapshot.
+    ///
+    /// Type-checked counterpart of [`get`](Self::get) for flags declared
+    /// with [`typed_flags!`](crate::typed_flags).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::{state::StateFlags, typed_flags};
+    ///
+    /// typed_flags! {
+    ///     enum MyFlag {
+    ///         Running = StateFlags::RUNNING,
+    ///         Loading = 8,
+    ///     }
+    /// }
+    ///
+    /// let flags = StateFlags::default();
+    /// flags.set_typed(MyFlag::Loading, true);
+    ///
+    /// let snapshot = flags.snapshot();
+    /// assert!(snapshot.get_typed(MyFlag::Loading));
+    /// assert!(!snapshot.get_typed(MyFlag::Running));
+    /// ```
+    #[inline]
+    pub fn get_typed<F: Flag>(&self, flag: F) -> bool {
+        self.get(flag.position())
+    }
+
     /// Returns the raw flags value.
     ///
     /// This is primarily useful for debugging or custom flag manipulation.
@@ -390,6 +785,181 @@ impl FlagsSnapshot {
     }
 }
 
+/// Flag snapshots double as application snapshots.
+///
+/// Reads the standard flags: the application should quit once
+/// [`RUNNING`](StateFlags::RUNNING) is cleared, is loading while
+/// [`PROCESSING`](StateFlags::PROCESSING) is set and has an error while
+/// [`HAS_ERROR`](StateFlags::HAS_ERROR) is set.
+impl StateSnapshot for FlagsSnapshot {
+    #[inline]
+    fn should_quit(&self) -> bool {
+        !self.get(StateFlags::RUNNING)
+    }
+
+    #[inline]
+    fn is_loading(&self) -> bool {
+        self.get(StateFlags::PROCESSING)
+    }
+
+    #[inline]
+    fn has_error(&self) -> bool {
+        self.get(StateFlags::HAS_ERROR)
+    }
+}
+
+/// A named flag with a fixed bit position in [`StateFlags`].
+///
+/// Implementing this for an enum gives compile-time checked flag access via
+/// [`StateFlags::get_typed`] and [`FlagsSnapshot::get_typed`] instead of bare
+/// `u32` positions. Use [`typed_flags!`](crate::typed_flags) to declare one.
+pub trait Flag: Copy {
+    /// Bit position of the flag (0-63)
+    fn position(self) -> u32;
+}
+
+/// Declares an enum of typed flags implementing [`Flag`].
+///
+/// Each variant is assigned an explicit bit position. Positions are checked
+/// against [`StateFlags::MAX_FLAGS`] at compile time.
+///
+/// # Examples
+///
+/// ```rust
+/// use oxitty::{state::StateFlags, typed_flags};
+///
+/// typed_flags! {
+///     /// Flags used by my application
+///     pub enum AppFlag {
+///         Running = StateFlags::RUNNING,
+///         /// Set while a sync is in progress
+///         Syncing = 8,
+///     }
+/// }
+///
+/// let flags = StateFlags::default();
+/// flags.set_typed(AppFlag::Syncing, true);
+/// assert!(flags.get_typed(AppFlag::Syncing));
+/// ```
+#[macro_export]
+macro_rules! typed_flags {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $position:expr),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant),+
+        }
+
+        impl $crate::state::Flag for $name {
+            #[inline]
+            fn position(self) -> u32 {
+                match self {
+                    $($name::$variant => $position),+
+                }
+            }
+        }
+
+        const _: () = {
+            $(assert!(
+                $position < $crate::state::StateFlags::MAX_FLAGS,
+                "Flag position out of bounds"
+            );)+
+        };
+    };
+}
+
+/// Tracks whether state changed since the last rendered frame.
+///
+/// Embed it in an [`AtomicState`] implementation, call
+/// [`mark_dirty`](Self::mark_dirty) after every mutation and return it from
+/// [`AtomicState::render_state`]. [`App::run`](crate::App::run) then only
+/// renders when something changed. It also counts changes, so the same
+/// value can back [`AtomicState::generation`].
+///
+/// A new `RenderState` starts dirty so the first frame is always drawn.
+/// Any number of changes between two frames are coalesced into the next
+/// one; [`pending_changes`](Self::pending_changes) reports how many.
+///
+/// # Examples
+///
+/// ```rust
+/// use oxitty::state::RenderState;
+///
+/// let render = RenderState::new();
+/// assert!(render.take_dirty());
+/// assert!(!render.take_dirty());
+///
+/// render.mark_dirty();
+/// render.mark_dirty();
+/// assert_eq!(render.pending_changes(), 2);
+/// assert!(render.take_dirty());
+/// assert_eq!(render.generation(), 2);
+/// assert_eq!(render.pending_changes(), 0);
+/// ```
+#[derive(Debug)]
+pub struct RenderState {
+    /// Set on every change, cleared when a frame is rendered
+    dirty: AtomicBool,
+    /// Number of changes marked so far
+    generation: AtomicU64,
+    /// Generation when the dirty flag was last taken
+    taken: AtomicU64,
+}
+
+impl RenderState {
+    /// Creates a render state that is dirty, so the first frame renders.
+    pub const fn new() -> Self {
+        Self {
+            dirty: AtomicBool::new(true),
+            generation: AtomicU64::new(0),
+            taken: AtomicU64::new(0),
+        }
+    }
+
+    /// Records that the state changed and the next frame should render.
+    pub fn mark_dirty(&self) {
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        self.dirty.store(true, Ordering::Release);
+    }
+
+    /// Clears the dirty flag, returning whether it was set.
+    ///
+    /// Also resets [`pending_changes`](Self::pending_changes) to zero.
+    pub fn take_dirty(&self) -> bool {
+        self.taken.store(self.generation(), Ordering::Release);
+        self.dirty.swap(false, Ordering::AcqRel)
+    }
+
+    /// Returns how many changes were marked since the dirty flag was last taken.
+    ///
+    /// These are the changes the next frame coalesces into a single render.
+    pub fn pending_changes(&self) -> u64 {
+        self.generation()
+            .saturating_sub(self.taken.load(Ordering::Acquire))
+    }
+
+    /// Returns whether a change is waiting to be rendered, without clearing it.
+    pub fn is_dirty(&self) -> bool {
+        self.dirty.load(Ordering::Acquire)
+    }
+
+    /// Returns how many times [`mark_dirty`](Self::mark_dirty) was called.
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
+}
+
+impl Default for RenderState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Trait for implementing thread-safe state behavior.
 ///
 /// This trait defines the core interface for atomic state management,
@@ -405,6 +975,22 @@ pub trait AtomicState: Send + Sync + Debug + 'static {
     /// view of the state at a single point in time.
     fn snapshot(&self) -> Self::Snapshot;
 
+    /// Updates an existing snapshot in place to match the current state.
+    ///
+    /// [`Tui`](crate::Tui) keeps its last snapshot and refreshes it through
+    /// this method on every frame, so states with large snapshots can
+    /// override it to reuse allocations, for example by clearing and
+    /// refilling a `Vec` instead of building a new one. The result must
+    /// equal what [`snapshot`](Self::snapshot) would return. Defaults to
+    /// replacing `buf` with a fresh snapshot.
+    ///
+    /// # Arguments
+    ///
+    /// * `buf` - A previous snapshot of this state to overwrite
+    fn snapshot_into(&self, buf: &mut Self::Snapshot) {
+        *buf = self.snapshot();
+    }
+
     /// Signals the application to quit.
     ///
     /// This method should atomically update the state to indicate that
@@ -416,15 +1002,195 @@ pub trait AtomicState: Send + Sync + Debug + 'static {
     /// Returns the current running state of the application, using
     /// appropriate atomic operations for thread safety.
     fn is_running(&self) -> bool;
+
+    /// Returns the exit code the application should report once it stops.
+    ///
+    /// Implementations that distinguish user-requested quits from
+    /// error-triggered ones can override this; [`App::run`](crate::App::run)
+    /// returns its value after the event loop terminates. Defaults to `0`.
+    fn exit_code(&self) -> i32 {
+        0
+    }
+
+    /// Returns the state to its initial values.
+    ///
+    /// Used by [`App::restart`](crate::App::restart) to start over without
+    /// reconstructing the application. Implementations should update their
+    /// fields atomically so concurrent snapshots never observe a half-reset
+    /// state. Defaults to a no-op.
+    fn reset(&self) {}
+
+    /// Returns a counter identifying the current version of the state.
+    ///
+    /// Implementations that track changes should increment it on every
+    /// mutation; [`Tui`](crate::Tui) then reuses its last snapshot while the
+    /// generation stays the same, which avoids rebuilding large snapshots on
+    /// frames where nothing changed. Defaults to `None`, meaning changes are
+    /// not tracked and a fresh snapshot is taken for every frame.
+    fn generation(&self) -> Option<u64> {
+        None
+    }
+
+    /// Returns the change tracker consulted before each render.
+    ///
+    /// When this returns a [`RenderState`], [`App::run`](crate::App::run)
+    /// only renders a frame if it was marked dirty, a handler returned
+    /// [`EventFlow::Redraw`](crate::EventFlow::Redraw), or the terminal was
+    /// resized. Defaults to `None`, meaning every loop iteration renders.
+    fn render_state(&self) -> Option<&RenderState> {
+        None
+    }
 }
 
 /// Trait for state snapshots that can be safely shared across threads.
 ///
 /// Snapshots must be efficiently cloneable and provide a consistent
 /// view of the application state at a point in time.
+///
+/// Only [`should_quit`](Self::should_quit) is required. The remaining
+/// methods describe common UI conditions with neutral defaults, so generic
+/// widgets can read them without each application wiring them up;
+/// override the ones the application tracks.
 pub trait StateSnapshot: Clone + Send + Debug + 'static {
     /// Returns whether the application should quit based on this snapshot.
     fn should_quit(&self) -> bool;
+
+    /// Returns whether work is in progress, for showing a spinner or busy
+    /// indicator. Defaults to `false`.
+    fn is_loading(&self) -> bool {
+        false
+    }
+
+    /// Returns whether the application is in an error state, for status
+    /// lines and error styling. Defaults to `false`.
+    fn has_error(&self) -> bool {
+        false
+    }
+
+    /// Returns whether the application has input focus, for dimming or
+    /// hiding the cursor while unfocused. Defaults to `true`.
+    fn is_focused(&self) -> bool {
+        true
+    }
+}
+
+/// The fast path for states that fit entirely in flags.
+///
+/// The snapshot is a single [`FlagsSnapshot`] word, so taking one never
+/// allocates and costs one atomic load. Applications whose state is
+/// nothing but flags can hand a [`StateFlags`] straight to
+/// [`App`](crate::App) instead of wrapping it in their own type.
+///
+/// # Examples
+///
+/// ```rust
+/// use oxitty::state::{AtomicState, StateFlags, StateSnapshot};
+///
+/// let state = StateFlags::with_flags(&[(StateFlags::RUNNING, true)]);
+/// state.set(StateFlags::PROCESSING, true);
+///
+/// let snapshot = AtomicState::snapshot(&state);
+/// assert!(snapshot.is_loading());
+/// assert!(!snapshot.should_quit());
+///
+/// state.quit();
+/// assert!(AtomicState::snapshot(&state).should_quit());
+/// ```
+impl AtomicState for StateFlags {
+    type Snapshot = FlagsSnapshot;
+
+    #[inline]
+    fn snapshot(&self) -> Self::Snapshot {
+        StateFlags::snapshot(self)
+    }
+
+    fn quit(&self) {
+        self.set(StateFlags::RUNNING, false);
+    }
+
+    #[inline]
+    fn is_running(&self) -> bool {
+        self.get(StateFlags::RUNNING)
+    }
+}
+
+/// Implements [`AtomicState`] for a struct whose lifecycle lives in a
+/// [`StateFlags`] field.
+///
+/// [`is_running`](AtomicState::is_running) reads the
+/// [`RUNNING`](StateFlags::RUNNING) flag of the named field and
+/// [`quit`](AtomicState::quit) clears it. The snapshot is built by the given
+/// block, with the state bound to the given name. Every other method keeps
+/// its default; implement the trait by hand to override them.
+///
+/// # Examples
+///
+/// ```rust
+/// use oxitty::{
+///     atomic_state,
+///     state::{AtomicState, StateFlags, StateSnapshot},
+/// };
+///
+/// #[derive(Debug)]
+/// struct AppState {
+///     flags: StateFlags,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// struct AppSnapshot {
+///     running: bool,
+/// }
+///
+/// impl StateSnapshot for AppSnapshot {
+///     fn should_quit(&self) -> bool {
+///         !self.running
+///     }
+/// }
+///
+/// atomic_state! {
+///     impl AtomicState for AppState {
+///         flags: flags,
+///         snapshot(state) -> AppSnapshot {
+///             AppSnapshot {
+///                 running: state.flags.get(StateFlags::RUNNING),
+///             }
+///         }
+///     }
+/// }
+///
+/// let app = AppState {
+///     flags: StateFlags::with_flags(&[(StateFlags::RUNNING, true)]),
+/// };
+/// assert!(app.is_running());
+///
+/// app.quit();
+/// assert!(app.snapshot().should_quit());
+/// ```
+#[macro_export]
+macro_rules! atomic_state {
+    (
+        impl AtomicState for $name:ty {
+            flags: $flags:ident,
+            snapshot($state:ident) -> $snapshot:ty $body:block $(,)?
+        }
+    ) => {
+        impl $crate::state::AtomicState for $name {
+            type Snapshot = $snapshot;
+
+            fn snapshot(&self) -> Self::Snapshot {
+                let $state = self;
+                $body
+            }
+
+            fn quit(&self) {
+                self.$flags.set($crate::state::StateFlags::RUNNING, false);
+            }
+
+            fn is_running(&self) -> bool {
+                self.$flags.get($crate::state::StateFlags::RUNNING)
+            }
+        }
+    };
 }
 
 #[cfg(test)]
@@ -433,6 +1199,170 @@ mod tests {
     use std::sync::Arc;
     use std::thread;
 
+    #[test]
+    fn test_flags_snapshot_round_trips_as_state() {
+        let state = StateFlags::with_flags(&[
+            (StateFlags::RUNNING, true),
+            (StateFlags::HAS_ERROR, true),
+            (40, true),
+        ]);
+
+        let mut snapshot = AtomicState::snapshot(&state);
+        assert_eq!(snapshot.raw(), state.load());
+        assert!(snapshot.get(40));
+        assert!(snapshot.has_error());
+        assert!(!snapshot.is_loading());
+        assert!(!snapshot.should_quit());
+        assert!(AtomicState::is_running(&state));
+
+        state.set(StateFlags::PROCESSING, true);
+        AtomicState::quit(&state);
+        state.snapshot_into(&mut snapshot);
+        assert_eq!(snapshot, StateFlags::snapshot(&state));
+        assert!(snapshot.is_loading());
+        assert!(snapshot.should_quit());
+        assert!(!AtomicState::is_running(&state));
+    }
+
+    #[test]
+    fn test_atomic_state_macro() {
+        #[derive(Debug)]
+        struct AppState {
+            flags: StateFlags,
+            count: AtomicU64,
+        }
+
+        #[derive(Debug, Clone)]
+        struct AppSnapshot {
+            running: bool,
+            count: u64,
+        }
+
+        impl StateSnapshot for AppSnapshot {
+            fn should_quit(&self) -> bool {
+                !self.running
+            }
+        }
+
+        atomic_state! {
+            impl AtomicState for AppState {
+                flags: flags,
+                snapshot(state) -> AppSnapshot {
+                    AppSnapshot {
+                        running: state.flags.get(StateFlags::RUNNING),
+                        count: state.count.load(Ordering::Acquire),
+                    }
+                }
+            }
+        }
+
+        let state = AppState {
+            flags: StateFlags::new(1 << StateFlags::RUNNING),
+            count: AtomicU64::new(3),
+        };
+        assert!(state.is_running());
+
+        let snapshot = state.snapshot();
+        assert!(!snapshot.should_quit());
+        assert_eq!(snapshot.count, 3);
+
+        state.quit();
+        assert!(!state.is_running());
+        assert!(state.snapshot().should_quit());
+        // Defaults are untouched
+        assert_eq!(state.exit_code(), 0);
+        assert_eq!(state.generation(), None);
+    }
+
+    #[test]
+    fn test_snapshot_into_matches_snapshot() {
+        #[derive(Debug)]
+        struct ListState {
+            items: Vec<AtomicU64>,
+        }
+
+        #[derive(Debug, Clone, PartialEq)]
+        struct ListSnapshot {
+            items: Vec<u64>,
+        }
+
+        impl StateSnapshot for ListSnapshot {
+            fn should_quit(&self) -> bool {
+                false
+            }
+        }
+
+        impl AtomicState for ListState {
+            type Snapshot = ListSnapshot;
+
+            fn snapshot(&self) -> Self::Snapshot {
+                let mut snapshot = ListSnapshot { items: Vec::new() };
+                self.snapshot_into(&mut snapshot);
+                snapshot
+            }
+
+            fn snapshot_into(&self, buf: &mut Self::Snapshot) {
+                buf.items.clear();
+                buf.items
+                    .extend(self.items.iter().map(|item| item.load(Ordering::Acquire)));
+            }
+
+            fn quit(&self) {}
+
+            fn is_running(&self) -> bool {
+                true
+            }
+        }
+
+        let state = ListState {
+            items: (0..64).map(AtomicU64::new).collect(),
+        };
+        let mut buf = state.snapshot();
+        let allocation = buf.items.as_ptr();
+
+        state.items[3].store(99, Ordering::Release);
+        state.snapshot_into(&mut buf);
+        assert_eq!(buf, state.snapshot());
+        assert_eq!(buf.items[3], 99);
+        assert_eq!(buf.items.as_ptr(), allocation);
+    }
+
+    #[test]
+    fn test_snapshot_condition_defaults() {
+        #[derive(Debug, Clone)]
+        struct Loading;
+
+        impl StateSnapshot for Loading {
+            fn should_quit(&self) -> bool {
+                false
+            }
+
+            fn is_loading(&self) -> bool {
+                true
+            }
+        }
+
+        assert!(Loading.is_loading());
+        assert!(!Loading.has_error());
+        assert!(Loading.is_focused());
+    }
+
+    #[test]
+    fn test_with_flags_builds_bitfield() {
+        let flags = StateFlags::with_flags(&[
+            (StateFlags::RUNNING, true),
+            (StateFlags::DEBUG, true),
+            (StateFlags::HAS_ERROR, false),
+        ]);
+        assert_eq!(flags.load(), 0b101);
+
+        // Later entries win
+        let flags = StateFlags::with_flags(&[(63, true), (StateFlags::DEBUG, true), (63, false)]);
+        assert_eq!(flags.load(), 1 << StateFlags::DEBUG);
+
+        assert_eq!(StateFlags::with_flags(&[]).load(), 0);
+    }
+
     #[test]
     fn test_atomic_operations() {
         let flags = StateFlags::default();
@@ -444,6 +1374,26 @@ mod tests {
         assert!(!flags.get(StateFlags::RUNNING));
     }
 
+    #[test]
+    fn test_set_reports_change() {
+        let flags = StateFlags::default();
+
+        // false -> true is a transition
+        assert!(flags.set(StateFlags::RUNNING, true));
+        // true -> true is not
+        assert!(!flags.set(StateFlags::RUNNING, true));
+        // true -> false is
+        assert!(flags.set(StateFlags::RUNNING, false));
+        assert!(!flags.set(StateFlags::RUNNING, false));
+
+        // Other flags are unaffected by toggling
+        assert!(flags.toggle(StateFlags::DEBUG));
+        assert!(flags.get(StateFlags::DEBUG));
+        assert!(!flags.toggle(StateFlags::DEBUG));
+        assert!(!flags.get(StateFlags::DEBUG));
+        assert!(!flags.get(StateFlags::RUNNING));
+    }
+
     #[test]
     fn test_snapshot_consistency() {
         let flags = Arc::new(StateFlags::default());
@@ -540,6 +1490,227 @@ mod tests {
         assert!(!flags.get(StateFlags::RUNNING));
     }
 
+    #[test]
+    fn test_mask_operations() {
+        let flags = StateFlags::new(0b1001);
+
+        assert_eq!(flags.set_mask(0b0110), 0b1001);
+        assert_eq!(flags.load(), 0b1111);
+        assert_eq!(flags.set_mask(1 << 63), 0b1111);
+        assert_eq!(flags.load(), (1 << 63) | 0b1111);
+
+        assert_eq!(flags.clear_mask(0b0101), (1 << 63) | 0b1111);
+        assert_eq!(flags.load(), (1 << 63) | 0b1010);
+        assert_eq!(flags.clear_mask(0), (1 << 63) | 0b1010);
+        assert_eq!(flags.load(), (1 << 63) | 0b1010);
+        flags.clear_mask(u64::MAX);
+        assert_eq!(flags.load(), 0);
+    }
+
+    #[test]
+    fn test_swap_returns_previous_value() {
+        const THREADS: u64 = 8;
+        const SWAPS: u64 = 1000;
+
+        let flags = Arc::new(StateFlags::new(0b101));
+        assert_eq!(flags.swap(0b010), 0b101);
+        assert_eq!(flags.load(), 0b010);
+        assert_eq!(flags.snapshot().raw(), flags.load());
+
+        // Every installed value is handed back by exactly one later swap,
+        // or is still installed at the end
+        flags.swap(0);
+        let handles: Vec<_> = (0..THREADS)
+            .map(|thread| {
+                let flags = flags.clone();
+                thread::spawn(move || {
+                    (0..SWAPS)
+                        .map(|i| flags.swap(1 + thread * SWAPS + i))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        let mut seen: Vec<u64> = handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect();
+        seen.push(flags.load());
+        seen.sort_unstable();
+        assert_eq!(seen, (0..=THREADS * SWAPS).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_transact_commits_only_on_some() {
+        let running = 1 << StateFlags::RUNNING;
+        let processing = 1 << StateFlags::PROCESSING;
+        let flags = StateFlags::new(1 << StateFlags::HAS_ERROR);
+        let before = flags.load();
+
+        assert!(!flags.transact(|bits| (bits & running != 0).then_some(bits | processing)));
+        assert_eq!(flags.load(), before);
+
+        assert!(flags
+            .transact(|bits| Some((bits | running | processing) & !(1 << StateFlags::HAS_ERROR))));
+        assert_eq!(flags.load(), running | processing);
+
+        // Concurrent increments through the bitfield never lose an update
+        let counter = Arc::new(StateFlags::default());
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let counter = counter.clone();
+                thread::spawn(move || {
+                    for _ in 0..1000 {
+                        assert!(counter.transact(|bits| Some(bits + 1)));
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(counter.load(), 8000);
+    }
+
+    #[test]
+    fn test_compare_and_set_single_winner() {
+        use std::sync::{atomic::AtomicUsize, Barrier};
+
+        const THREADS: usize = 16;
+
+        let flags = Arc::new(StateFlags::default());
+        let winners = Arc::new(AtomicUsize::new(0));
+        let barrier = Arc::new(Barrier::new(THREADS));
+
+        let handles: Vec<_> = (0..THREADS)
+            .map(|_| {
+                let flags = flags.clone();
+                let winners = winners.clone();
+                let barrier = barrier.clone();
+                thread::spawn(move || {
+                    barrier.wait();
+                    if flags.compare_and_set(StateFlags::RENDERING, false, true) {
+                        winners.fetch_add(1, Ordering::SeqCst);
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(winners.load(Ordering::SeqCst), 1);
+        assert!(flags.get(StateFlags::RENDERING));
+    }
+
+    #[test]
+    fn test_typed_flags() {
+        crate::typed_flags! {
+            enum TestFlag {
+                Running = StateFlags::RUNNING,
+                Custom = 10,
+            }
+        }
+
+        assert_eq!(TestFlag::Running.position(), StateFlags::RUNNING);
+        assert_eq!(TestFlag::Custom.position(), 10);
+
+        let flags = StateFlags::default();
+        assert!(flags.set_typed(TestFlag::Custom, true));
+        assert!(flags.get_typed(TestFlag::Custom));
+        assert!(!flags.get_typed(TestFlag::Running));
+        assert_eq!(flags.snapshot().raw(), 1 << 10);
+
+        let snapshot = flags.snapshot();
+        assert!(snapshot.get_typed(TestFlag::Custom));
+        assert_eq!(
+            snapshot.get_typed(TestFlag::Running),
+            snapshot.get(StateFlags::RUNNING)
+        );
+    }
+
+    #[test]
+    fn test_wait_for_resolves_on_quit() {
+        use std::sync::atomic::AtomicBool;
+        use std::time::Duration;
+
+        #[derive(Debug, Clone)]
+        struct Snapshot;
+
+        impl StateSnapshot for Snapshot {
+            fn should_quit(&self) -> bool {
+                false
+            }
+        }
+
+        #[derive(Debug)]
+        struct AppState {
+            flags: StateFlags,
+        }
+
+        impl AtomicState for AppState {
+            type Snapshot = Snapshot;
+
+            fn snapshot(&self) -> Self::Snapshot {
+                Snapshot
+            }
+
+            fn quit(&self) {
+                self.flags.set(StateFlags::RUNNING, false);
+            }
+
+            fn is_running(&self) -> bool {
+                self.flags.get(StateFlags::RUNNING)
+            }
+        }
+
+        let state = Arc::new(AppState {
+            flags: StateFlags::new(1 << StateFlags::RUNNING),
+        });
+        let resolved = Arc::new(AtomicBool::new(false));
+
+        let waiter = {
+            let state = state.clone();
+            let resolved = resolved.clone();
+            crate::runtime::spawn(async move {
+                state.flags.wait_for(StateFlags::RUNNING, false).await;
+                resolved.store(true, Ordering::SeqCst);
+            })
+        };
+
+        // Updates to other flags wake the waiter without resolving it
+        thread::sleep(Duration::from_millis(20));
+        state.flags.set(StateFlags::DEBUG, true);
+        thread::sleep(Duration::from_millis(20));
+        assert!(!resolved.load(Ordering::SeqCst));
+
+        state.quit();
+        crate::runtime::block_on(waiter);
+        assert!(resolved.load(Ordering::SeqCst));
+
+        // Already satisfied: resolves immediately
+        crate::runtime::block_on(state.flags.wait_for(StateFlags::RUNNING, false));
+    }
+
+    #[test]
+    fn test_render_state_dirty_flag() {
+        let render = RenderState::default();
+
+        // Starts dirty so the first frame renders
+        assert!(render.is_dirty());
+        assert!(render.take_dirty());
+        assert!(!render.take_dirty());
+
+        // Marks coalesce until taken
+        render.mark_dirty();
+        render.mark_dirty();
+        assert!(render.take_dirty());
+        assert!(!render.take_dirty());
+        assert!(!render.is_dirty());
+        assert_eq!(render.generation(), 2);
+    }
+
     #[test]
     fn test_snapshot_immutability() {
         let flags = StateFlags::default();
@@ -8,7 +8,9 @@
 //! - Atomic state management with thread-safe snapshots
 //! - Theme-based styling system with consistent color schemes
 //! - Raw mode and alternate screen management
+//! - Inline viewport mode that preserves the shell scrollback
 //! - Mouse capture support
+//! - Drawing to any `Write` target, such as stderr, instead of stdout
 //! - Non-blocking rendering system
 //! - Error handling with detailed context
 //!
@@ -130,28 +132,211 @@
 //! }
 //! ```
 
-use std::io::{self, Stdout};
+use std::{
+    cell::Cell,
+    io::{self, Stdout},
+    time::Duration,
+};
 
 use crossterm::{
+    cursor,
     event::{DisableMouseCapture, EnableMouseCapture},
-    execute,
+    execute, queue,
+    style::{Attribute, Print, SetAttribute, SetBackgroundColor, SetForegroundColor},
     terminal::{self, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{
-    backend::CrosstermBackend,
-    layout::{Rect, Size},
+    backend::{Backend, CrosstermBackend, TestBackend},
+    buffer::Buffer,
+    layout::{Position, Rect, Size},
     prelude::Line,
-    style::Style,
-    widgets::Block,
-    Terminal,
+    style::{Modifier, Style},
+    widgets::{Block, Borders, Paragraph},
+    Terminal, TerminalOptions, Viewport,
 };
+use unicode_width::UnicodeWidthStr;
 
 use crate::{
-    colors::theme,
+    colors::{color_support, theme, with_color_support, Color, ColorSupport},
     error::{OxittyError, OxittyResult},
     state::AtomicState,
 };
 
+/// Severity of a status message, selecting the matching theme status color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// Informational message, rendered with [`Tui::info`]
+    Info,
+    /// Successful outcome, rendered with [`Tui::success`]
+    Success,
+    /// Recoverable problem, rendered with [`Tui::warning`]
+    Warning,
+    /// Failure, rendered with [`Tui::error`]
+    Error,
+}
+
+/// Semantic roles of the theme, each mapping to a [`Tui`] style.
+///
+/// See [`Tui::style_for`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Semantic {
+    /// Primary text
+    Primary,
+    /// Secondary, less prominent text
+    Secondary,
+    /// Error messages
+    Error,
+    /// Warning messages
+    Warning,
+    /// Informational messages
+    Info,
+    /// Success messages
+    Success,
+    /// Borders and dividers
+    Border,
+    /// Focused elements
+    Focus,
+    /// Void accent elements
+    Void,
+}
+
+impl Semantic {
+    /// Every semantic role, in declaration order.
+    pub const ALL: [Semantic; 9] = [
+        Semantic::Primary,
+        Semantic::Secondary,
+        Semantic::Error,
+        Semantic::Warning,
+        Semantic::Info,
+        Semantic::Success,
+        Semantic::Border,
+        Semantic::Focus,
+        Semantic::Void,
+    ];
+}
+
+impl From<Severity> for Semantic {
+    fn from(severity: Severity) -> Self {
+        match severity {
+            Severity::Info => Semantic::Info,
+            Severity::Success => Semantic::Success,
+            Severity::Warning => Semantic::Warning,
+            Severity::Error => Semantic::Error,
+        }
+    }
+}
+
+/// Semantic colors overridden while part of the UI renders.
+///
+/// Built with [`with`](Self::with) and activated with
+/// [`apply`](Self::apply), a context makes [`Tui::style_for`] and every
+/// style helper built on it, such as [`Tui::error`] or the themed blocks,
+/// use the given colors instead of the theme's. Contexts nest: an inner
+/// context overrides on top of the outer one, and roles it leaves alone
+/// keep the outer color. The active context is tracked per thread, so
+/// rendering on other threads is unaffected.
+///
+/// # Examples
+///
+/// ```rust
+/// use oxitty::{colors::Color, tui::{Semantic, ThemeContext}};
+///
+/// // An "error mode" panel tints focus and borders red
+/// let alarm = ThemeContext::new()
+///     .with(Semantic::Focus, Color::rgb(255, 60, 60))
+///     .with(Semantic::Border, Color::rgb(120, 30, 30));
+///
+/// // Tui::style_for(Semantic::Focus) picks this color up inside `apply`
+/// let inside = alarm.apply(|| ThemeContext::current().color(Semantic::Focus));
+/// assert_eq!(inside, Some(Color::rgb(255, 60, 60)));
+/// assert_eq!(ThemeContext::current().color(Semantic::Focus), None);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ThemeContext {
+    /// Override per role, indexed by declaration order
+    colors: [Option<Color>; Semantic::ALL.len()],
+}
+
+thread_local! {
+    /// Merged overrides of the contexts applied on this thread
+    static THEME_CONTEXT: Cell<ThemeContext> = const { Cell::new(ThemeContext::new()) };
+}
+
+impl ThemeContext {
+    /// Creates a context that overrides nothing.
+    pub const fn new() -> Self {
+        Self {
+            colors: [None; Semantic::ALL.len()],
+        }
+    }
+
+    /// Overrides the color of a semantic role.
+    ///
+    /// # Arguments
+    ///
+    /// * `semantic` - Role to recolor
+    /// * `color` - Color used for the role while the context is applied
+    pub fn with(mut self, semantic: Semantic, color: Color) -> Self {
+        self.colors[semantic as usize] = Some(color);
+        self
+    }
+
+    /// Returns the override for a role, if the context has one.
+    pub fn color(&self, semantic: Semantic) -> Option<Color> {
+        self.colors[semantic as usize]
+    }
+
+    /// Returns the overrides active on this thread.
+    ///
+    /// Empty outside of any [`apply`](Self::apply) call.
+    pub fn current() -> Self {
+        THEME_CONTEXT.get()
+    }
+
+    /// Runs `f` with this context's overrides active on this thread.
+    ///
+    /// The overrides are layered on top of any enclosing context, and the
+    /// previous context is restored afterwards, even if `f` panics.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Closure to run with the overrides in place, typically
+    ///   rendering one region of the frame
+    pub fn apply<T>(&self, f: impl FnOnce() -> T) -> T {
+        /// Restores the previous context when dropped
+        struct Restore(ThemeContext);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                THEME_CONTEXT.set(self.0);
+            }
+        }
+
+        let previous = THEME_CONTEXT.get();
+        let mut merged = previous;
+        for (slot, color) in merged.colors.iter_mut().zip(self.colors) {
+            if color.is_some() {
+                *slot = color;
+            }
+        }
+
+        let _restore = Restore(previous);
+        THEME_CONTEXT.set(merged);
+        f()
+    }
+}
+
+/// How a [`Tui`] has configured the real terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TerminalMode {
+    /// The real terminal is untouched, e.g. when drawing to a `TestBackend`
+    Unmanaged,
+    /// Raw mode, alternate screen and mouse capture are enabled
+    Fullscreen,
+    /// Raw mode only, drawing to a fixed number of lines below the cursor
+    Inline,
+}
+
 /// Terminal user interface manager that coordinates rendering and state management.
 ///
 /// Manages terminal setup, rendering, cleanup, and maintains thread-safe state access.
@@ -160,13 +345,62 @@ use crate::{
 /// # Type Parameters
 ///
 /// * `S` - The atomic state type that must implement `AtomicState`
-pub struct Tui<S: AtomicState> {
+/// * `B` - The ratatui backend to draw to, defaulting to crossterm on stdout
+pub struct Tui<S: AtomicState, B: Backend = CrosstermBackend<Stdout>> {
     /// Terminal instance for rendering operations
-    terminal: Terminal<CrosstermBackend<Stdout>>,
+    terminal: Terminal<B>,
     /// Thread-safe application state
     state: S,
+    /// How the real terminal was configured and must be restored on drop
+    mode: TerminalMode,
+    /// Whether mouse capture is currently enabled
+    mouse_capture: bool,
+    /// Whether this instance currently has the real terminal in raw mode
+    raw_mode: bool,
+    /// Turns raw mode of the real terminal on or off
+    raw_mode_switch: RawModeSwitch,
+    /// Contents of the most recently drawn frame, kept for partial redraws
+    last_frame: Option<Buffer>,
+    /// Last snapshot taken, with the state generation it was taken at
+    snapshot_cache: Option<SnapshotCache<S>>,
+    /// Snapshot drawn by the last [`render_with_previous`](Tui::render_with_previous)
+    previous_snapshot: Option<S::Snapshot>,
+    /// Whether the next render must rewrite every cell
+    full_redraw: bool,
+    /// Whether [`bell`](Tui::bell) flashes the screen instead of beeping
+    visual_bell: bool,
+    /// Whether the next rendered frame is shown inverted
+    flash_pending: bool,
+    /// Cursor position before an inline viewport was set up, whose column is restored on cleanup
+    saved_cursor: Option<Position>,
+    /// Whether theme colors are rendered as grays
+    monochrome: bool,
+    /// Whether the final frame stays visible once the terminal is restored
+    leave_on_exit: bool,
+    /// Whether the screen is cleared before the first frame is drawn
+    clear_on_start: bool,
+}
+
+/// Cached snapshot paired with the state generation it reflects.
+type SnapshotCache<S> = (Option<u64>, <S as AtomicState>::Snapshot);
+
+/// Turns raw mode of the real terminal on (`true`) or off (`false`).
+///
+/// Kept on [`Tui`] so tests can observe the transitions without a TTY.
+type RawModeSwitch = fn(bool) -> io::Result<()>;
+
+/// Switches raw mode of the real terminal through crossterm.
+fn set_raw_mode(enabled: bool) -> io::Result<()> {
+    if enabled {
+        terminal::enable_raw_mode()
+    } else {
+        terminal::disable_raw_mode()
+    }
 }
 
+/// Placeholder symbol that never matches a drawn cell, a Unicode noncharacter.
+const INVALID_SYMBOL: &str = "\u{FFFF}";
+
 impl<S: AtomicState> Tui<S> {
     /// Creates a new TUI instance with the provided atomic state.
     ///
@@ -198,7 +432,72 @@ impl<S: AtomicState> Tui<S> {
         }
 
         let terminal = Self::setup_terminal()?;
-        Ok(Self { terminal, state })
+        Ok(Self::from_parts(
+            terminal,
+            state,
+            TerminalMode::Fullscreen,
+            true,
+            true,
+            None,
+        ))
+    }
+
+    /// Creates a new TUI instance that draws inline below the shell prompt.
+    ///
+    /// Instead of switching to the alternate screen, the UI occupies the
+    /// bottom `height` lines of the terminal and the rest of the scrollback
+    /// stays visible. Use [`Tui::insert_before`] to print log lines above the
+    /// viewport. Only raw mode is enabled, mouse capture is left off, and
+    /// cleanup leaves the scrollback intact. The cursor position from before
+    /// setup is recorded, and cleanup leaves the cursor in its column on the
+    /// line below the viewport, see [`saved_cursor`](Self::saved_cursor).
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The initial atomic state
+    /// * `height` - Number of terminal lines reserved for the UI
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - Not running in a real terminal
+    /// - Raw mode cannot be enabled
+    /// - The inline viewport cannot be created
+    pub fn new_inline(state: S, height: u16) -> OxittyResult<Self> {
+        if !Self::is_real_terminal() {
+            return Err(OxittyError::terminal(
+                "terminal check",
+                (0, 0),
+                "Not a real terminal or terminal capabilities not available".to_string(),
+            )
+            .into());
+        }
+
+        Self::enter_terminal_modes(TerminalMode::Inline, false, set_raw_mode)?;
+        let saved_cursor = cursor::position().ok().map(Position::from);
+        let terminal = Terminal::with_options(
+            CrosstermBackend::new(io::stdout()),
+            TerminalOptions {
+                viewport: Viewport::Inline(height),
+            },
+        )
+        .map_err(|e| {
+            let _ = set_raw_mode(false);
+            OxittyError::terminal(
+                "terminal setup",
+                (0, 0),
+                format!("Failed to create inline terminal: {}", e),
+            )
+        })?;
+
+        Ok(Self::from_parts(
+            terminal,
+            state,
+            TerminalMode::Inline,
+            false,
+            true,
+            saved_cursor,
+        ))
     }
 
     /// Checks if running in a real terminal environment.
@@ -225,62 +524,420 @@ impl<S: AtomicState> Tui<S> {
     /// - Alternate screen
     /// - Mouse capture
     fn setup_terminal() -> OxittyResult<Terminal<CrosstermBackend<Stdout>>> {
-        let mut stdout = io::stdout();
+        Self::enter_terminal_modes(TerminalMode::Fullscreen, true, set_raw_mode)?;
 
-        execute!(stdout, EnterAlternateScreen, EnableMouseCapture).map_err(|e| {
+        Terminal::new(CrosstermBackend::new(io::stdout())).map_err(|e| {
             OxittyError::terminal(
                 "terminal setup",
                 (0, 0),
-                format!("Failed to setup terminal: {}", e),
+                format!("Failed to create terminal: {}", e),
             )
-        })?;
+            .into()
+        })
+    }
+}
+
+impl<S: AtomicState> Tui<S, TestBackend> {
+    /// Creates a TUI instance drawing to an in-memory buffer of the given size.
+    ///
+    /// A shorthand for [`Tui::with_backend`] over ratatui's `TestBackend`,
+    /// meant for tests and non-interactive snapshot generation. No TTY is
+    /// needed and the real terminal is never touched. The rendered cells
+    /// are available through `tui.terminal().backend().buffer()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The initial atomic state
+    /// * `size` - Dimensions of the in-memory terminal
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::sync::atomic::{AtomicBool, Ordering};
+    /// # use oxitty::{Tui, AtomicState, StateSnapshot};
+    /// # #[derive(Debug)]
+    /// # struct AppState {
+    /// #     running: AtomicBool,
+    /// # }
+    /// # #[derive(Debug, Clone)]
+    /// # struct AppSnapshot {
+    /// #     running: bool,
+    /// # }
+    /// # impl StateSnapshot for AppSnapshot {
+    /// #     fn should_quit(&self) -> bool { !self.running }
+    /// # }
+    /// # impl AtomicState for AppState {
+    /// #     type Snapshot = AppSnapshot;
+    /// #     fn snapshot(&self) -> Self::Snapshot {
+    /// #         AppSnapshot {
+    /// #             running: self.running.load(Ordering::Acquire),
+    /// #         }
+    /// #     }
+    /// #     fn quit(&self) { self.running.store(false, Ordering::Release); }
+    /// #     fn is_running(&self) -> bool { self.running.load(Ordering::Acquire) }
+    /// # }
+    /// use oxitty::tui::buffer_to_string;
+    /// use ratatui::{layout::Size, widgets::Paragraph};
+    ///
+    /// let state = AppState {
+    ///     running: AtomicBool::new(true),
+    /// };
+    ///
+    /// let mut tui = Tui::headless(state, Size::new(6, 1));
+    /// tui.render(|_, area, frame| frame.render_widget(Paragraph::new("ready"), area))
+    ///     .unwrap();
+    /// assert_eq!(buffer_to_string(tui.terminal().backend().buffer()), "ready ");
+    /// ```
+    pub fn headless(state: S, size: Size) -> Self {
+        Self::with_backend(state, TestBackend::new(size.width, size.height))
+            .expect("TestBackend cannot fail")
+    }
+}
+
+impl<S: AtomicState, W: io::Write> Tui<S, CrosstermBackend<W>> {
+    /// Creates a TUI instance writing its frames to any `Write` target.
+    ///
+    /// [`Tui::new`] always draws to stdout. This draws to `writer` instead,
+    /// for example `io::stderr()` to keep stdout free for piped,
+    /// machine-readable output. Like [`Tui::with_backend`], the real
+    /// terminal is left untouched, so raw mode and the alternate screen are
+    /// up to the caller. The size is queried from the controlling terminal;
+    /// use [`Tui::with_writer_sized`] for targets without one.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The initial atomic state
+    /// * `writer` - Where escape sequences and frame contents are written
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the terminal size cannot be queried.
+    pub fn with_writer(state: S, writer: W) -> OxittyResult<Self> {
+        Self::with_backend(state, CrosstermBackend::new(writer))
+    }
 
-        terminal::enable_raw_mode().map_err(|e| {
+    /// Creates a TUI instance writing frames of a fixed size to `writer`.
+    ///
+    /// The counterpart of [`Tui::with_writer`] for files, pipes and
+    /// in-memory buffers, which have no terminal size to query. Frames
+    /// always cover `size`, anchored at the origin.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The initial atomic state
+    /// * `writer` - Where escape sequences and frame contents are written
+    /// * `size` - Dimensions of the drawn frames
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the backend cannot be set up.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::sync::atomic::{AtomicBool, Ordering};
+    /// # use oxitty::{Tui, AtomicState, StateSnapshot};
+    /// # #[derive(Debug)]
+    /// # struct AppState {
+    /// #     running: AtomicBool,
+    /// # }
+    /// # #[derive(Debug, Clone)]
+    /// # struct AppSnapshot {
+    /// #     running: bool,
+    /// # }
+    /// # impl StateSnapshot for AppSnapshot {
+    /// #     fn should_quit(&self) -> bool { !self.running }
+    /// # }
+    /// # impl AtomicState for AppState {
+    /// #     type Snapshot = AppSnapshot;
+    /// #     fn snapshot(&self) -> Self::Snapshot {
+    /// #         AppSnapshot {
+    /// #             running: self.running.load(Ordering::Acquire),
+    /// #         }
+    /// #     }
+    /// #     fn quit(&self) { self.running.store(false, Ordering::Release); }
+    /// #     fn is_running(&self) -> bool { self.running.load(Ordering::Acquire) }
+    /// # }
+    /// use ratatui::{layout::Size, widgets::Paragraph};
+    ///
+    /// let state = AppState {
+    ///     running: AtomicBool::new(true),
+    /// };
+    ///
+    /// let mut output = Vec::new();
+    /// let mut tui = Tui::with_writer_sized(state, &mut output, Size::new(5, 1)).unwrap();
+    /// tui.render(|_, area, frame| frame.render_widget(Paragraph::new("hello"), area))
+    ///     .unwrap();
+    /// drop(tui);
+    ///
+    /// assert!(String::from_utf8_lossy(&output).contains("hello"));
+    /// ```
+    pub fn with_writer_sized(state: S, writer: W, size: Size) -> OxittyResult<Self> {
+        let terminal = Terminal::with_options(
+            CrosstermBackend::new(writer),
+            TerminalOptions {
+                viewport: Viewport::Fixed(Rect::from((Position::ORIGIN, size))),
+            },
+        )
+        .map_err(|e| {
             OxittyError::terminal(
                 "terminal setup",
                 (0, 0),
-                format!("Failed to enable raw mode: {}", e),
+                format!("Failed to create terminal: {}", e),
             )
         })?;
 
-        Terminal::new(CrosstermBackend::new(stdout)).map_err(|e| {
+        Ok(Self::from_parts(
+            terminal,
+            state,
+            TerminalMode::Unmanaged,
+            false,
+            false,
+            None,
+        ))
+    }
+}
+
+impl<S: AtomicState, B: Backend> Tui<S, B> {
+    /// Creates a new TUI instance drawing to the provided backend.
+    ///
+    /// Unlike [`Tui::new`], the real terminal is left untouched: raw mode,
+    /// the alternate screen and mouse capture are neither enabled here nor
+    /// restored on drop. This is primarily useful with ratatui's `TestBackend`.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The initial atomic state
+    /// * `backend` - The backend to render to
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the backend cannot report its size.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::sync::atomic::{AtomicBool, Ordering};
+    /// # use oxitty::{Tui, AtomicState, StateSnapshot};
+    /// # #[derive(Debug)]
+    /// # struct AppState {
+    /// #     running: AtomicBool,
+    /// # }
+    /// # #[derive(Debug, Clone)]
+    /// # struct AppSnapshot {
+    /// #     running: bool,
+    /// # }
+    /// # impl StateSnapshot for AppSnapshot {
+    /// #     fn should_quit(&self) -> bool { !self.running }
+    /// # }
+    /// # impl AtomicState for AppState {
+    /// #     type Snapshot = AppSnapshot;
+    /// #     fn snapshot(&self) -> Self::Snapshot {
+    /// #         AppSnapshot {
+    /// #             running: self.running.load(Ordering::Acquire),
+    /// #         }
+    /// #     }
+    /// #     fn quit(&self) { self.running.store(false, Ordering::Release); }
+    /// #     fn is_running(&self) -> bool { self.running.load(Ordering::Acquire) }
+    /// # }
+    /// use ratatui::backend::TestBackend;
+    ///
+    /// let state = AppState {
+    ///     running: AtomicBool::new(true),
+    /// };
+    ///
+    /// let tui = Tui::with_backend(state, TestBackend::new(80, 24)).unwrap();
+    /// assert_eq!(tui.size().unwrap().width, 80);
+    /// ```
+    pub fn with_backend(state: S, backend: B) -> OxittyResult<Self> {
+        let terminal = Terminal::new(backend).map_err(|e| {
             OxittyError::terminal(
                 "terminal setup",
                 (0, 0),
                 format!("Failed to create terminal: {}", e),
             )
-            .into()
-        })
+        })?;
+
+        Ok(Self::from_parts(
+            terminal,
+            state,
+            TerminalMode::Unmanaged,
+            false,
+            false,
+            None,
+        ))
     }
 
-    /// Restores terminal to original state.
+    /// Creates an inline TUI instance drawing to the provided backend.
     ///
-    /// Disables:
-    /// - Raw mode
-    /// - Alternate screen
-    /// - Mouse capture
-    fn restore_terminal(&mut self) -> OxittyResult<()> {
-        terminal::disable_raw_mode().map_err(|e| {
+    /// The inline counterpart of [`Tui::with_backend`]: rendering is limited
+    /// to the bottom `height` lines of the backend, as with [`Tui::new_inline`],
+    /// and the real terminal is left untouched. The backend's cursor position
+    /// is recorded, and [`restore`](Self::restore) and
+    /// [`shutdown`](Self::shutdown) return the cursor to its column on the
+    /// line below the viewport.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The initial atomic state
+    /// * `backend` - The backend to render to
+    /// * `height` - Number of lines reserved for the UI
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the backend cannot report its size or cursor position.
+    pub fn with_backend_inline(state: S, mut backend: B, height: u16) -> OxittyResult<Self> {
+        let saved_cursor = backend.get_cursor_position().ok();
+        let terminal = Terminal::with_options(
+            backend,
+            TerminalOptions {
+                viewport: Viewport::Inline(height),
+            },
+        )
+        .map_err(|e| {
             OxittyError::terminal(
-                "terminal cleanup",
+                "terminal setup",
                 (0, 0),
-                format!("Failed to disable raw mode: {}", e),
+                format!("Failed to create inline terminal: {}", e),
             )
         })?;
 
-        execute!(
-            self.terminal.backend_mut(),
-            LeaveAlternateScreen,
-            DisableMouseCapture
-        )
-        .map_err(|e| {
+        Ok(Self::from_parts(
+            terminal,
+            state,
+            TerminalMode::Unmanaged,
+            false,
+            false,
+            saved_cursor,
+        ))
+    }
+
+    /// Assembles a `Tui` around a prepared terminal, with every other
+    /// setting at its default.
+    ///
+    /// # Arguments
+    ///
+    /// * `terminal` - Terminal to render with
+    /// * `state` - The initial atomic state
+    /// * `mode` - How the real terminal was configured
+    /// * `mouse_capture` - Whether mouse capture was enabled
+    /// * `raw_mode` - Whether the real terminal was put in raw mode
+    /// * `saved_cursor` - Cursor position recorded before an inline viewport was set up
+    fn from_parts(
+        terminal: Terminal<B>,
+        state: S,
+        mode: TerminalMode,
+        mouse_capture: bool,
+        raw_mode: bool,
+        saved_cursor: Option<Position>,
+    ) -> Self {
+        Self {
+            terminal,
+            state,
+            mode,
+            mouse_capture,
+            raw_mode,
+            raw_mode_switch: set_raw_mode,
+            last_frame: None,
+            snapshot_cache: None,
+            previous_snapshot: None,
+            full_redraw: false,
+            visual_bell: false,
+            flash_pending: false,
+            saved_cursor,
+            monochrome: false,
+            leave_on_exit: false,
+            clear_on_start: false,
+        }
+    }
+
+    /// Switches the real terminal into TUI mode.
+    ///
+    /// Enables:
+    /// - Alternate screen (fullscreen only)
+    /// - Mouse capture (if requested)
+    /// - Raw mode, through `switch_raw_mode`
+    fn enter_terminal_modes(
+        mode: TerminalMode,
+        mouse_capture: bool,
+        switch_raw_mode: RawModeSwitch,
+    ) -> OxittyResult<()> {
+        if mode == TerminalMode::Fullscreen {
+            execute!(io::stdout(), EnterAlternateScreen).map_err(|e| {
+                OxittyError::terminal(
+                    "terminal setup",
+                    (0, 0),
+                    format!("Failed to setup terminal: {}", e),
+                )
+            })?;
+        }
+
+        if mouse_capture {
+            execute!(io::stdout(), EnableMouseCapture).map_err(|e| {
+                OxittyError::terminal(
+                    "terminal setup",
+                    (0, 0),
+                    format!("Failed to enable mouse capture: {}", e),
+                )
+            })?;
+        }
+
+        Ok(switch_raw_mode(true).map_err(|e| {
             OxittyError::terminal(
-                "terminal cleanup",
+                "terminal setup",
                 (0, 0),
-                format!("Failed to restore terminal: {}", e),
+                format!("Failed to enable raw mode: {}", e),
             )
-        })?;
+        })?)
+    }
+
+    /// Restores terminal to original state.
+    ///
+    /// Disables:
+    /// - Raw mode (if currently enabled)
+    /// - Alternate screen (fullscreen only)
+    /// - Mouse capture (if currently enabled)
+    ///
+    /// The scrollback is never cleared, so inline output stays visible.
+    /// When `exiting` with [`set_leave_on_exit`](Self::set_leave_on_exit)
+    /// enabled, the final frame is kept on the main screen as well.
+    fn restore_terminal(&mut self, exiting: bool) -> OxittyResult<()> {
+        if self.raw_mode {
+            (self.raw_mode_switch)(false).map_err(|e| {
+                OxittyError::terminal(
+                    "terminal cleanup",
+                    (0, 0),
+                    format!("Failed to disable raw mode: {}", e),
+                )
+            })?;
+            self.raw_mode = false;
+        }
+
+        if self.mouse_capture {
+            execute!(io::stdout(), DisableMouseCapture).map_err(|e| {
+                OxittyError::terminal(
+                    "terminal cleanup",
+                    (0, 0),
+                    format!("Failed to disable mouse capture: {}", e),
+                )
+            })?;
+        }
+
+        if self.mode == TerminalMode::Fullscreen {
+            execute!(io::stdout(), LeaveAlternateScreen).map_err(|e| {
+                OxittyError::terminal(
+                    "terminal cleanup",
+                    (0, 0),
+                    format!("Failed to restore terminal: {}", e),
+                )
+            })?;
+
+            if exiting && self.leave_on_exit {
+                self.print_final_frame()?;
+            }
+        }
 
+        self.place_cursor(exiting)?;
         Ok(self.terminal.show_cursor().map_err(|e| {
             OxittyError::terminal(
                 "terminal cleanup",
@@ -293,6 +950,13 @@ impl<S: AtomicState> Tui<S> {
     /// Renders a frame using the provided render function.
     ///
     /// Takes an atomic snapshot of the current state for consistent rendering.
+    /// When the state reports a [`generation`](AtomicState::generation), the
+    /// previous snapshot is reused until the generation changes.
+    ///
+    /// `render_fn` is only called with a non-empty area. While the terminal
+    /// has zero columns or rows, which can happen transiently during a
+    /// resize, the frame is skipped and `Ok(())` returned, so render code
+    /// never has to guard against degenerate sizes.
     ///
     /// # Arguments
     ///
@@ -305,262 +969,2686 @@ impl<S: AtomicState> Tui<S> {
     where
         F: FnOnce(&S::Snapshot, Rect, &mut ratatui::Frame<'_>),
     {
-        let snapshot = self.state.snapshot();
-
-        Ok(self
-            .terminal
-            .draw(|frame| {
-                let area = frame.area();
-                render_fn(&snapshot, area, frame);
-            })
-            .map(|_| ())
-            .map_err(|e| {
-                OxittyError::terminal(
-                    "rendering",
-                    (0, 0),
-                    format!("Failed to render frame: {}", e),
-                )
-            })?)
+        self.try_render(|snapshot, area, frame| {
+            render_fn(snapshot, area, frame);
+            Ok(())
+        })
     }
 
-    /// Returns reference to underlying terminal instance.
-    pub fn terminal(&self) -> &Terminal<CrosstermBackend<Stdout>> {
-        &self.terminal
-    }
+    /// Renders a frame using a render function that can fail.
+    ///
+    /// Behaves like [`render`](Self::render), but an error returned by
+    /// `render_fn` aborts the frame: nothing is flushed, the previous frame
+    /// stays on screen, and the error is returned unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `render_fn` - Function to handle frame rendering with current state
+    ///
+    /// # Errors
+    ///
+    /// Returns the error produced by `render_fn`, or a terminal error if the
+    /// frame cannot be drawn. If writing fails because the terminal is gone,
+    /// such as after a dropped SSH session, the error is an
+    /// [`OxittyError::Disconnected`] instead.
+    pub fn try_render<F>(&mut self, render_fn: F) -> OxittyResult<()>
+    where
+        F: FnOnce(&S::Snapshot, Rect, &mut ratatui::Frame<'_>) -> OxittyResult<()>,
+    {
+        self.clear_before_first_frame()?;
+        self.apply_full_redraw();
+        let snapshot = Self::cached_snapshot(&self.state, &mut self.snapshot_cache);
+        let mut render_error = None;
+        let mut flash = self.flash_pending;
+        let monochrome = self.monochrome;
 
-    /// Returns reference to current application state.
-    pub fn state(&self) -> &S {
-        &self.state
+        let drawn = self.terminal.try_draw(|frame| {
+            let area = frame.area();
+            if area.is_empty() {
+                flash = false;
+                return Ok(());
+            }
+            in_theme_mode(monochrome, || render_fn(snapshot, area, frame)).map_err(|e| {
+                render_error = Some(e);
+                io::Error::other("render function failed")
+            })?;
+            if flash {
+                toggle_reversed(frame.buffer_mut());
+            }
+            Ok::<_, io::Error>(())
+        });
+
+        if let Some(error) = render_error {
+            // Drop the partially drawn frame so it cannot leak into the next one
+            self.terminal.current_buffer_mut().reset();
+            return Err(error);
+        }
+
+        let completed = drawn.map_err(|e| draw_error(e, "Failed to render frame"))?;
+
+        // Keep the frame as drawn by the render function, without the flash
+        let mut frame = completed.buffer.clone();
+        if flash {
+            toggle_reversed(&mut frame);
+            self.flash_pending = false;
+        }
+        self.last_frame = Some(frame);
+        Ok(())
     }
 
-    /// Returns current terminal dimensions.
-    pub fn size(&self) -> OxittyResult<Size> {
-        Ok(self.terminal.size().map_err(|e| {
-            OxittyError::terminal(
-                "terminal size",
+    /// Renders a frame with the snapshot drawn by the previous call as well.
+    ///
+    /// Behaves like [`render`](Self::render), but `render_fn` also receives
+    /// the snapshot passed to it on the last successful call, or `None` on
+    /// the first one, which lets crossfades and slides animate between two
+    /// states. The snapshot is cloned after each frame to be handed over
+    /// next time; frames drawn with other render methods do not replace it.
+    /// If drawing fails, the earlier snapshot is kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `render_fn` - Function receiving the current and previous snapshots, area and frame
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the frame cannot be drawn.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    /// use oxitty::{AtomicState, StateSnapshot, Tui};
+    /// use ratatui::layout::Size;
+    ///
+    /// #[derive(Debug, Clone)]
+    /// struct Snapshot {
+    ///     running: bool,
+    /// }
+    ///
+    /// impl StateSnapshot for Snapshot {
+    ///     fn should_quit(&self) -> bool {
+    ///         !self.running
+    ///     }
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// struct State {
+    ///     running: AtomicBool,
+    /// }
+    ///
+    /// impl AtomicState for State {
+    ///     type Snapshot = Snapshot;
+    ///
+    ///     fn snapshot(&self) -> Snapshot {
+    ///         Snapshot { running: self.running.load(Ordering::Acquire) }
+    ///     }
+    ///
+    ///     fn quit(&self) {
+    ///         self.running.store(false, Ordering::Release);
+    ///     }
+    ///
+    ///     fn is_running(&self) -> bool {
+    ///         self.running.load(Ordering::Acquire)
+    ///     }
+    /// }
+    ///
+    /// let state = State { running: AtomicBool::new(true) };
+    /// let mut tui = Tui::headless(state, Size::new(10, 1));
+    ///
+    /// tui.render_with_previous(|_, previous, _, _| assert!(previous.is_none()))?;
+    /// tui.state().quit();
+    /// tui.render_with_previous(|current, previous, _, _| {
+    ///     assert!(!current.running);
+    ///     assert!(previous.unwrap().running);
+    /// })?;
+    /// # Ok::<(), miette::Report>(())
+    /// ```
+    pub fn render_with_previous<F>(&mut self, render_fn: F) -> OxittyResult<()>
+    where
+        F: FnOnce(&S::Snapshot, Option<&S::Snapshot>, Rect, &mut ratatui::Frame<'_>),
+    {
+        let previous = self.previous_snapshot.take();
+        let mut current = None;
+        let rendered = self.render(|snapshot, area, frame| {
+            render_fn(snapshot, previous.as_ref(), area, frame);
+            current = Some(snapshot.clone());
+        });
+
+        self.previous_snapshot = match rendered {
+            Ok(()) => current.or(previous),
+            Err(_) => previous,
+        };
+        rendered
+    }
+
+    /// Renders a frame with access to mutable widget state.
+    ///
+    /// The snapshot is the application's state and stays read-only. Widget
+    /// UI state, such as the selection and scroll offset of a ratatui
+    /// [`ListState`](ratatui::widgets::ListState), belongs to the view
+    /// instead: the caller owns it, keeps it across frames and hands it in
+    /// here so stateful widgets can update it through
+    /// [`Frame::render_stateful_widget`](ratatui::Frame::render_stateful_widget).
+    ///
+    /// # Arguments
+    ///
+    /// * `widget_state` - View-owned state that persists between frames
+    /// * `render_fn` - Function to handle frame rendering with current state
+    ///
+    /// # Type Parameters
+    ///
+    /// * `W` - Widget state type, often a tuple or struct of ratatui states
+    /// * `F` - Render function type that accepts snapshot, widget state, area, and frame
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use oxitty::{AtomicState, StateSnapshot, Tui};
+    /// # use std::sync::atomic::{AtomicBool, Ordering};
+    /// # #[derive(Debug)]
+    /// # struct AppState { running: AtomicBool }
+    /// # #[derive(Debug, Clone)]
+    /// # struct AppSnapshot { running: bool }
+    /// # impl StateSnapshot for AppSnapshot {
+    /// #     fn should_quit(&self) -> bool { !self.running }
+    /// # }
+    /// # impl AtomicState for AppState {
+    /// #     type Snapshot = AppSnapshot;
+    /// #     fn snapshot(&self) -> Self::Snapshot {
+    /// #         AppSnapshot { running: self.running.load(Ordering::Acquire) }
+    /// #     }
+    /// #     fn quit(&self) { self.running.store(false, Ordering::Release); }
+    /// #     fn is_running(&self) -> bool { self.running.load(Ordering::Acquire) }
+    /// # }
+    /// # fn example(tui: &mut Tui<AppState>) -> oxitty::OxittyResult<()> {
+    /// use ratatui::widgets::{List, ListState};
+    ///
+    /// let mut list_state = ListState::default().with_selected(Some(1));
+    ///
+    /// tui.render_stateful(&mut list_state, |_snapshot, list_state, area, frame| {
+    ///     let list = List::new(["one", "two", "three"]).highlight_symbol("> ");
+    ///     frame.render_stateful_widget(list, area, list_state);
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn render_stateful<W, F>(&mut self, widget_state: &mut W, render_fn: F) -> OxittyResult<()>
+    where
+        F: FnOnce(&S::Snapshot, &mut W, Rect, &mut ratatui::Frame<'_>),
+    {
+        self.render(|snapshot, area, frame| render_fn(snapshot, widget_state, area, frame))
+    }
+
+    /// Redraws only a sub-rectangle of the screen.
+    ///
+    /// The previously drawn frame is carried over unchanged and the render
+    /// function is handed `area` (clipped to the frame) instead of the full
+    /// screen, so only cells inside that region are diffed and flushed.
+    /// Content outside `area` is only preserved if the render function
+    /// restricts its drawing to the area it is given. As with
+    /// [`render`](Self::render), the render function is not called when the
+    /// clipped region is empty.
+    ///
+    /// # Resizing
+    ///
+    /// A terminal resize invalidates the previous frame. When the frame size
+    /// no longer matches the last drawn one, nothing is carried over and only
+    /// `area` is drawn; callers should follow a resize with a full
+    /// [`render`](Self::render).
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Region of the screen to redraw
+    /// * `render_fn` - Function to handle rendering within the region
+    pub fn render_region<F>(&mut self, area: Rect, render_fn: F) -> OxittyResult<()>
+    where
+        F: FnOnce(&S::Snapshot, Rect, &mut ratatui::Frame<'_>),
+    {
+        self.clear_before_first_frame()?;
+        self.apply_full_redraw();
+        let snapshot = Self::cached_snapshot(&self.state, &mut self.snapshot_cache);
+        let last_frame = self.last_frame.take();
+        let monochrome = self.monochrome;
+
+        let completed = self
+            .terminal
+            .draw(|frame| {
+                if let Some(previous) = last_frame {
+                    if previous.area == frame.area() {
+                        *frame.buffer_mut() = previous;
+                    }
+                }
+
+                let region = area.intersection(frame.area());
+                if !region.is_empty() {
+                    in_theme_mode(monochrome, || render_fn(snapshot, region, frame));
+                }
+            })
+            .map_err(|e| draw_error(e, "Failed to render frame region"))?;
+
+        self.last_frame = Some(completed.buffer.clone());
+        Ok(())
+    }
+
+    /// Redraws a sub-rectangle of the screen, discarding writes outside it.
+    ///
+    /// Behaves like [`render_region`](Self::render_region), except that the
+    /// render function cannot affect anything beyond `area`: cells it
+    /// changes outside the region are put back as they were. Use it to
+    /// contain a misbehaving widget while tracking down which panel it
+    /// corrupts. See [`clip`] to clip part of a full-screen render instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Region of the screen the render function may change
+    /// * `render_fn` - Function to handle rendering within the region
+    pub fn render_clipped<F>(&mut self, area: Rect, render_fn: F) -> OxittyResult<()>
+    where
+        F: FnOnce(&S::Snapshot, Rect, &mut ratatui::Frame<'_>),
+    {
+        self.render_region(area, |snapshot, region, frame| {
+            clip(frame, region, |region, frame| {
+                render_fn(snapshot, region, frame)
+            })
+        })
+    }
+
+    /// Alerts the user, for example after invalid input.
+    ///
+    /// Writes the BEL character to the terminal, or with
+    /// [`set_visual_bell`](Self::set_visual_bell) enabled, flashes the
+    /// screen instead; see [`flash`](Self::flash).
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the BEL character cannot be written.
+    pub fn bell(&mut self) -> OxittyResult<()>
+    where
+        B: io::Write,
+    {
+        if self.visual_bell {
+            self.flash();
+            return Ok(());
+        }
+
+        let backend = self.terminal.backend_mut();
+        Ok(backend
+            .write_all(b"\x07")
+            .and_then(|()| io::Write::flush(backend))
+            .map_err(|e| {
+                OxittyError::terminal(
+                    "terminal bell",
+                    (0, 0),
+                    format!("Failed to ring bell: {}", e),
+                )
+            })?)
+    }
+
+    /// Asks the terminal for its background color with OSC 11.
+    ///
+    /// Writes the query, waits briefly for the reply on standard input and
+    /// parses it, so applications can pick a light or dark theme to match
+    /// the terminal. Raw mode is enabled for the duration of the query if
+    /// it is not already. Call this before input handling starts, for
+    /// example right after creating the `Tui`, so that an event reader does
+    /// not consume the reply.
+    ///
+    /// # Returns
+    ///
+    /// The terminal background, or `None` if standard input is not a
+    /// terminal, the terminal does not answer within 100ms or the reply
+    /// cannot be parsed. Always `None` on platforms other than unix, and
+    /// for a `Tui` that does not manage the terminal, such as one created
+    /// with [`with_writer`](Self::with_writer) or
+    /// [`with_backend`](Self::with_backend): the reply would arrive on the
+    /// real terminal, not the writer the query goes to.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the query cannot be written or reading the reply
+    /// fails.
+    pub fn query_background_color(&mut self) -> OxittyResult<Option<Color>>
+    where
+        B: io::Write,
+    {
+        if self.mode == TerminalMode::Unmanaged || !atty::is(atty::Stream::Stdin) {
+            return Ok(None);
+        }
+
+        let enable_raw = !self.raw_mode && !terminal::is_raw_mode_enabled().unwrap_or(false);
+        if enable_raw {
+            (self.raw_mode_switch)(true).map_err(|e| {
+                OxittyError::terminal(
+                    "background color query",
+                    (0, 0),
+                    format!("Failed to enable raw mode: {}", e),
+                )
+            })?;
+        }
+
+        let backend = self.terminal.backend_mut();
+        let reply = backend
+            .write_all(b"\x1b]11;?\x1b\\")
+            .and_then(|()| io::Write::flush(backend))
+            .and_then(|()| read_osc_reply(OSC_REPLY_TIMEOUT));
+
+        if enable_raw {
+            let _ = (self.raw_mode_switch)(false);
+        }
+
+        let reply = reply.map_err(|e| {
+            OxittyError::terminal(
+                "background color query",
                 (0, 0),
-                format!("Failed to get terminal size: {}", e),
+                format!("Failed to query background color: {}", e),
+            )
+        })?;
+        Ok(parse_osc11_reply(&reply))
+    }
+
+    /// Chooses whether [`bell`](Self::bell) flashes the screen instead of beeping.
+    ///
+    /// Useful for users who have disabled the audible bell or terminals
+    /// that ignore it.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to use the visual bell
+    pub fn set_visual_bell(&mut self, enabled: bool) {
+        self.visual_bell = enabled;
+    }
+
+    /// Shows the next rendered frame with foreground and background swapped.
+    ///
+    /// The flash lasts exactly one frame: the render after it draws
+    /// normally again, so the caller must render twice for the flash to
+    /// appear and disappear. Frames drawn with
+    /// [`render_region`](Self::render_region) do not show it.
+    pub fn flash(&mut self) {
+        self.flash_pending = true;
+    }
+
+    /// Renders theme colors as shades of gray.
+    ///
+    /// While enabled, render functions run as if the terminal reported
+    /// [`ColorSupport::Grayscale`]. The theme styles and blocks of this type,
+    /// and anything else converting colors through [`color_support`], then
+    /// map every color to a step of the 24-level gray ramp by luminance.
+    /// Colors converted with [`Color::to_ratatui`] directly are unaffected.
+    /// Meant for monochrome terminals and accessibility modes.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to render in grayscale
+    pub fn set_monochrome(&mut self, enabled: bool) {
+        self.monochrome = enabled;
+    }
+
+    /// Returns whether theme colors are rendered as grays.
+    pub fn is_monochrome(&self) -> bool {
+        self.monochrome
+    }
+
+    /// Keeps the final frame visible after the terminal is restored.
+    ///
+    /// By default a fullscreen UI disappears with the alternate screen on
+    /// exit. With this enabled, the last rendered frame is printed to the
+    /// main screen after leaving it, so it remains in the scrollback like a
+    /// pager's output. An inline viewport is already on the main screen; the
+    /// cursor is left below it rather than returned to its position before
+    /// setup, so the shell prompt does not overwrite the frame. Applies when
+    /// the `Tui` is dropped, [`restore`](Self::restore)d or
+    /// [`shutdown`](Self::shutdown), not while suspended with
+    /// [`with_suspended`](Self::with_suspended).
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to leave the final frame on screen
+    pub fn set_leave_on_exit(&mut self, enabled: bool) {
+        self.leave_on_exit = enabled;
+    }
+
+    /// Returns whether the final frame stays visible after exit.
+    pub fn leaves_on_exit(&self) -> bool {
+        self.leave_on_exit
+    }
+
+    /// Chooses whether the screen is cleared before the first frame.
+    ///
+    /// By default nothing is cleared: a fullscreen UI starts on the blank
+    /// alternate screen, and an inline viewport is drawn over whatever the
+    /// terminal shows there, so cells the first frame leaves empty keep
+    /// their old contents. With this enabled, the first
+    /// [`render`](Self::render) or [`render_region`](Self::render_region)
+    /// clears the viewport first, as [`clear`](Self::clear) does. Has no
+    /// effect once a frame has been drawn.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to clear before the first frame
+    pub fn set_clear_on_start(&mut self, enabled: bool) {
+        self.clear_on_start = enabled;
+    }
+
+    /// Returns whether the screen is cleared before the first frame.
+    pub fn clears_on_start(&self) -> bool {
+        self.clear_on_start
+    }
+
+    /// Clears the screen if requested and no frame has been drawn yet.
+    fn clear_before_first_frame(&mut self) -> OxittyResult<()> {
+        if self.clear_on_start && self.terminal.get_frame().count() == 0 {
+            self.clear()?;
+        }
+        Ok(())
+    }
+
+    /// Returns whether a [`flash`](Self::flash) is waiting for the next render.
+    pub fn is_flash_pending(&self) -> bool {
+        self.flash_pending
+    }
+
+    /// Makes the next render rewrite every cell, without clearing the screen.
+    ///
+    /// Ratatui normally only sends cells that differ from the previous frame.
+    /// After invalidation the next [`render`](Self::render) or
+    /// [`render_region`](Self::render_region) treats the whole screen as
+    /// changed, then the flag resets. Unlike [`clear`](Self::clear), the
+    /// screen keeps its contents until they are overwritten, which avoids a
+    /// visible flash. The frame kept for `render_region` is discarded.
+    pub fn invalidate(&mut self) {
+        self.full_redraw = true;
+        self.last_frame = None;
+    }
+
+    /// Consumes the [`invalidate`](Self::invalidate) flag.
+    ///
+    /// Fills the back buffer with a symbol no widget draws and swaps it in as
+    /// the previous frame, so ratatui's diff reports every cell as changed.
+    fn apply_full_redraw(&mut self) {
+        if !std::mem::take(&mut self.full_redraw) {
+            return;
+        }
+
+        for cell in &mut self.terminal.current_buffer_mut().content {
+            cell.set_symbol(INVALID_SYMBOL);
+        }
+        self.terminal.swap_buffers();
+    }
+
+    /// Marks the cached snapshot stale so the next render takes a fresh one.
+    ///
+    /// Only needed for states that report a
+    /// [`generation`](AtomicState::generation) but changed without bumping it.
+    pub fn invalidate_snapshot(&mut self) {
+        if let Some((generation, _)) = &mut self.snapshot_cache {
+            *generation = None;
+        }
+    }
+
+    /// Returns the cached snapshot, refreshing it if the generation moved on.
+    ///
+    /// A stale snapshot is updated in place with
+    /// [`snapshot_into`](AtomicState::snapshot_into) rather than replaced.
+    fn cached_snapshot<'a>(state: &S, cache: &'a mut Option<SnapshotCache<S>>) -> &'a S::Snapshot {
+        let generation = state.generation();
+        match cache {
+            // A cached generation of `None` never counts as fresh
+            Some((cached, snapshot)) => {
+                if generation.is_none() || *cached != generation {
+                    state.snapshot_into(snapshot);
+                    *cached = generation;
+                }
+                snapshot
+            }
+            None => &cache.insert((generation, state.snapshot())).1,
+        }
+    }
+
+    /// Runs a closure with the terminal temporarily handed back to the user.
+    ///
+    /// Raw mode, the alternate screen and mouse capture are disabled and the
+    /// cursor is shown before `f` runs, so it can spawn programs such as
+    /// `$EDITOR` or a pager that inherit the real terminal. Afterwards the
+    /// TUI modes are re-enabled and the screen is cleared so the next render
+    /// repaints everything. For a [`Tui::with_backend`] instance only the
+    /// cursor and screen are managed.
+    ///
+    /// If `f` panics, the terminal is resumed before the panic propagates.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Closure to run while suspended
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the terminal cannot be restored or set up again.
+    pub fn with_suspended<T>(&mut self, f: impl FnOnce() -> T) -> OxittyResult<T> {
+        /// Resumes the terminal when dropped during a panic in `f`
+        struct Resume<'a, S: AtomicState, B: Backend>(&'a mut Tui<S, B>);
+
+        impl<S: AtomicState, B: Backend> Drop for Resume<'_, S, B> {
+            fn drop(&mut self) {
+                if std::thread::panicking() {
+                    if let Err(e) = self.0.resume() {
+                        eprintln!("Failed to resume terminal: {}", e);
+                    }
+                }
+            }
+        }
+
+        self.suspend(false)?;
+        let guard = Resume(self);
+        let result = f();
+        guard.0.resume()?;
+        Ok(result)
+    }
+
+    /// Hands the terminal back to its original state for good.
+    ///
+    /// Does what dropping the `Tui` would, but reports failures instead of
+    /// printing them. Afterwards the terminal is treated as unmanaged:
+    /// rendering still works, but raw mode and the alternate screen are not
+    /// re-entered and nothing is restored again on drop.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the terminal cannot be restored.
+    pub fn restore(&mut self) -> OxittyResult<()> {
+        self.suspend(true)?;
+        self.mode = TerminalMode::Unmanaged;
+        Ok(())
+    }
+
+    /// Restores the terminal and consumes the `Tui`, reporting any failure.
+    ///
+    /// Dropping a `Tui` restores the terminal too, but can only print a
+    /// failure to stderr. Call this instead on early-exit paths, such as an
+    /// error between construction and rendering, to handle cleanup errors.
+    /// Restoring is attempted once; on failure, drop does not try again.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the terminal cannot be restored.
+    pub fn shutdown(mut self) -> OxittyResult<()> {
+        let restored = self.suspend(true);
+        self.mode = TerminalMode::Unmanaged;
+        restored
+    }
+
+    /// Hands the terminal back to its original state for [`with_suspended`](Self::with_suspended).
+    ///
+    /// `exiting` is set when the terminal is restored for good.
+    fn suspend(&mut self, exiting: bool) -> OxittyResult<()> {
+        if self.mode != TerminalMode::Unmanaged {
+            return self.restore_terminal(exiting);
+        }
+
+        self.place_cursor(exiting)?;
+        Ok(self.terminal.show_cursor().map_err(|e| {
+            OxittyError::terminal(
+                "terminal suspend",
+                (0, 0),
+                format!("Failed to show cursor: {}", e),
             )
         })?)
     }
 
-    /// Flushes pending changes to terminal.
-    pub fn flush(&mut self) -> OxittyResult<()> {
-        Ok(self.terminal.flush().map_err(|e| {
+    /// Positions the cursor for the shell once the terminal is handed back.
+    ///
+    /// An inline viewport moves the cursor to the line below it, so the
+    /// shell prompt does not overwrite the UI, keeping the column it had
+    /// before setup. When `exiting` with
+    /// [`set_leave_on_exit`](Self::set_leave_on_exit) enabled, the cursor
+    /// starts the line instead, below the final frame.
+    fn place_cursor(&mut self, exiting: bool) -> OxittyResult<()> {
+        let Some(saved) = self.saved_cursor else {
+            return Ok(());
+        };
+
+        let column = if exiting && self.leave_on_exit {
+            0
+        } else {
+            saved.x
+        };
+        self.move_below_viewport(column)
+    }
+
+    /// Moves the cursor to `column` on the line below the viewport.
+    ///
+    /// Scrolls the screen up a line if the viewport ends at the bottom.
+    fn move_below_viewport(&mut self, column: u16) -> OxittyResult<()> {
+        let area = self.terminal.get_frame().area();
+        let backend = self.terminal.backend_mut();
+
+        backend
+            .size()
+            .and_then(|screen| {
+                if area.bottom() < screen.height {
+                    return backend.set_cursor_position(Position::new(column, area.bottom()));
+                }
+                // The viewport ends on the last row, which becomes free after scrolling
+                let last_row = screen.height.saturating_sub(1);
+                backend.set_cursor_position(Position::new(0, last_row))?;
+                backend.append_lines(1)?;
+                backend.set_cursor_position(Position::new(column, last_row))
+            })
+            .and_then(|()| backend.flush())
+            .map_err(|e| {
+                OxittyError::terminal(
+                    "terminal cleanup",
+                    (0, 0),
+                    format!("Failed to move cursor below viewport: {}", e),
+                )
+                .into()
+            })
+    }
+
+    /// Writes the last rendered frame to stdout as lines of colored text.
+    ///
+    /// Used after leaving the alternate screen, where the frame would
+    /// otherwise vanish with it.
+    fn print_final_frame(&self) -> OxittyResult<()> {
+        let Some(frame) = &self.last_frame else {
+            return Ok(());
+        };
+
+        Ok(write_buffer(&mut io::stdout(), frame).map_err(|e| {
             OxittyError::terminal(
-                "terminal flush",
+                "terminal cleanup",
                 (0, 0),
-                format!("Failed to flush terminal: {}", e),
+                format!("Failed to print final frame: {}", e),
+            )
+        })?)
+    }
+
+    /// Re-enters TUI mode after [`suspend`](Self::suspend) and invalidates the screen.
+    fn resume(&mut self) -> OxittyResult<()> {
+        if self.mode != TerminalMode::Unmanaged && !self.raw_mode {
+            Self::enter_terminal_modes(self.mode, self.mouse_capture, self.raw_mode_switch)?;
+            self.raw_mode = true;
+        }
+
+        self.clear()
+    }
+
+    /// Clears the screen and forces the next render to repaint every cell.
+    ///
+    /// Use this when the display may have been corrupted by output that
+    /// bypassed ratatui, such as a subprocess writing to the terminal. The
+    /// frame kept for [`render_region`](Self::render_region) is discarded too.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the backend fails to clear the screen.
+    pub fn clear(&mut self) -> OxittyResult<()> {
+        self.last_frame = None;
+
+        Ok(self.terminal.clear().map_err(|e| {
+            OxittyError::terminal(
+                "terminal clear",
+                (0, 0),
+                format!("Failed to clear terminal: {}", e),
+            )
+        })?)
+    }
+
+    /// Inserts lines above an inline viewport, scrolling them into the scrollback.
+    ///
+    /// Intended for [`Tui::new_inline`] instances, e.g. to print log output
+    /// that stays on screen after the UI exits. With a fullscreen viewport
+    /// ratatui draws the lines at the top of the screen instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `height` - Number of lines to insert
+    /// * `draw_fn` - Function drawing the inserted lines into a buffer
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the backend fails to scroll or draw.
+    pub fn insert_before<F>(&mut self, height: u16, draw_fn: F) -> OxittyResult<()>
+    where
+        F: FnOnce(&mut Buffer),
+    {
+        self.last_frame = None;
+
+        Ok(self.terminal.insert_before(height, draw_fn).map_err(|e| {
+            OxittyError::terminal(
+                "terminal insert",
+                (0, 0),
+                format!("Failed to insert lines: {}", e),
             )
         })?)
     }
 
-    /// Returns default theme style (primary text on base background).
-    pub fn style() -> Style {
-        Style::default()
-            .fg(theme::text::PRIMARY.into())
-            .bg(theme::background::BASE.into())
-    }
+    /// Enables or disables mouse capture while the TUI is running.
+    ///
+    /// With capture off the terminal handles the mouse itself, which allows
+    /// native text selection and copying; no mouse events are delivered in
+    /// the meantime. The current setting is tracked so that cleanup only
+    /// disables capture when it is actually enabled. A [`Tui::with_backend`]
+    /// instance only tracks the setting without touching the real terminal.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether the terminal should report mouse events
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the terminal rejects the mode change.
+    pub fn set_mouse_capture(&mut self, enabled: bool) -> OxittyResult<()> {
+        if enabled == self.mouse_capture {
+            return Ok(());
+        }
+
+        if self.mode != TerminalMode::Unmanaged {
+            let result = if enabled {
+                execute!(io::stdout(), EnableMouseCapture)
+            } else {
+                execute!(io::stdout(), DisableMouseCapture)
+            };
+            result.map_err(|e| {
+                OxittyError::terminal(
+                    "mouse capture",
+                    (0, 0),
+                    format!("Failed to toggle mouse capture: {}", e),
+                )
+            })?;
+        }
+
+        self.mouse_capture = enabled;
+        Ok(())
+    }
+
+    /// Returns whether this instance currently has the terminal in raw mode.
+    ///
+    /// Set by [`Tui::new`] and [`Tui::new_inline`], cleared while
+    /// [`with_suspended`](Self::with_suspended) runs its closure and when the
+    /// terminal is restored. Always `false` for [`Tui::with_backend`]
+    /// instances, which never touch the real terminal.
+    pub fn is_raw_mode(&self) -> bool {
+        self.raw_mode
+    }
+
+    /// Returns whether mouse capture is currently enabled.
+    pub fn mouse_capture(&self) -> bool {
+        self.mouse_capture
+    }
+
+    /// Moves the hardware cursor to the given position.
+    ///
+    /// Each render hides the cursor unless the render function places it
+    /// with [`Frame::set_cursor_position`](ratatui::Frame::set_cursor_position),
+    /// so text inputs that want a visible, blinking cursor should set it
+    /// from inside the render function on every frame. This method is meant
+    /// for positioning outside of rendering.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Column of the cursor
+    /// * `y` - Row of the cursor
+    pub fn set_cursor(&mut self, x: u16, y: u16) -> OxittyResult<()> {
+        Ok(self
+            .terminal
+            .set_cursor_position(Position::new(x, y))
+            .map_err(|e| {
+                OxittyError::terminal(
+                    "terminal cursor",
+                    (0, 0),
+                    format!("Failed to move cursor: {}", e),
+                )
+            })?)
+    }
+
+    /// Returns the cursor position recorded before the inline viewport was set up.
+    ///
+    /// Recorded by [`Tui::new_inline`] and [`Tui::with_backend_inline`].
+    /// Cleanup puts the cursor back in this column on the line below the
+    /// viewport, so the shell prompt continues where it was rather than on
+    /// top of the UI or wherever the UI last left the cursor. The row is not
+    /// reused, since the viewport may have been drawn over it or scrolled it
+    /// away. `None` for fullscreen instances, which get their cursor back
+    /// with the main screen, or if the position could not be queried.
+    pub fn saved_cursor(&self) -> Option<Position> {
+        self.saved_cursor
+    }
+
+    /// Overrides the cursor position whose column is restored on cleanup.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - Position whose column the cursor returns to below
+    ///   the viewport, or `None` to leave it wherever the UI last put it
+    pub fn set_saved_cursor(&mut self, position: Option<Position>) {
+        self.saved_cursor = position;
+    }
+
+    /// Hides the hardware cursor.
+    pub fn hide_cursor(&mut self) -> OxittyResult<()> {
+        Ok(self.terminal.hide_cursor().map_err(|e| {
+            OxittyError::terminal(
+                "terminal cursor",
+                (0, 0),
+                format!("Failed to hide cursor: {}", e),
+            )
+        })?)
+    }
+
+    /// Shows the hardware cursor.
+    ///
+    /// See [`set_cursor`](Self::set_cursor) for how rendering affects visibility.
+    pub fn show_cursor(&mut self) -> OxittyResult<()> {
+        Ok(self.terminal.show_cursor().map_err(|e| {
+            OxittyError::terminal(
+                "terminal cursor",
+                (0, 0),
+                format!("Failed to show cursor: {}", e),
+            )
+        })?)
+    }
+
+    /// Resizes the viewport after a terminal resize.
+    ///
+    /// Updates the drawing area to the new dimensions, clears the screen and
+    /// discards the frame kept for [`render_region`](Self::render_region), so
+    /// the next render is a full redraw at the new size.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - New terminal width in columns
+    /// * `height` - New terminal height in rows
+    pub fn resize(&mut self, width: u16, height: u16) -> OxittyResult<()> {
+        self.last_frame = None;
+
+        Ok(self
+            .terminal
+            .resize(Rect::new(0, 0, width, height))
+            .map_err(|e| {
+                OxittyError::terminal(
+                    "terminal resize",
+                    (0, 0),
+                    format!("Failed to resize terminal: {}", e),
+                )
+            })?)
+    }
+
+    /// Returns reference to underlying terminal instance.
+    pub fn terminal(&self) -> &Terminal<B> {
+        &self.terminal
+    }
+
+    /// Returns mutable reference to underlying terminal instance.
+    pub fn terminal_mut(&mut self) -> &mut Terminal<B> {
+        &mut self.terminal
+    }
+
+    /// Returns reference to current application state.
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Returns current terminal dimensions.
+    pub fn size(&self) -> OxittyResult<Size> {
+        Ok(self.terminal.size().map_err(|e| {
+            OxittyError::terminal(
+                "terminal size",
+                (0, 0),
+                format!("Failed to get terminal size: {}", e),
+            )
+        })?)
+    }
+
+    /// Returns the full terminal area anchored at the origin.
+    ///
+    /// Lets layout code plan between frames without calling
+    /// [`render`](Self::render). For an inline viewport this is still the
+    /// whole terminal, not the lines reserved for the UI.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the terminal size cannot be queried.
+    pub fn area(&self) -> OxittyResult<Rect> {
+        let size = self.size()?;
+        Ok(Rect::new(0, 0, size.width, size.height))
+    }
+
+    /// Returns the contents of the most recently drawn frame.
+    ///
+    /// The buffer holds every cell as it was drawn, independent of the
+    /// backend, so it can be cloned and shipped to another display or
+    /// written out as a frame dump after each [`render`](Self::render).
+    ///
+    /// # Returns
+    ///
+    /// * `Some(buffer)` - The last frame drawn by `render` or
+    ///   [`render_region`](Self::render_region)
+    /// * `None` - Nothing drawn yet, or the frame was discarded by
+    ///   [`invalidate`](Self::invalidate), [`clear`](Self::clear),
+    ///   [`insert_before`](Self::insert_before) or [`resize`](Self::resize)
+    pub fn last_buffer(&self) -> Option<&Buffer> {
+        self.last_frame.as_ref()
+    }
+
+    /// Flushes pending changes to terminal.
+    pub fn flush(&mut self) -> OxittyResult<()> {
+        Ok(self.terminal.flush().map_err(|e| {
+            OxittyError::terminal(
+                "terminal flush",
+                (0, 0),
+                format!("Failed to flush terminal: {}", e),
+            )
+        })?)
+    }
+
+    /// Returns the theme style for a semantic role.
+    ///
+    /// Every role draws its foreground color from the theme on the base
+    /// background. The named helpers such as [`Tui::error`] are shorthands
+    /// for this method, which is useful when the role is chosen from data.
+    /// When [`color_support`] reports a 16-color terminal, each color is
+    /// replaced by the nearest named ANSI color. Inside
+    /// [`ThemeContext::apply`], the context's override for the role, if
+    /// any, replaces the theme color.
+    ///
+    /// # Arguments
+    ///
+    /// * `semantic` - The role to style
+    pub fn style_for(semantic: Semantic) -> Style {
+        let themed = match semantic {
+            Semantic::Primary => theme::text::PRIMARY,
+            Semantic::Secondary => theme::text::SECONDARY,
+            Semantic::Error => theme::status::ERROR,
+            Semantic::Warning => theme::status::WARNING,
+            Semantic::Info => theme::status::INFO,
+            Semantic::Success => theme::status::SUCCESS,
+            Semantic::Border => theme::background::ELEVATION_3,
+            Semantic::Focus => theme::void::PURPLE,
+            Semantic::Void => theme::void::GREEN,
+        };
+        let fg = ThemeContext::current().color(semantic).unwrap_or(themed);
+
+        let support = color_support();
+        Style::default()
+            .fg(fg.to_ratatui_for(support))
+            .bg(theme::background::BASE.to_ratatui_for(support))
+    }
+
+    /// Returns default theme style (primary text on base background).
+    pub fn style() -> Style {
+        Self::style_for(Semantic::Primary)
+    }
+
+    /// Returns primary text style.
+    pub fn primary() -> Style {
+        Self::style_for(Semantic::Primary)
+    }
+
+    /// Returns secondary text style.
+    pub fn secondary() -> Style {
+        Self::style_for(Semantic::Secondary)
+    }
+
+    /// Returns error message style.
+    pub fn error() -> Style {
+        Self::style_for(Semantic::Error)
+    }
+
+    /// Returns warning message style.
+    pub fn warning() -> Style {
+        Self::style_for(Semantic::Warning)
+    }
+
+    /// Returns info message style.
+    pub fn info() -> Style {
+        Self::style_for(Semantic::Info)
+    }
+
+    /// Returns success message style.
+    pub fn success() -> Style {
+        Self::style_for(Semantic::Success)
+    }
+
+    /// Returns info banner style.
+    ///
+    /// The info color on its subtle tint, flattened onto the base
+    /// background so it renders the same on terminals without blending.
+    pub fn info_banner() -> Style {
+        Self::banner(theme::status::INFO, theme::status::INFO_SUBTLE)
+    }
+
+    /// Returns success banner style.
+    ///
+    /// See [`info_banner`](Self::info_banner) for how the background is derived.
+    pub fn success_banner() -> Style {
+        Self::banner(theme::status::SUCCESS, theme::status::SUCCESS_SUBTLE)
+    }
+
+    /// Returns warning banner style.
+    ///
+    /// See [`info_banner`](Self::info_banner) for how the background is derived.
+    pub fn warning_banner() -> Style {
+        Self::banner(theme::status::WARNING, theme::status::WARNING_SUBTLE)
+    }
+
+    /// Returns error banner style.
+    ///
+    /// See [`info_banner`](Self::info_banner) for how the background is derived.
+    pub fn error_banner() -> Style {
+        Self::banner(theme::status::ERROR, theme::status::ERROR_SUBTLE)
+    }
+
+    /// Builds a banner style from a status color and its translucent tint.
+    fn banner(fg: Color, subtle: Color) -> Style {
+        let support = color_support();
+        Style::default().fg(fg.to_ratatui_for(support)).bg(subtle
+            .over(&theme::background::BASE)
+            .to_ratatui_for(support))
+    }
+
+    /// Returns border element style.
+    pub fn border() -> Style {
+        Self::style_for(Semantic::Border)
+    }
+
+    /// Returns focused element style.
+    pub fn focus() -> Style {
+        Self::style_for(Semantic::Focus)
+    }
+
+    /// Returns void element style.
+    pub fn void() -> Style {
+        Self::style_for(Semantic::Void)
+    }
+
+    /// Returns primary text style on an elevated background.
+    ///
+    /// Nested panels read as raised when their background is lighter than
+    /// the one they sit on; pick a higher `level` for each nesting depth.
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - Elevation passed to [`theme::background::custom_elevation`]
+    ///   (0.0-3.0, where 0.0 is the base background)
+    pub fn style_on_elevation(level: f32) -> Style {
+        let background = theme::background::custom_elevation(level);
+        Self::primary().bg(background.to_ratatui_for(color_support()))
+    }
+
+    /// Returns a style with legible text on an arbitrary background.
+    ///
+    /// The foreground is [`theme::text::PRIMARY`] on dark backgrounds and
+    /// the near-black base background color on light ones, as chosen by
+    /// [`Color::readable_foreground`]. Use it for cells whose background
+    /// comes from data, such as tags or heatmap entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `bg` - Background color of the cell
+    pub fn contrasting_style(bg: Color) -> Style {
+        let support = color_support();
+        Style::default()
+            .fg(bg.readable_foreground().to_ratatui_for(support))
+            .bg(bg.to_ratatui_for(support))
+    }
+
+    /// Creates a themed block with given title.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Block title text
+    pub fn block(title: impl Into<String>) -> Block<'static> {
+        Block::default()
+            .title(Line::from(title.into()))
+            .style(Self::primary())
+            .border_style(Self::border())
+    }
+
+    /// Creates a bordered block highlighting the focused element.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Block title text
+    pub fn block_focused(title: impl Into<String>) -> Block<'static> {
+        Self::block(title)
+            .borders(Borders::ALL)
+            .border_style(Self::focus())
+    }
+
+    /// Creates a bordered block colored by status severity.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Block title text
+    /// * `severity` - Selects the border color
+    pub fn block_status(title: impl Into<String>, severity: Severity) -> Block<'static> {
+        Self::block(title)
+            .borders(Borders::ALL)
+            .border_style(Self::style_for(severity.into()))
+    }
+
+    /// Creates a themed block drawn on an elevated background.
+    ///
+    /// Like [`block`](Self::block), with the background of
+    /// [`style_on_elevation`](Self::style_on_elevation).
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Block title text
+    /// * `level` - Elevation of the block's background (0.0-3.0)
+    pub fn block_on_elevation(title: impl Into<String>, level: f32) -> Block<'static> {
+        Self::block(title).style(Self::style_on_elevation(level))
+    }
+
+    /// Creates a borderless block with given title.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Block title text
+    pub fn block_plain(title: impl Into<String>) -> Block<'static> {
+        Block::default()
+            .title(Line::from(title.into()))
+            .style(Self::primary())
+            .borders(Borders::NONE)
+    }
+
+    /// Draws plain text into an area with the given style.
+    ///
+    /// The whole area takes the style and the text is written from its top
+    /// left corner, one line per `\n`, clipped to the area. This saves
+    /// building a `Paragraph` for simple labels and status lines.
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - Frame to draw into
+    /// * `area` - Region to fill
+    /// * `text` - Text to draw, possibly spanning several lines
+    /// * `style` - Style of the text and the area behind it
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use oxitty::{state::StateFlags, tui::buffer_to_string, Tui};
+    /// use ratatui::layout::Size;
+    ///
+    /// let mut tui = Tui::headless(StateFlags::default(), Size::new(6, 2));
+    /// tui.render(|_, area, frame| {
+    ///     Tui::<StateFlags>::draw_text(frame, area, "ready\nok", Tui::<StateFlags>::primary());
+    /// })
+    /// .unwrap();
+    /// assert_eq!(
+    ///     buffer_to_string(tui.last_buffer().unwrap()),
+    ///     "ready \nok    "
+    /// );
+    /// ```
+    pub fn draw_text(frame: &mut ratatui::Frame<'_>, area: Rect, text: &str, style: Style) {
+        frame.render_widget(Paragraph::new(text).style(style), area);
+    }
+
+    /// Draws a status message in the theme color for its severity.
+    ///
+    /// Shorthand for [`draw_text`](Self::draw_text) with the style of
+    /// [`style_for`](Self::style_for) for the severity.
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - Frame to draw into
+    /// * `area` - Region to fill
+    /// * `msg` - Message to draw, possibly spanning several lines
+    /// * `severity` - Selects the text color
+    pub fn draw_status(frame: &mut ratatui::Frame<'_>, area: Rect, msg: &str, severity: Severity) {
+        Self::draw_text(frame, area, msg, Self::style_for(severity.into()));
+    }
+}
+
+/// How long [`Tui::query_background_color`] waits for the terminal to answer.
+const OSC_REPLY_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Reads an OSC reply from standard input until its terminator or timeout.
+///
+/// Bytes are read one at a time straight from the file descriptor, so
+/// input typed after the reply stays queued for the event reader.
+#[cfg(unix)]
+fn read_osc_reply(timeout: Duration) -> io::Result<Vec<u8>> {
+    use rustix::{
+        event::{poll, PollFd, PollFlags},
+        io::Errno,
+    };
+    use std::time::Instant;
+
+    let stdin = io::stdin();
+    let deadline = Instant::now() + timeout;
+    let mut reply = Vec::new();
+
+    while !reply.ends_with(b"\x07") && !reply.ends_with(b"\x1b\\") {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            break;
+        }
+
+        let mut fds = [PollFd::new(&stdin, PollFlags::IN)];
+        let millis = remaining.as_millis().clamp(1, i32::MAX as u128) as i32;
+        match poll(&mut fds, millis) {
+            Ok(0) => break,
+            Ok(_) => {}
+            Err(Errno::INTR) => continue,
+            Err(e) => return Err(e.into()),
+        }
+
+        let mut byte = [0u8];
+        match rustix::io::read(&stdin, &mut byte) {
+            Ok(0) => break,
+            Ok(_) => reply.push(byte[0]),
+            Err(Errno::INTR) => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    Ok(reply)
+}
+
+/// Reads an OSC reply from standard input until its terminator or timeout.
+///
+/// Not supported on this platform, so no reply is ever read.
+#[cfg(not(unix))]
+fn read_osc_reply(_timeout: Duration) -> io::Result<Vec<u8>> {
+    Ok(Vec::new())
+}
+
+/// Parses the color out of an OSC 11 reply.
+///
+/// Terminals answer `ESC ]11;rgb:rrrr/gggg/bbbb` terminated by either BEL
+/// or `ESC \`; the color is parsed with [`Color::from_x11`]. Bytes before
+/// the reply, such as stray input, are skipped.
+fn parse_osc11_reply(reply: &[u8]) -> Option<Color> {
+    const PREFIX: &[u8] = b"\x1b]11;";
+
+    let start = reply
+        .windows(PREFIX.len())
+        .position(|window| window == PREFIX)?
+        + PREFIX.len();
+    let body = &reply[start..];
+    let body = body
+        .strip_suffix(b"\x07")
+        .or_else(|| body.strip_suffix(b"\x1b\\"))?;
+
+    Color::from_x11(std::str::from_utf8(body).ok()?)
+}
+
+/// Renders once into an off-screen buffer of the given size.
+///
+/// Runs `render_fn` against an in-memory `TestBackend` and returns the
+/// resulting buffer, which makes golden-file tests of a UI a single call.
+/// Combine with [`buffer_to_string`] for text comparisons.
+///
+/// # Arguments
+///
+/// * `size` - Dimensions of the off-screen buffer
+/// * `render_fn` - Function drawing into the full buffer area
+///
+/// # Examples
+///
+/// ```rust
+/// use oxitty::tui::{buffer_to_string, render_to_buffer};
+/// use ratatui::{layout::Size, widgets::Paragraph};
+///
+/// let buffer = render_to_buffer(Size::new(5, 2), |area, frame| {
+///     frame.render_widget(Paragraph::new("hi"), area);
+/// });
+/// assert_eq!(buffer_to_string(&buffer), "hi   \n     ");
+/// ```
+pub fn render_to_buffer<F>(size: Size, render_fn: F) -> Buffer
+where
+    F: FnOnce(Rect, &mut ratatui::Frame<'_>),
+{
+    let backend = TestBackend::new(size.width, size.height);
+    let mut terminal = Terminal::new(backend).expect("TestBackend cannot fail");
+
+    terminal
+        .draw(|frame| {
+            let area = frame.area();
+            render_fn(area, frame);
+        })
+        .expect("TestBackend cannot fail");
+
+    terminal.backend().buffer().clone()
+}
+
+/// Toggles the reversed modifier on every cell, which is its own inverse.
+fn toggle_reversed(buffer: &mut Buffer) {
+    for cell in &mut buffer.content {
+        cell.modifier.toggle(Modifier::REVERSED);
+    }
+}
+
+/// Runs `f` in grayscale color support when `monochrome` is set.
+fn in_theme_mode<T>(monochrome: bool, f: impl FnOnce() -> T) -> T {
+    if monochrome {
+        with_color_support(ColorSupport::Grayscale, f)
+    } else {
+        f()
+    }
+}
+
+/// Runs a render function that may only change cells inside `area`.
+///
+/// The frame's buffer is saved before `render_fn` runs, and every cell
+/// outside `area` is restored afterwards, so out-of-bounds writes are
+/// discarded instead of corrupting neighbouring panels. `render_fn` is
+/// handed `area` clipped to the frame.
+///
+/// # Arguments
+///
+/// * `frame` - Frame being drawn
+/// * `area` - Region the render function may change
+/// * `render_fn` - Function drawing into the region
+///
+/// # Examples
+///
+/// ```rust
+/// use oxitty::tui::{buffer_to_string, clip, render_to_buffer};
+/// use ratatui::{
+///     layout::{Rect, Size},
+///     widgets::Paragraph,
+/// };
+///
+/// let buffer = render_to_buffer(Size::new(6, 1), |area, frame| {
+///     clip(frame, Rect::new(0, 0, 3, 1), |_, frame| {
+///         // Ignores its region and draws across the whole frame
+///         frame.render_widget(Paragraph::new("overflow"), area);
+///     });
+/// });
+/// assert_eq!(buffer_to_string(&buffer), "ove   ");
+/// ```
+pub fn clip<F>(frame: &mut ratatui::Frame<'_>, area: Rect, render_fn: F)
+where
+    F: FnOnce(Rect, &mut ratatui::Frame<'_>),
+{
+    let region = area.intersection(frame.area());
+    let saved = frame.buffer_mut().clone();
+    render_fn(region, frame);
+
+    // Keep only what was drawn inside the region
+    let drawn = std::mem::replace(frame.buffer_mut(), saved);
+    let buffer = frame.buffer_mut();
+    for position in region.positions() {
+        buffer[position] = drawn[position].clone();
+    }
+}
+
+/// Maps an error from drawing a frame to an [`OxittyError`].
+///
+/// Writes failing because the terminal is gone become
+/// [`OxittyError::Disconnected`], everything else a terminal error.
+fn draw_error(e: io::Error, context: &str) -> OxittyError {
+    let msg = format!("{}: {}", context, e);
+    if is_disconnect(&e) {
+        OxittyError::disconnected("rendering", (0, 0), msg)
+    } else {
+        OxittyError::terminal("rendering", (0, 0), msg)
+    }
+}
+
+/// Returns whether an IO error means the terminal on the other end is gone.
+fn is_disconnect(e: &io::Error) -> bool {
+    // `EIO` is returned by writes to a terminal whose session hung up
+    #[cfg(unix)]
+    if e.raw_os_error() == Some(rustix::io::Errno::IO.raw_os_error()) {
+        return true;
+    }
+
+    matches!(
+        e.kind(),
+        io::ErrorKind::BrokenPipe
+            | io::ErrorKind::ConnectionReset
+            | io::ErrorKind::ConnectionAborted
+            | io::ErrorKind::NotConnected
+            | io::ErrorKind::UnexpectedEof
+    )
+}
+
+/// Writes a buffer to `out` as styled text, one line per row.
+///
+/// Cells hidden behind wide characters are skipped, like in
+/// [`buffer_to_string`], and colors and modifiers are reset at the end of
+/// every row.
+fn write_buffer<W: io::Write>(out: &mut W, buffer: &Buffer) -> io::Result<()> {
+    let area = buffer.area;
+
+    for y in area.top()..area.bottom() {
+        let (mut fg, mut bg, mut modifier) = (None, None, Modifier::empty());
+        for cell in row_glyphs(buffer, y) {
+            if !cell.modifier.contains(modifier) {
+                // Attributes can only be switched off together with the colors
+                queue!(out, SetAttribute(Attribute::Reset))?;
+                (fg, bg, modifier) = (None, None, Modifier::empty());
+            }
+            queue_modifier(out, cell.modifier - modifier)?;
+            modifier = cell.modifier;
+            if fg != Some(cell.fg) {
+                queue!(out, SetForegroundColor(cell.fg.into()))?;
+                fg = Some(cell.fg);
+            }
+            if bg != Some(cell.bg) {
+                queue!(out, SetBackgroundColor(cell.bg.into()))?;
+                bg = Some(cell.bg);
+            }
+            queue!(out, Print(cell.symbol()))?;
+        }
+        queue!(out, SetAttribute(Attribute::Reset), Print("\r\n"))?;
+    }
+
+    out.flush()
+}
+
+/// Queues the attributes switching on each flag of `modifier`.
+fn queue_modifier<W: io::Write>(out: &mut W, modifier: Modifier) -> io::Result<()> {
+    const ATTRIBUTES: [(Modifier, Attribute); 9] = [
+        (Modifier::BOLD, Attribute::Bold),
+        (Modifier::DIM, Attribute::Dim),
+        (Modifier::ITALIC, Attribute::Italic),
+        (Modifier::UNDERLINED, Attribute::Underlined),
+        (Modifier::SLOW_BLINK, Attribute::SlowBlink),
+        (Modifier::RAPID_BLINK, Attribute::RapidBlink),
+        (Modifier::REVERSED, Attribute::Reverse),
+        (Modifier::HIDDEN, Attribute::Hidden),
+        (Modifier::CROSSED_OUT, Attribute::CrossedOut),
+    ];
+
+    for (flag, attribute) in ATTRIBUTES {
+        if modifier.contains(flag) {
+            queue!(out, SetAttribute(attribute))?;
+        }
+    }
+    Ok(())
+}
+
+/// Flattens a buffer into its text content, one line per row.
+///
+/// Styles are dropped and rows are joined with `\n`. Cells hidden behind
+/// wide characters are skipped so each row reads as it appears on screen.
+pub fn buffer_to_string(buffer: &Buffer) -> String {
+    let area = buffer.area;
+
+    (area.top()..area.bottom())
+        .map(|y| {
+            row_glyphs(buffer, y)
+                .map(|cell| cell.symbol())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Iterates the cells of row `y` that start a visible glyph.
+///
+/// ratatui leaves the cells behind a wide symbol as plain spaces, so like
+/// `Buffer::diff` the next `width - 1` cells after each symbol are skipped,
+/// as are cells marked `skip`.
+fn row_glyphs(buffer: &Buffer, y: u16) -> impl Iterator<Item = &ratatui::buffer::Cell> {
+    let area = buffer.area;
+    let mut covered = 0;
+
+    (area.left()..area.right())
+        .map(move |x| &buffer[(x, y)])
+        .filter(move |cell| {
+            if covered > 0 {
+                covered -= 1;
+                return false;
+            }
+            covered = cell.symbol().width().saturating_sub(1);
+            !cell.skip
+        })
+}
+
+impl<S: AtomicState, B: Backend> Drop for Tui<S, B> {
+    fn drop(&mut self) {
+        if self.mode == TerminalMode::Unmanaged {
+            return;
+        }
+
+        if let Err(e) = self.restore_terminal(true) {
+            eprintln!("Failed to restore terminal: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{
+        backend::{ClearType, WindowSize},
+        buffer::Cell,
+        widgets::Paragraph,
+    };
+    use std::{
+        cell::RefCell,
+        rc::Rc,
+        sync::atomic::{AtomicBool, Ordering},
+    };
+
+    /// Test backend that records how many cells each draw call writes,
+    /// along with cursor and clear calls in a log shared with the test.
+    struct CountingBackend {
+        inner: TestBackend,
+        drawn: Vec<usize>,
+        log: Rc<RefCell<Vec<&'static str>>>,
+        /// Whether showing the cursor fails, as on a closed terminal
+        broken: bool,
+        /// Raw bytes written outside of drawing, such as the bell
+        written: Vec<u8>,
+    }
+
+    impl io::Write for CountingBackend {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.written.write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl CountingBackend {
+        fn new(width: u16, height: u16) -> Self {
+            Self {
+                inner: TestBackend::new(width, height),
+                drawn: Vec::new(),
+                log: Rc::default(),
+                broken: false,
+                written: Vec::new(),
+            }
+        }
+    }
+
+    impl Backend for CountingBackend {
+        fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+        where
+            I: Iterator<Item = (u16, u16, &'a Cell)>,
+        {
+            let content: Vec<_> = content.collect();
+            self.drawn.push(content.len());
+            self.inner.draw(content.into_iter())
+        }
+
+        fn hide_cursor(&mut self) -> io::Result<()> {
+            self.log.borrow_mut().push("hide_cursor");
+            self.inner.hide_cursor()
+        }
+
+        fn show_cursor(&mut self) -> io::Result<()> {
+            self.log.borrow_mut().push("show_cursor");
+            if self.broken {
+                return Err(io::Error::new(io::ErrorKind::BrokenPipe, "terminal closed"));
+            }
+            self.inner.show_cursor()
+        }
+
+        fn get_cursor_position(&mut self) -> io::Result<Position> {
+            self.inner.get_cursor_position()
+        }
+
+        fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> io::Result<()> {
+            self.inner.set_cursor_position(position)
+        }
+
+        fn clear(&mut self) -> io::Result<()> {
+            self.log.borrow_mut().push("clear");
+            self.inner.clear()
+        }
+
+        fn clear_region(&mut self, clear_type: ClearType) -> io::Result<()> {
+            self.log.borrow_mut().push("clear");
+            self.inner.clear_region(clear_type)
+        }
+
+        fn size(&self) -> io::Result<Size> {
+            self.inner.size()
+        }
+
+        fn window_size(&mut self) -> io::Result<WindowSize> {
+            self.inner.window_size()
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            self.inner.flush()
+        }
+    }
+
+    // Mock terminal setup
+    fn setup_mock_terminal() {
+        // Force non-interactive environment
+        std::env::remove_var("TERM");
+        std::env::remove_var("COLORTERM");
+        std::env::remove_var("TERMINFO");
+        std::env::remove_var("TERMINFO_DIRS");
+
+        // Set minimal dumb terminal
+        std::env::set_var("TERM", "dumb");
+    }
+
+    #[derive(Debug, Clone)]
+    struct TestSnapshot {
+        running: bool,
+    }
+
+    impl crate::state::StateSnapshot for TestSnapshot {
+        fn should_quit(&self) -> bool {
+            !self.running
+        }
+    }
+
+    #[derive(Debug)]
+    struct TestState {
+        running: AtomicBool,
+    }
+
+    impl AtomicState for TestState {
+        type Snapshot = TestSnapshot;
+
+        fn snapshot(&self) -> Self::Snapshot {
+            TestSnapshot {
+                running: self.running.load(Ordering::Acquire),
+            }
+        }
+
+        fn quit(&self) {
+            self.running.store(false, Ordering::Release);
+        }
+
+        fn is_running(&self) -> bool {
+            self.running.load(Ordering::Acquire)
+        }
+    }
+
+    #[test]
+    fn test_terminal_not_available() {
+        setup_mock_terminal();
+
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+
+        let result = Tui::new(state);
+        assert!(
+            result.is_err(),
+            "Expected TUI creation to fail in mock environment"
+        );
+
+        if let Err(e) = result {
+            let err_msg = e.to_string().to_lowercase();
+            assert!(
+                err_msg.contains("terminal") || err_msg.contains("tty"),
+                "Expected terminal-related error, got: {}",
+                err_msg
+            );
+        }
+    }
+
+    #[test]
+    fn test_tui_creation() {
+        setup_mock_terminal();
+
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+
+        let result = Tui::new(state);
+        assert!(
+            result.is_err(),
+            "Expected TUI creation to fail in mock environment"
+        );
+
+        if let Err(e) = result {
+            let err_msg = e.to_string().to_lowercase();
+            assert!(
+                err_msg.contains("terminal") || err_msg.contains("tty"),
+                "Expected terminal-related error, got: {}",
+                err_msg
+            );
+        }
+    }
+
+    #[test]
+    fn test_theme_styles() {
+        use crate::colors::{with_color_support, ColorSupport};
+
+        with_color_support(ColorSupport::TrueColor, || {
+            // Test primary style
+            let style = Tui::<TestState>::primary();
+            assert_eq!(style.fg, Some(theme::text::PRIMARY.into()));
+            assert_eq!(style.bg, Some(theme::background::BASE.into()));
+
+            // Test error style
+            let style = Tui::<TestState>::error();
+            assert_eq!(style.fg, Some(theme::status::ERROR.into()));
+            assert_eq!(style.bg, Some(theme::background::BASE.into()));
+
+            // Test border style
+            let style = Tui::<TestState>::border();
+            assert_eq!(style.fg, Some(theme::background::ELEVATION_3.into()));
+            assert_eq!(style.bg, Some(theme::background::BASE.into()));
+        });
+    }
+
+    #[test]
+    fn test_headless_renders_without_terminal() {
+        setup_mock_terminal();
+
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+        let mut tui = Tui::headless(state, Size::new(8, 2));
+        assert!(!tui.is_raw_mode());
+        assert_eq!(tui.size().unwrap(), Size::new(8, 2));
+
+        tui.render(|snapshot, area, frame| {
+            let text = if snapshot.running {
+                "running"
+            } else {
+                "stopped"
+            };
+            frame.render_widget(Paragraph::new(text), area);
+        })
+        .unwrap();
+
+        assert_eq!(
+            buffer_to_string(tui.terminal().backend().buffer()),
+            "running \n        "
+        );
+    }
+
+    #[test]
+    fn test_with_writer_draws_into_writer() {
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+        let mut output = Vec::new();
+        let mut tui = Tui::with_writer_sized(state, &mut output, Size::new(6, 2)).unwrap();
+        assert!(!tui.is_raw_mode());
+
+        tui.render(|_, area, frame| {
+            frame.render_widget(Paragraph::new("frame"), area);
+        })
+        .unwrap();
+        assert_eq!(
+            buffer_to_string(tui.last_buffer().unwrap()),
+            "frame \n      "
+        );
+
+        // The bell goes to the same target
+        tui.bell().unwrap();
+        drop(tui);
+
+        assert!(String::from_utf8_lossy(&output).contains("frame"));
+        assert!(output.contains(&0x07));
+    }
+
+    #[test]
+    fn test_area_matches_backend_size() {
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+        let mut tui = Tui::headless(state, Size::new(12, 4));
+        assert_eq!(tui.area().unwrap(), Rect::new(0, 0, 12, 4));
+
+        let mut rendered = None;
+        tui.render(|_, area, _| rendered = Some(area)).unwrap();
+        assert_eq!(rendered, Some(tui.area().unwrap()));
+    }
+
+    #[test]
+    fn test_last_buffer_captures_frame() {
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+        let mut tui = Tui::headless(state, Size::new(6, 2));
+        assert!(tui.last_buffer().is_none());
+
+        tui.render(|_, area, frame| {
+            frame.render_widget(
+                Paragraph::new("frame").style(Tui::<TestState>::error()),
+                area,
+            );
+        })
+        .unwrap();
+
+        let buffer = tui.last_buffer().unwrap().clone();
+        assert_eq!(buffer.area, Rect::new(0, 0, 6, 2));
+        assert_eq!(buffer_to_string(&buffer), "frame \n      ");
+        assert_eq!(buffer[(0, 0)].symbol(), "f");
+        assert_eq!(Some(buffer[(0, 0)].fg), Tui::<TestState>::error().fg);
+
+        tui.invalidate();
+        assert!(tui.last_buffer().is_none());
+    }
+
+    #[test]
+    fn test_render_clipped_discards_writes_outside_area() {
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+        let mut tui = Tui::with_backend(state, TestBackend::new(8, 2)).unwrap();
+
+        tui.render(|_, area, frame| {
+            frame.render_widget(Paragraph::new("........\n........"), area);
+        })
+        .unwrap();
+
+        // The closure ignores its region and paints the whole screen
+        let clip_area = Rect::new(2, 0, 3, 1);
+        let mut given = None;
+        tui.render_clipped(clip_area, |_, region, frame| {
+            given = Some(region);
+            let everywhere = frame.area();
+            frame.render_widget(Paragraph::new("XXXXXXXX\nXXXXXXXX"), everywhere);
+        })
+        .unwrap();
+
+        assert_eq!(given, Some(clip_area));
+        assert_eq!(
+            buffer_to_string(tui.terminal().backend().buffer()),
+            "..XXX...\n........"
+        );
+    }
+
+    #[test]
+    fn test_render_region_preserves_other_regions() {
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+        let mut tui = Tui::with_backend(state, TestBackend::new(10, 2)).unwrap();
+
+        let top = Rect::new(0, 0, 10, 1);
+        let bottom = Rect::new(0, 1, 10, 1);
+
+        tui.render_region(top, |_, area, frame| {
+            frame.render_widget(Paragraph::new("top"), area);
+        })
+        .unwrap();
+        tui.render_region(bottom, |_, area, frame| {
+            frame.render_widget(Paragraph::new("bottom"), area);
+        })
+        .unwrap();
+
+        tui.terminal()
+            .backend()
+            .assert_buffer_lines(["top       ", "bottom    "]);
+    }
+
+    #[test]
+    fn test_render_refreshes_snapshot_in_place() {
+        use std::sync::atomic::AtomicUsize;
+
+        #[derive(Debug, Default)]
+        struct RefreshingState {
+            snapshots: AtomicUsize,
+            refreshes: AtomicUsize,
+        }
+
+        impl AtomicState for RefreshingState {
+            type Snapshot = TestSnapshot;
+
+            fn snapshot(&self) -> Self::Snapshot {
+                self.snapshots.fetch_add(1, Ordering::AcqRel);
+                TestSnapshot { running: true }
+            }
+
+            fn snapshot_into(&self, buf: &mut Self::Snapshot) {
+                self.refreshes.fetch_add(1, Ordering::AcqRel);
+                buf.running = true;
+            }
+
+            fn quit(&self) {}
+
+            fn is_running(&self) -> bool {
+                true
+            }
+        }
+
+        let mut tui =
+            Tui::with_backend(RefreshingState::default(), TestBackend::new(10, 2)).unwrap();
+        for _ in 0..3 {
+            tui.render(|snapshot, _, _| assert!(snapshot.running))
+                .unwrap();
+        }
+
+        // Only the first frame builds a snapshot, later ones update it
+        assert_eq!(tui.state().snapshots.load(Ordering::Acquire), 1);
+        assert_eq!(tui.state().refreshes.load(Ordering::Acquire), 2);
+
+        tui.invalidate_snapshot();
+        tui.render(|_, _, _| {}).unwrap();
+        assert_eq!(tui.state().snapshots.load(Ordering::Acquire), 1);
+        assert_eq!(tui.state().refreshes.load(Ordering::Acquire), 3);
+    }
+
+    #[test]
+    fn test_render_with_previous_hands_over_last_snapshot() {
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+        let mut tui = Tui::with_backend(state, TestBackend::new(10, 2)).unwrap();
+
+        let mut seen = Vec::new();
+        tui.render_with_previous(|current, previous, _, _| {
+            seen.push((current.running, previous.map(|p| p.running)))
+        })
+        .unwrap();
+
+        tui.state().running.store(false, Ordering::Release);
+        tui.render_with_previous(|current, previous, area, frame| {
+            seen.push((current.running, previous.map(|p| p.running)));
+            frame.render_widget(Paragraph::new("fading"), area);
+        })
+        .unwrap();
+
+        // Plain renders neither see nor replace the previous snapshot
+        tui.render(|_, _, _| {}).unwrap();
+        tui.render_with_previous(|current, previous, _, _| {
+            seen.push((current.running, previous.map(|p| p.running)))
+        })
+        .unwrap();
+
+        assert_eq!(
+            seen,
+            vec![(true, None), (false, Some(true)), (false, Some(false))]
+        );
+    }
+
+    #[test]
+    fn test_render_reuses_snapshot_until_generation_changes() {
+        use std::sync::atomic::{AtomicU64, AtomicUsize};
+
+        #[derive(Debug, Default)]
+        struct VersionedState {
+            generation: AtomicU64,
+            snapshots: AtomicUsize,
+        }
+
+        impl AtomicState for VersionedState {
+            type Snapshot = TestSnapshot;
+
+            fn snapshot(&self) -> Self::Snapshot {
+                self.snapshots.fetch_add(1, Ordering::AcqRel);
+                TestSnapshot { running: true }
+            }
+
+            fn quit(&self) {}
+
+            fn is_running(&self) -> bool {
+                true
+            }
+
+            fn generation(&self) -> Option<u64> {
+                Some(self.generation.load(Ordering::Acquire))
+            }
+        }
+
+        let mut tui =
+            Tui::with_backend(VersionedState::default(), TestBackend::new(10, 2)).unwrap();
+        let snapshots =
+            |tui: &Tui<VersionedState, TestBackend>| tui.state().snapshots.load(Ordering::Acquire);
+
+        // Unchanged generation: the first snapshot is reused
+        tui.render(|_, _, _| {}).unwrap();
+        tui.render(|_, _, _| {}).unwrap();
+        tui.render_region(Rect::new(0, 0, 5, 1), |_, _, _| {})
+            .unwrap();
+        assert_eq!(snapshots(&tui), 1);
+
+        // A bumped generation or explicit invalidation retakes it
+        tui.state().generation.fetch_add(1, Ordering::AcqRel);
+        tui.render(|_, _, _| {}).unwrap();
+        assert_eq!(snapshots(&tui), 2);
+
+        tui.invalidate_snapshot();
+        tui.render(|_, _, _| {}).unwrap();
+        assert_eq!(snapshots(&tui), 3);
+
+        // States without a generation are snapshotted every frame
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+        let mut tui = Tui::with_backend(state, TestBackend::new(10, 2)).unwrap();
+        tui.render(|snapshot, _, _| assert!(snapshot.running))
+            .unwrap();
+        tui.state().quit();
+        tui.render(|snapshot, _, _| assert!(!snapshot.running))
+            .unwrap();
+    }
+
+    /// Writer failing like a terminal whose SSH session dropped.
+    struct BrokenPipe;
+
+    impl io::Write for BrokenPipe {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::BrokenPipe.into())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Err(io::ErrorKind::BrokenPipe.into())
+        }
+    }
+
+    #[test]
+    fn test_render_reports_disconnect() {
+        use crate::error::{as_oxitty, ErrorKind};
+
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+        let mut tui = Tui::with_writer_sized(state, BrokenPipe, Size::new(10, 2)).unwrap();
+
+        let error = tui
+            .render(|_, area, frame| frame.render_widget(Paragraph::new("lost"), area))
+            .unwrap_err();
+        assert_eq!(
+            as_oxitty(&error).map(OxittyError::kind),
+            Some(ErrorKind::Disconnected)
+        );
+
+        // Other write failures stay terminal errors
+        let other = draw_error(io::Error::other("no space"), "Failed to render frame");
+        assert_eq!(other.kind(), ErrorKind::Terminal);
+        #[cfg(unix)]
+        assert!(is_disconnect(&io::Error::from_raw_os_error(5)));
+    }
+
+    #[test]
+    fn test_render_skips_empty_area() {
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+        let mut tui = Tui::with_backend(state, TestBackend::new(0, 0)).unwrap();
+        let mut calls = 0;
+
+        tui.render(|_, _, _| calls += 1).unwrap();
+        tui.try_render(|_, _, _| {
+            calls += 1;
+            Ok(())
+        })
+        .unwrap();
+        tui.render_region(Rect::new(0, 0, 5, 5), |_, _, _| calls += 1)
+            .unwrap();
+        assert_eq!(calls, 0);
+
+        // Rendering resumes once the terminal has a size again
+        tui.terminal_mut().backend_mut().resize(3, 1);
+        tui.render(|_, area, _| {
+            assert_eq!(area, Rect::new(0, 0, 3, 1));
+            calls += 1;
+        })
+        .unwrap();
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn test_try_render_propagates_errors() {
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+        let mut tui = Tui::with_backend(state, TestBackend::new(10, 1)).unwrap();
+
+        tui.try_render(|_, area, frame| {
+            frame.render_widget(Paragraph::new("ok"), area);
+            Ok(())
+        })
+        .unwrap();
+
+        let result = tui.try_render(|_, area, frame| {
+            frame.render_widget(Paragraph::new("partial"), area);
+            Err(OxittyError::terminal("rendering", (0, 0), "data not loaded").into())
+        });
+        let error = result.unwrap_err();
+        assert!(error.to_string().contains("data not loaded"));
+
+        // The failed frame was never flushed and does not leak into the next one
+        let expected = Buffer::with_lines(["ok        "]);
+        tui.terminal.backend().assert_buffer(&expected);
+        tui.render(|_, _, _| {}).unwrap();
+        tui.terminal
+            .backend()
+            .assert_buffer(&Buffer::with_lines(["          "]));
+    }
+
+    #[test]
+    fn test_render_stateful_persists_widget_state() {
+        use ratatui::widgets::{List, ListState};
+
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+        let mut tui = Tui::with_backend(state, TestBackend::new(8, 2)).unwrap();
+        let mut list_state = ListState::default().with_selected(Some(3));
+
+        let draw = |tui: &mut Tui<TestState, TestBackend>, list_state: &mut ListState| {
+            tui.render_stateful(list_state, |_, list_state, area, frame| {
+                let list = List::new(["a", "b", "c", "d"]).highlight_symbol("> ");
+                frame.render_stateful_widget(list, area, list_state);
+            })
+            .unwrap();
+        };
+
+        // Scrolling to keep the selection visible is written back
+        draw(&mut tui, &mut list_state);
+        assert_eq!(list_state.offset(), 2);
+        tui.terminal
+            .backend()
+            .assert_buffer(&Buffer::with_lines(["  c     ", "> d     "]));
+
+        // Moving up within the visible window keeps the offset from last frame
+        list_state.select_previous();
+        draw(&mut tui, &mut list_state);
+        assert_eq!(list_state.selected(), Some(2));
+        assert_eq!(list_state.offset(), 2);
+        tui.terminal
+            .backend()
+            .assert_buffer(&Buffer::with_lines(["> c     ", "  d     "]));
+    }
+
+    #[test]
+    fn test_clear_forces_full_repaint() {
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+        let mut tui = Tui::with_backend(state, CountingBackend::new(4, 2)).unwrap();
+        let draw = |_: &TestSnapshot, area: Rect, frame: &mut ratatui::Frame<'_>| {
+            frame.render_widget(Paragraph::new("ab"), area);
+        };
+
+        tui.render(draw).unwrap();
+        tui.render(draw).unwrap();
+        tui.clear().unwrap();
+        tui.render(draw).unwrap();
+
+        // The identical second frame is diffed away, but after a clear the
+        // blanked screen no longer matches and every non-blank cell is rewritten
+        assert_eq!(tui.terminal().backend().drawn, vec![2, 0, 2]);
+        tui.terminal()
+            .backend()
+            .inner
+            .assert_buffer_lines(["ab  ", "    "]);
+    }
+
+    #[test]
+    fn test_invalidate_redraws_every_cell() {
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+        let mut tui = Tui::with_backend(state, CountingBackend::new(4, 2)).unwrap();
+        let draw = |_: &TestSnapshot, area: Rect, frame: &mut ratatui::Frame<'_>| {
+            frame.render_widget(Paragraph::new("ab"), area);
+        };
+
+        tui.render(draw).unwrap();
+        tui.render(draw).unwrap();
+        tui.invalidate();
+        tui.render(draw).unwrap();
+        tui.render(draw).unwrap();
+
+        // Blank cells are rewritten too, the screen is never cleared, and the
+        // flag only applies to one frame
+        assert_eq!(tui.terminal().backend().drawn, vec![2, 0, 8, 0]);
+        assert!(!tui.terminal().backend().log.borrow().contains(&"clear"));
+        tui.terminal()
+            .backend()
+            .inner
+            .assert_buffer_lines(["ab  ", "    "]);
+    }
+
+    #[test]
+    fn test_with_suspended_restores_and_resumes() {
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+        let backend = CountingBackend::new(4, 1);
+        let log = backend.log.clone();
+        let mut tui = Tui::with_backend(state, backend).unwrap();
+
+        tui.render(|_, area, frame| frame.render_widget(Paragraph::new("ab"), area))
+            .unwrap();
+        log.borrow_mut().clear();
+
+        let value = tui
+            .with_suspended(|| {
+                log.borrow_mut().push("f");
+                42
+            })
+            .unwrap();
+        assert_eq!(value, 42);
+
+        // Cursor is handed back before `f` and the screen invalidated after
+        assert_eq!(*log.borrow(), vec!["show_cursor", "f", "clear"]);
+
+        tui.render(|_, area, frame| frame.render_widget(Paragraph::new("ab"), area))
+            .unwrap();
+        assert_eq!(tui.terminal().backend().drawn, vec![2, 2]);
+    }
+
+    #[test]
+    fn test_with_suspended_resumes_after_panic() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+        let backend = CountingBackend::new(4, 1);
+        let log = backend.log.clone();
+        let mut tui = Tui::with_backend(state, backend).unwrap();
+
+        tui.render(|_, area, frame| frame.render_widget(Paragraph::new("ab"), area))
+            .unwrap();
+        log.borrow_mut().clear();
+
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            tui.with_suspended(|| -> () { panic!("editor crashed") })
+        }));
+        assert!(result.is_err());
+
+        // The screen is invalidated even though `f` never returned
+        assert_eq!(*log.borrow(), vec!["show_cursor", "clear"]);
+
+        tui.render(|_, area, frame| frame.render_widget(Paragraph::new("ab"), area))
+            .unwrap();
+        assert_eq!(tui.terminal().backend().drawn, vec![2, 2]);
+    }
+
+    #[test]
+    fn test_restore_shows_cursor_once() {
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+        let backend = CountingBackend::new(4, 1);
+        let log = backend.log.clone();
+        let mut tui = Tui::with_backend(state, backend).unwrap();
+
+        tui.restore().unwrap();
+        assert_eq!(*log.borrow(), vec!["show_cursor"]);
+        assert!(!tui.is_raw_mode());
+
+        // Still renders after restoring
+        tui.render(|_, area, frame| frame.render_widget(Paragraph::new("ab"), area))
+            .unwrap();
+        assert_eq!(tui.terminal().backend().drawn, vec![2]);
+    }
+
+    #[test]
+    fn test_shutdown_reports_restore_result() {
+        let state = || TestState {
+            running: AtomicBool::new(true),
+        };
+
+        let backend = CountingBackend::new(4, 1);
+        let log = backend.log.clone();
+        let tui = Tui::with_backend(state(), backend).unwrap();
+        assert!(tui.shutdown().is_ok());
+        assert_eq!(*log.borrow(), vec!["show_cursor"]);
+
+        // A failure is returned instead of printed, and not retried on drop
+        let mut backend = CountingBackend::new(4, 1);
+        backend.broken = true;
+        let log = backend.log.clone();
+        let tui = Tui::with_backend(state(), backend).unwrap();
+        let err = tui.shutdown().unwrap_err();
+        assert!(err.to_string().contains("Failed to show cursor"));
+        assert_eq!(*log.borrow(), vec!["show_cursor"]);
+    }
+
+    #[test]
+    fn test_bell_writes_bel() {
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+        let mut tui = Tui::with_backend(state, CountingBackend::new(4, 1)).unwrap();
+
+        tui.bell().unwrap();
+        assert_eq!(tui.terminal().backend().written, b"\x07");
+        assert!(!tui.is_flash_pending());
+    }
+
+    #[test]
+    fn test_visual_bell_flashes_one_frame() {
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+        let mut tui = Tui::with_backend(state, CountingBackend::new(4, 1)).unwrap();
+        tui.set_visual_bell(true);
+        tui.bell().unwrap();
+        assert!(tui.terminal().backend().written.is_empty());
+        assert!(tui.is_flash_pending());
+
+        let draw = |_: &TestSnapshot, area: Rect, frame: &mut ratatui::Frame<'_>| {
+            frame.render_widget(Paragraph::new("ab"), area);
+        };
+        let reversed = |tui: &Tui<TestState, CountingBackend>| {
+            let buffer = tui.terminal().backend().inner.buffer();
+            buffer
+                .content
+                .iter()
+                .all(|cell| cell.modifier.contains(Modifier::REVERSED))
+        };
+
+        tui.render(draw).unwrap();
+        assert!(reversed(&tui));
+        assert!(!tui.is_flash_pending());
+        // The kept frame is the one the render function drew
+        assert!(!tui.last_buffer().unwrap()[(0, 0)]
+            .modifier
+            .contains(Modifier::REVERSED));
 
-    /// Returns primary text style.
-    pub fn primary() -> Style {
-        Style::default()
-            .fg(theme::text::PRIMARY.into())
-            .bg(theme::background::BASE.into())
+        tui.render(draw).unwrap();
+        assert!(tui
+            .terminal()
+            .backend()
+            .inner
+            .buffer()
+            .content
+            .iter()
+            .all(|cell| !cell.modifier.contains(Modifier::REVERSED)));
     }
 
-    /// Returns secondary text style.
-    pub fn secondary() -> Style {
-        Style::default()
-            .fg(theme::text::SECONDARY.into())
-            .bg(theme::background::BASE.into())
-    }
+    #[test]
+    fn test_raw_mode_tracking() {
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+        let mut tui = Tui::with_backend(state, TestBackend::new(4, 1)).unwrap();
 
-    /// Returns error message style.
-    pub fn error() -> Style {
-        Style::default()
-            .fg(theme::status::ERROR.into())
-            .bg(theme::background::BASE.into())
-    }
+        // Test backends never enable raw mode, even across a suspend
+        assert!(!tui.is_raw_mode());
+        tui.with_suspended(|| ()).unwrap();
+        assert!(!tui.is_raw_mode());
 
-    /// Returns warning message style.
-    pub fn warning() -> Style {
-        Style::default()
-            .fg(theme::status::WARNING.into())
-            .bg(theme::background::BASE.into())
-    }
+        // A managed terminal reports setup, suspend and restore transitions
+        thread_local! {
+            static SWITCHES: std::cell::RefCell<Vec<bool>> = const { std::cell::RefCell::new(Vec::new()) };
+        }
+        fn record(enabled: bool) -> io::Result<()> {
+            SWITCHES.with_borrow_mut(|switches| switches.push(enabled));
+            Ok(())
+        }
+        let switches = || SWITCHES.with_borrow(|switches| switches.clone());
 
-    /// Returns info message style.
-    pub fn info() -> Style {
-        Style::default()
-            .fg(theme::status::INFO.into())
-            .bg(theme::background::BASE.into())
-    }
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+        let terminal = Terminal::new(TestBackend::new(4, 1)).unwrap();
+        let mut tui = Tui::from_parts(terminal, state, TerminalMode::Inline, false, true, None);
+        tui.raw_mode_switch = record;
+        assert!(tui.is_raw_mode());
 
-    /// Returns success message style.
-    pub fn success() -> Style {
-        Style::default()
-            .fg(theme::status::SUCCESS.into())
-            .bg(theme::background::BASE.into())
-    }
+        let suspended_raw = tui.with_suspended(switches).unwrap();
+        assert_eq!(suspended_raw, [false]);
+        assert!(tui.is_raw_mode());
+        assert_eq!(switches(), [false, true]);
 
-    /// Returns border element style.
-    pub fn border() -> Style {
-        Style::default()
-            .fg(theme::background::ELEVATION_3.into())
-            .bg(theme::background::BASE.into())
+        // Restoring clears the flag once and is a no-op afterwards
+        tui.restore().unwrap();
+        assert!(!tui.is_raw_mode());
+        tui.restore().unwrap();
+        assert!(!tui.is_raw_mode());
+        assert_eq!(switches(), [false, true, false]);
     }
 
-    /// Returns focused element style.
-    pub fn focus() -> Style {
-        Style::default()
-            .fg(theme::void::PURPLE.into())
-            .bg(theme::background::BASE.into())
-    }
+    #[test]
+    #[ignore = "needs a real terminal"]
+    fn test_raw_mode_tracking_on_terminal() {
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+        let mut tui = Tui::new(state).unwrap();
+        assert!(tui.is_raw_mode());
 
-    /// Returns void element style.
-    pub fn void() -> Style {
-        Style::default()
-            .fg(theme::void::GREEN.into())
-            .bg(theme::background::BASE.into())
-    }
+        let suspended_raw = tui.with_suspended(terminal::is_raw_mode_enabled).unwrap();
+        assert!(!suspended_raw.unwrap());
+        assert!(tui.is_raw_mode());
 
-    /// Creates a themed block with given title.
-    ///
-    /// # Arguments
-    ///
-    /// * `title` - Block title text
-    pub fn block(title: impl Into<String>) -> Block<'static> {
-        Block::default()
-            .title(Line::from(title.into()))
-            .style(Self::primary())
-            .border_style(Self::border())
+        tui.restore().unwrap();
+        assert!(!tui.is_raw_mode());
+        assert!(!terminal::is_raw_mode_enabled().unwrap());
     }
-}
 
-impl<S: AtomicState> Drop for Tui<S> {
-    fn drop(&mut self) {
-        if let Err(e) = self.restore_terminal() {
-            eprintln!("Failed to restore terminal: {}", e);
-        }
+    #[test]
+    fn test_cursor_control() {
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+        let mut tui = Tui::with_backend(state, TestBackend::new(10, 3)).unwrap();
+
+        tui.set_cursor(3, 1).unwrap();
+        tui.show_cursor().unwrap();
+        tui.terminal.backend_mut().assert_cursor_position((3, 1));
+
+        // Cursor placed from inside the render function
+        tui.render(|_, _, frame| frame.set_cursor_position((7, 2)))
+            .unwrap();
+        tui.terminal.backend_mut().assert_cursor_position((7, 2));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::sync::atomic::{AtomicBool, Ordering};
+    #[test]
+    fn test_monochrome_routes_theme_colors_to_grays() {
+        use ratatui::style::Color as RatatuiColor;
 
-    // Mock terminal setup
-    fn setup_mock_terminal() {
-        // Force non-interactive environment
-        std::env::remove_var("TERM");
-        std::env::remove_var("COLORTERM");
-        std::env::remove_var("TERMINFO");
-        std::env::remove_var("TERMINFO_DIRS");
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+        let mut tui = Tui::with_backend(state, TestBackend::new(4, 1)).unwrap();
+        tui.set_monochrome(true);
+        assert!(tui.is_monochrome());
 
-        // Set minimal dumb terminal
-        std::env::set_var("TERM", "dumb");
-    }
+        let draw = |_: &TestSnapshot, area: Rect, frame: &mut ratatui::Frame<'_>| {
+            frame.render_widget(Paragraph::new("ab").style(Tui::<TestState>::error()), area);
+        };
+        tui.render(draw).unwrap();
+        let cell = &tui.terminal().backend().buffer()[(0, 0)];
+        assert_eq!(
+            cell.fg,
+            RatatuiColor::Indexed(theme::status::ERROR.to_gray_level())
+        );
+        assert_eq!(
+            cell.bg,
+            RatatuiColor::Indexed(theme::background::BASE.to_gray_level())
+        );
 
-    #[derive(Debug, Clone)]
-    struct TestSnapshot {
-        running: bool,
+        // The override only applies while rendering
+        with_color_support(ColorSupport::TrueColor, || {
+            tui.set_monochrome(false);
+            tui.render(draw).unwrap();
+            let cell = &tui.terminal().backend().buffer()[(0, 0)];
+            assert_eq!(cell.fg, theme::status::ERROR.to_ratatui());
+        });
     }
 
-    impl crate::state::StateSnapshot for TestSnapshot {
-        fn should_quit(&self) -> bool {
-            !self.running
-        }
+    #[test]
+    fn test_inline_restores_saved_cursor() {
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+        let mut backend = TestBackend::new(10, 8);
+        backend.set_cursor_position((2, 3)).unwrap();
+        let mut tui = Tui::with_backend_inline(state, backend, 2).unwrap();
+        assert_eq!(tui.saved_cursor(), Some(Position::new(2, 3)));
+
+        tui.render(|_, _, frame| frame.set_cursor_position((7, 4)))
+            .unwrap();
+        tui.terminal.backend_mut().assert_cursor_position((7, 4));
+
+        // The viewport covers rows 3 and 4, so the saved column moves below it
+        tui.restore().unwrap();
+        assert_eq!(tui.terminal.get_frame().area().bottom(), 5);
+        tui.terminal.backend_mut().assert_cursor_position((2, 5));
+
+        // Fullscreen instances leave the cursor alone
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+        let mut tui = Tui::with_backend(state, TestBackend::new(10, 3)).unwrap();
+        assert_eq!(tui.saved_cursor(), None);
+        tui.set_cursor(4, 1).unwrap();
+        tui.restore().unwrap();
+        tui.terminal.backend_mut().assert_cursor_position((4, 1));
     }
 
-    #[derive(Debug)]
-    struct TestState {
-        running: AtomicBool,
+    #[test]
+    fn test_clear_on_start_clears_inline_viewport() {
+        let render_over_old_output = |clear_on_start: bool| {
+            let state = TestState {
+                running: AtomicBool::new(true),
+            };
+            let mut backend = TestBackend::new(6, 4);
+            let mut old = Buffer::empty(Rect::new(0, 0, 6, 4));
+            for y in 0..4 {
+                old.set_string(0, y, "xxxxxx", Style::new());
+            }
+            let cells = old
+                .content
+                .iter()
+                .enumerate()
+                .map(|(i, cell)| {
+                    let (x, y) = old.pos_of(i);
+                    (x, y, cell)
+                })
+                .collect::<Vec<_>>();
+            backend.draw(cells.into_iter()).unwrap();
+            backend.set_cursor_position((0, 2)).unwrap();
+
+            let mut tui = Tui::with_backend_inline(state, backend, 2).unwrap();
+            assert!(!tui.clears_on_start());
+            tui.set_clear_on_start(clear_on_start);
+            tui.render(|_, area, frame| frame.render_widget(Paragraph::new("ok"), area))
+                .unwrap();
+
+            // Later frames never clear
+            tui.terminal
+                .backend_mut()
+                .draw([(5, 3, &Cell::new("!"))].into_iter())
+                .unwrap();
+            tui.render(|_, area, frame| frame.render_widget(Paragraph::new("ok"), area))
+                .unwrap();
+            buffer_to_string(tui.terminal.backend().buffer())
+        };
+
+        assert_eq!(
+            render_over_old_output(false),
+            "xxxxxx\nxxxxxx\nokxxxx\nxxxxx!"
+        );
+        assert_eq!(
+            render_over_old_output(true),
+            "xxxxxx\nxxxxxx\nok    \n     !"
+        );
     }
 
-    impl AtomicState for TestState {
-        type Snapshot = TestSnapshot;
+    #[test]
+    fn test_leave_on_exit_keeps_final_frame() {
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+        let mut backend = TestBackend::new(10, 8);
+        backend.set_cursor_position((0, 2)).unwrap();
+        let mut tui = Tui::with_backend_inline(state, backend, 2).unwrap();
+        tui.set_leave_on_exit(true);
+        assert!(tui.leaves_on_exit());
 
-        fn snapshot(&self) -> Self::Snapshot {
-            TestSnapshot {
-                running: self.running.load(Ordering::Acquire),
-            }
-        }
+        tui.render(|_, area, frame| frame.render_widget(Paragraph::new("done"), area))
+            .unwrap();
+        let viewport = tui.terminal.get_frame().area();
+        tui.restore().unwrap();
 
-        fn quit(&self) {
-            self.running.store(false, Ordering::Release);
-        }
+        // The frame stays and the cursor waits on the line below it
+        let backend = tui.terminal.backend();
+        let text = buffer_to_string(backend.buffer());
+        assert_eq!(text.lines().nth(viewport.y as usize), Some("done      "));
+        tui.terminal
+            .backend_mut()
+            .assert_cursor_position((0, viewport.bottom()));
 
-        fn is_running(&self) -> bool {
-            self.running.load(Ordering::Acquire)
-        }
+        // Written out, the frame keeps its colors and text
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 2));
+        buffer.set_string(0, 0, "ok", Style::new().fg(ratatui::style::Color::Red));
+        let mut output = Vec::new();
+        write_buffer(&mut output, &buffer).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("ok"));
+        assert!(output.contains("\x1b[38;5;1m"));
+        assert_eq!(output.matches("\r\n").count(), 2);
     }
 
     #[test]
-    fn test_terminal_not_available() {
-        setup_mock_terminal();
+    fn test_write_buffer_wide_glyphs_and_modifiers() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 2));
+        buffer.set_string(0, 0, "日本", Style::new());
+        buffer.set_string(0, 1, "a", Style::new().add_modifier(Modifier::BOLD));
+        buffer.set_string(1, 1, "b", Style::new());
+
+        let mut output = Vec::new();
+        write_buffer(&mut output, &buffer).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        // Wide glyphs are not padded, and bold is switched off after its cell
+        let (colors, reset) = ("\x1b[39m\x1b[49m", "\x1b[0m");
+        assert_eq!(
+            output,
+            format!("{colors}日本{reset}\r\n\x1b[1m{colors}a{reset}{colors}b  {reset}\r\n")
+        );
+    }
 
+    #[test]
+    fn test_mouse_capture_toggle() {
         let state = TestState {
             running: AtomicBool::new(true),
         };
+        let mut tui = Tui::with_backend(state, TestBackend::new(10, 3)).unwrap();
+        assert!(!tui.mouse_capture());
 
-        let result = Tui::new(state);
-        assert!(
-            result.is_err(),
-            "Expected TUI creation to fail in mock environment"
-        );
+        tui.set_mouse_capture(true).unwrap();
+        assert!(tui.mouse_capture());
 
-        if let Err(e) = result {
-            let err_msg = e.to_string().to_lowercase();
-            assert!(
-                err_msg.contains("terminal") || err_msg.contains("tty"),
-                "Expected terminal-related error, got: {}",
-                err_msg
-            );
-        }
+        // Repeating the current setting is a no-op
+        tui.set_mouse_capture(true).unwrap();
+        assert!(tui.mouse_capture());
+
+        tui.set_mouse_capture(false).unwrap();
+        assert!(!tui.mouse_capture());
     }
 
     #[test]
-    fn test_tui_creation() {
-        setup_mock_terminal();
-
+    fn test_inline_viewport_height() {
         let state = TestState {
             running: AtomicBool::new(true),
         };
+        let mut tui = Tui::with_backend_inline(state, TestBackend::new(20, 10), 3).unwrap();
 
-        let result = Tui::new(state);
-        assert!(
-            result.is_err(),
-            "Expected TUI creation to fail in mock environment"
-        );
+        let mut rendered = Rect::default();
+        tui.render(|_, area, _| rendered = area).unwrap();
+        assert_eq!(rendered.width, 20);
+        assert_eq!(rendered.height, 3);
 
-        if let Err(e) = result {
-            let err_msg = e.to_string().to_lowercase();
-            assert!(
-                err_msg.contains("terminal") || err_msg.contains("tty"),
-                "Expected terminal-related error, got: {}",
-                err_msg
-            );
-        }
+        tui.insert_before(1, |buf| {
+            buf.set_string(0, 0, "log line", Style::default());
+        })
+        .unwrap();
+        tui.render(|_, area, _| rendered = area).unwrap();
+        assert_eq!(rendered.height, 3);
+        assert!(buffer_to_string(tui.terminal().backend().buffer()).contains("log line"));
     }
 
     #[test]
-    fn test_theme_styles() {
-        // Test primary style
-        let style = Tui::<TestState>::primary();
-        assert_eq!(style.fg, Some(theme::text::PRIMARY.into()));
-        assert_eq!(style.bg, Some(theme::background::BASE.into()));
+    fn test_render_to_buffer_golden() {
+        use crate::colors::{with_color_support, ColorSupport};
 
-        // Test error style
-        let style = Tui::<TestState>::error();
-        assert_eq!(style.fg, Some(theme::status::ERROR.into()));
-        assert_eq!(style.bg, Some(theme::background::BASE.into()));
+        with_color_support(ColorSupport::TrueColor, || {
+            let buffer = render_to_buffer(Size::new(10, 3), |area, frame| {
+                frame.render_widget(Tui::<TestState>::block("Test").borders(Borders::ALL), area);
+            });
 
-        // Test border style
-        let style = Tui::<TestState>::border();
-        assert_eq!(style.fg, Some(theme::background::ELEVATION_3.into()));
-        assert_eq!(style.bg, Some(theme::background::BASE.into()));
+            assert_eq!(
+                buffer_to_string(&buffer),
+                ["┌Test────┐", "│        │", "└────────┘"].join("\n")
+            );
+            assert_eq!(buffer[(0, 0)].fg, theme::background::ELEVATION_3.into());
+            assert_eq!(buffer[(1, 1)].bg, theme::background::BASE.into());
+        });
     }
 
     #[test]
@@ -581,4 +3669,261 @@ mod tests {
         // Assert our themed block matches the reference
         assert_eq!(themed_block, reference_block);
     }
+
+    #[test]
+    fn test_styles_fall_back_to_ansi16() {
+        use crate::colors::{with_color_support, ColorSupport};
+        use ratatui::style::Color as RatatuiColor;
+
+        type T = Tui<TestState, TestBackend>;
+
+        let style = with_color_support(ColorSupport::Ansi16, T::error);
+        assert!(matches!(
+            style.fg,
+            Some(RatatuiColor::Red | RatatuiColor::LightRed)
+        ));
+        assert_eq!(style.bg, Some(RatatuiColor::Black));
+
+        let style = with_color_support(ColorSupport::TrueColor, T::error);
+        assert_eq!(style.fg, Some(theme::status::ERROR.into()));
+    }
+
+    #[test]
+    fn test_style_for_semantic() {
+        use crate::colors::{with_color_support, ColorSupport};
+
+        type T = Tui<TestState>;
+
+        with_color_support(ColorSupport::TrueColor, || {
+            assert_eq!(T::style_for(Semantic::Error), T::error());
+            assert_eq!(
+                T::style_for(Semantic::Error),
+                Style::default()
+                    .fg(theme::status::ERROR.into())
+                    .bg(theme::background::BASE.into())
+            );
+            assert_eq!(T::style_for(Semantic::Primary), T::style());
+            assert_eq!(T::style_for(Severity::Warning.into()), T::warning());
+
+            // Every role is on the base background with a distinct foreground
+            let foregrounds: std::collections::HashSet<_> = Semantic::ALL
+                .iter()
+                .map(|&semantic| {
+                    let style = T::style_for(semantic);
+                    assert_eq!(style.bg, Some(theme::background::BASE.into()));
+                    style.fg
+                })
+                .collect();
+            assert_eq!(foregrounds.len(), Semantic::ALL.len());
+        });
+    }
+
+    #[test]
+    fn test_theme_context_overrides_styles() {
+        use crate::colors::{with_color_support, ColorSupport};
+
+        type T = Tui<TestState>;
+
+        with_color_support(ColorSupport::TrueColor, || {
+            let red = Color::rgb(255, 0, 0);
+            let blue = Color::rgb(0, 0, 255);
+            let outer = ThemeContext::new()
+                .with(Semantic::Focus, red)
+                .with(Semantic::Error, red);
+            let inner = ThemeContext::new().with(Semantic::Focus, blue);
+            let outside = T::style_for(Semantic::Focus);
+
+            outer.apply(|| {
+                assert_eq!(T::style_for(Semantic::Focus).fg, Some(red.into()));
+                assert_eq!(T::error().fg, Some(red.into()));
+                // Roles without an override keep the theme color
+                assert_eq!(
+                    T::info(),
+                    with_default_context(|| {
+                        with_color_support(ColorSupport::TrueColor, T::info)
+                    })
+                );
+
+                inner.apply(|| {
+                    assert_eq!(T::style_for(Semantic::Focus).fg, Some(blue.into()));
+                    assert_eq!(T::error().fg, Some(red.into()));
+                });
+                assert_eq!(T::style_for(Semantic::Focus).fg, Some(red.into()));
+            });
+
+            assert_eq!(T::style_for(Semantic::Focus), outside);
+            assert_ne!(T::style_for(Semantic::Focus).fg, Some(red.into()));
+            assert_eq!(ThemeContext::current(), ThemeContext::new());
+
+            // A panic inside the context still restores the outer one
+            let result = std::panic::catch_unwind(|| outer.apply(|| panic!("render failed")));
+            assert!(result.is_err());
+            assert_eq!(ThemeContext::current(), ThemeContext::new());
+        });
+    }
+
+    /// Runs `f` on a fresh thread, outside any theme context.
+    fn with_default_context<T: Send>(f: impl FnOnce() -> T + Send) -> T {
+        std::thread::scope(|scope| scope.spawn(f).join().unwrap())
+    }
+
+    #[test]
+    fn test_elevation_styles() {
+        use crate::colors::{with_color_support, ColorSupport};
+
+        type T = Tui<TestState, TestBackend>;
+
+        with_color_support(ColorSupport::TrueColor, || {
+            let style = T::style_on_elevation(2.0);
+            assert_eq!(style.bg, Some(theme::background::ELEVATION_2.into()));
+            assert_eq!(style.fg, T::primary().fg);
+            assert_eq!(
+                T::style_on_elevation(0.0).bg,
+                Some(theme::background::BASE.into())
+            );
+
+            let expected = T::block("Panel").style(style);
+            assert_eq!(T::block_on_elevation("Panel", 2.0), expected);
+        });
+    }
+
+    #[test]
+    fn test_contrasting_style() {
+        use crate::colors::{with_color_support, ColorSupport};
+
+        type T = Tui<TestState, TestBackend>;
+
+        with_color_support(ColorSupport::TrueColor, || {
+            let white = Color::rgb(255, 255, 255);
+            let style = T::contrasting_style(white);
+            assert_eq!(style.bg, Some(white.into()));
+            assert_eq!(style.fg, Some(theme::background::BASE.into()));
+
+            let near_black = Color::rgb(12, 12, 16);
+            let style = T::contrasting_style(near_black);
+            assert_eq!(style.bg, Some(near_black.into()));
+            assert_eq!(style.fg, Some(theme::text::PRIMARY.into()));
+        });
+    }
+
+    #[test]
+    fn test_banner_styles() {
+        use crate::colors::{with_color_support, ColorSupport};
+        use ratatui::style::Color as RatatuiColor;
+
+        type T = Tui<TestState, TestBackend>;
+
+        let expected = |fg: Color, subtle: Color| {
+            Style::default()
+                .fg(fg.into())
+                .bg(subtle.over(&theme::background::BASE).into())
+        };
+
+        with_color_support(ColorSupport::TrueColor, || {
+            use theme::status::*;
+
+            assert_eq!(T::info_banner(), expected(INFO, INFO_SUBTLE));
+            assert_eq!(T::success_banner(), expected(SUCCESS, SUCCESS_SUBTLE));
+            assert_eq!(T::warning_banner(), expected(WARNING, WARNING_SUBTLE));
+            assert_eq!(T::error_banner(), expected(ERROR, ERROR_SUBTLE));
+
+            // The tint sits between the base background and the status color
+            assert_eq!(T::error_banner().bg, Some(RatatuiColor::Rgb(51, 22, 31)));
+        });
+    }
+
+    #[test]
+    fn test_block_variants() {
+        let title = "Test";
+
+        let focused = Block::default()
+            .title(Line::from(title))
+            .style(Tui::<TestState>::primary())
+            .borders(Borders::ALL)
+            .border_style(Tui::<TestState>::focus());
+        assert_eq!(Tui::<TestState>::block_focused(title), focused);
+
+        for (severity, border_style) in [
+            (Severity::Info, Tui::<TestState>::info()),
+            (Severity::Success, Tui::<TestState>::success()),
+            (Severity::Warning, Tui::<TestState>::warning()),
+            (Severity::Error, Tui::<TestState>::error()),
+        ] {
+            let status = Block::default()
+                .title(Line::from(title))
+                .style(Tui::<TestState>::primary())
+                .borders(Borders::ALL)
+                .border_style(border_style);
+            assert_eq!(Tui::<TestState>::block_status(title, severity), status);
+        }
+
+        let plain = Block::default()
+            .title(Line::from(title))
+            .style(Tui::<TestState>::primary());
+        assert_eq!(Tui::<TestState>::block_plain(title), plain);
+    }
+
+    #[test]
+    fn test_query_background_color_skips_unmanaged_terminal() {
+        let state = TestState {
+            running: AtomicBool::new(true),
+        };
+        let mut output = Vec::new();
+        let mut tui = Tui::with_writer_sized(state, &mut output, Size::new(6, 2)).unwrap();
+
+        assert_eq!(tui.query_background_color().unwrap(), None);
+        assert!(!tui.is_raw_mode());
+        drop(tui);
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn test_parse_osc11_reply() {
+        assert_eq!(
+            parse_osc11_reply(b"\x1b]11;rgb:1e1e/2e2e/3e3e\x1b\\"),
+            Some(Color::rgb(0x1e, 0x2e, 0x3e))
+        );
+        assert_eq!(
+            parse_osc11_reply(b"x\x1b]11;rgb:ffff/ffff/ffff\x07"),
+            Some(Color::WHITE)
+        );
+
+        // Truncated, foreign and malformed replies
+        assert_eq!(parse_osc11_reply(b"\x1b]11;rgb:0000/0000/0000"), None);
+        assert_eq!(parse_osc11_reply(b"\x1b]10;rgb:0000/0000/0000\x07"), None);
+        assert_eq!(parse_osc11_reply(b"\x1b]11;#000000\x07"), None);
+        assert_eq!(parse_osc11_reply(b""), None);
+    }
+
+    #[test]
+    fn test_draw_text_and_status() {
+        let style = Tui::<TestState>::secondary();
+        let buffer = render_to_buffer(Size::new(4, 3), |area, frame| {
+            Tui::<TestState>::draw_text(frame, area, "ab\ncdef", style);
+        });
+        assert_eq!(buffer_to_string(&buffer), "ab  \ncdef\n    ");
+        for cell in buffer.content() {
+            assert_eq!(Some(cell.fg), style.fg);
+            assert_eq!(Some(cell.bg), style.bg);
+        }
+
+        let buffer = render_to_buffer(Size::new(4, 1), |area, frame| {
+            Tui::<TestState>::draw_status(frame, area, "bad", Severity::Error);
+        });
+        assert_eq!(buffer_to_string(&buffer), "bad ");
+        assert_eq!(Some(buffer[(0, 0)].fg), Tui::<TestState>::error().fg);
+    }
+
+    #[test]
+    fn test_buffer_to_string_wide_glyphs() {
+        let buffer = render_to_buffer(Size::new(4, 1), |area, frame| {
+            frame.render_widget(Paragraph::new("日本"), area);
+        });
+        assert_eq!(buffer_to_string(&buffer), "日本");
+
+        let buffer = render_to_buffer(Size::new(5, 1), |area, frame| {
+            frame.render_widget(Paragraph::new("a日b"), area);
+        });
+        assert_eq!(buffer_to_string(&buffer), "a日b ");
+    }
 }
@@ -0,0 +1,781 @@
+//! Themed ratatui widgets
+//!
+//! Ready-made widgets that draw with the crate's [`Color`] system and theme.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use oxitty::{colors::theme, widgets::GradientBar};
+//! use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+//!
+//! let area = Rect::new(0, 0, 10, 1);
+//! let mut buffer = Buffer::empty(area);
+//!
+//! GradientBar::new(theme::void::GREEN, theme::void::PURPLE, 0.5).render(area, &mut buffer);
+//! assert_eq!(buffer[(0, 0)].fg, theme::void::GREEN.into());
+//! ```
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Flex, Layout, Margin, Rect},
+    style::Style,
+    symbols::scrollbar,
+    text::{Line, Span},
+    widgets::{
+        Block, Borders, Clear, Scrollbar, ScrollbarOrientation, ScrollbarState, StatefulWidget,
+        Widget,
+    },
+    Frame,
+};
+
+use crate::colors::{theme, Color};
+
+/// Symbol drawn in filled cells of a [`GradientBar`].
+const FILLED_SYMBOL: &str = "█";
+/// Symbol drawn in unfilled cells of a [`GradientBar`].
+const EMPTY_SYMBOL: &str = "░";
+/// Columns between adjacent buttons of a [`Modal`].
+const BUTTON_GAP: usize = 2;
+
+/// Horizontal progress bar whose fill fades from one color to another.
+///
+/// The gradient spans the full width of the bar, so a partially filled bar
+/// shows the leading part of the gradient and only a full bar reaches the end
+/// color. Unfilled cells are drawn in the theme's third elevation color.
+/// Every row of the render area is filled the same way.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GradientBar {
+    /// Color of the leftmost cell
+    start: Color,
+    /// Color of the rightmost cell when full
+    end: Color,
+    /// Filled fraction (0.0-1.0)
+    ratio: f32,
+}
+
+impl GradientBar {
+    /// Creates a gradient bar.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Color at the left edge
+    /// * `end` - Color at the right edge
+    /// * `ratio` - Filled fraction, clamped to 0.0-1.0 (NaN counts as 0.0)
+    pub fn new(start: Color, end: Color, ratio: f32) -> Self {
+        let ratio = if ratio.is_nan() {
+            0.0
+        } else {
+            ratio.clamp(0.0, 1.0)
+        };
+
+        Self { start, end, ratio }
+    }
+
+    /// Returns the filled fraction after clamping.
+    pub fn ratio(&self) -> f32 {
+        self.ratio
+    }
+}
+
+impl Widget for GradientBar {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+
+        let filled = (self.ratio * area.width as f32).round() as u16;
+        let colors = self.start.gradient(&self.end, area.width as usize);
+        let empty_color = theme::background::ELEVATION_3.into();
+
+        for y in area.top()..area.bottom() {
+            for (offset, color) in colors.iter().enumerate() {
+                let x = area.x + offset as u16;
+                let cell = &mut buf[(x, y)];
+                if (offset as u16) < filled {
+                    cell.set_symbol(FILLED_SYMBOL).set_fg((*color).into());
+                } else {
+                    cell.set_symbol(EMPTY_SYMBOL).set_fg(empty_color);
+                }
+            }
+        }
+    }
+}
+
+/// Vertical scrollbar along the right edge of a scrollable panel.
+///
+/// Wraps ratatui's [`Scrollbar`] so callers only describe the scroll
+/// position: how many lines the content has, how many fit in the viewport,
+/// and the index of the first visible line. The thumb length is
+/// proportional to the visible share of the content and reaches the bottom
+/// of the track exactly when the last line is visible. The thumb is drawn in
+/// the theme's purple accent over a track in the second elevation color.
+///
+/// Nothing is drawn when the content fits in the viewport.
+///
+/// # Examples
+///
+/// ```rust
+/// use oxitty::widgets::ThemedScrollbar;
+/// use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+///
+/// let area = Rect::new(0, 0, 1, 4);
+/// let mut buffer = Buffer::empty(area);
+///
+/// // 8 lines scrolled to the bottom of a 4-line viewport
+/// ThemedScrollbar::new(8, 4, 4).render(area, &mut buffer);
+/// assert_eq!(buffer[(0, 0)].symbol(), "│");
+/// assert_eq!(buffer[(0, 3)].symbol(), "█");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThemedScrollbar {
+    /// Total number of lines in the content
+    content_length: usize,
+    /// Number of lines visible at once
+    viewport_length: usize,
+    /// Index of the first visible line
+    offset: usize,
+}
+
+impl ThemedScrollbar {
+    /// Creates a scrollbar for the given scroll position.
+    ///
+    /// # Arguments
+    ///
+    /// * `content_length` - Total number of lines in the content
+    /// * `viewport_length` - Number of lines visible at once
+    /// * `offset` - Index of the first visible line, clamped so the viewport
+    ///   never scrolls past the end of the content
+    pub fn new(content_length: usize, viewport_length: usize, offset: usize) -> Self {
+        let offset = offset.min(content_length.saturating_sub(viewport_length));
+
+        Self {
+            content_length,
+            viewport_length,
+            offset,
+        }
+    }
+
+    /// Returns the offset after clamping.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns whether the content overflows the viewport, so the bar is drawn.
+    pub fn is_visible(&self) -> bool {
+        self.content_length > self.viewport_length
+    }
+}
+
+impl Widget for ThemedScrollbar {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() || !self.is_visible() {
+            return;
+        }
+
+        // ratatui counts scroll positions rather than lines: the last
+        // position shows the final `viewport_length` lines
+        let mut state = ScrollbarState::new(self.content_length - self.viewport_length + 1)
+            .viewport_content_length(self.viewport_length)
+            .position(self.offset);
+
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .symbols(scrollbar::VERTICAL)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .thumb_style(Style::new().fg(theme::void::PURPLE.into()))
+            .track_style(Style::new().fg(theme::background::ELEVATION_2.into()))
+            .render(area, buf, &mut state);
+    }
+}
+
+/// A key combination paired with the label of the action it triggers.
+pub type KeyBinding<'a> = ((KeyCode, KeyModifiers), &'a str);
+
+/// Modal help popup listing key bindings and their actions.
+///
+/// Dims everything in the render area with
+/// [`semantic::MODAL_OVERLAY`](theme::semantic::MODAL_OVERLAY) and draws a
+/// centered, bordered box with one binding per row: the key combination in
+/// the theme's green accent, followed by its label. When there are more
+/// bindings than rows, the list starts at [`offset`](Self::offset) and a
+/// [`ThemedScrollbar`] on the right border shows the position.
+///
+/// Bindings use the `(KeyCode, KeyModifiers)` pairs built by
+/// [`key!`](crate::key), so the same table can drive key handling and help.
+///
+/// # Examples
+///
+/// ```rust
+/// use oxitty::{key, tui::{buffer_to_string, render_to_buffer}, widgets::KeymapOverlay};
+/// use ratatui::layout::Size;
+///
+/// let bindings = [(key!(Ctrl + 'c'), "Quit"), (key!('?'), "Toggle help")];
+/// let buffer = render_to_buffer(Size::new(30, 6), |area, frame| {
+///     frame.render_widget(KeymapOverlay::new(&bindings), area);
+/// });
+///
+/// let text = buffer_to_string(&buffer);
+/// assert!(text.contains("Ctrl+c  Quit"));
+/// assert!(text.contains("?       Toggle help"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeymapOverlay<'a> {
+    /// Bindings listed in order
+    bindings: &'a [KeyBinding<'a>],
+    /// Index of the first listed binding
+    offset: usize,
+}
+
+impl<'a> KeymapOverlay<'a> {
+    /// Creates an overlay listing `bindings` from the first one.
+    ///
+    /// # Arguments
+    ///
+    /// * `bindings` - Key combinations and their action labels
+    pub fn new(bindings: &'a [KeyBinding<'a>]) -> Self {
+        Self {
+            bindings,
+            offset: 0,
+        }
+    }
+
+    /// Scrolls the list to start at the given binding.
+    ///
+    /// The offset is clamped when rendering so the last page stays full.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - Index of the first binding to show
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+impl Widget for KeymapOverlay<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+
+        dim(area, buf);
+
+        let keys: Vec<String> = self
+            .bindings
+            .iter()
+            .map(|((code, modifiers), _)| key_label(*code, *modifiers))
+            .collect();
+        let key_width = keys
+            .iter()
+            .map(|key| key.chars().count())
+            .max()
+            .unwrap_or(0);
+        let label_width = self
+            .bindings
+            .iter()
+            .map(|(_, label)| label.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        // Two columns apart, with a border and a space of padding either side
+        let width = (key_width + 2 + label_width + 4).min(u16::MAX as usize) as u16;
+        let height = (self.bindings.len() + 2).min(u16::MAX as usize) as u16;
+        let [popup] = Layout::horizontal([Constraint::Length(width)])
+            .flex(Flex::Center)
+            .areas(area);
+        let [popup] = Layout::vertical([Constraint::Length(height)])
+            .flex(Flex::Center)
+            .areas(popup);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Keys ")
+            .style(
+                Style::new()
+                    .fg(theme::text::PRIMARY.into())
+                    .bg(theme::background::ELEVATION_1.into()),
+            )
+            .border_style(Style::new().fg(theme::semantic::FOCUS_RING.into()));
+        let inner = block.inner(popup);
+        Clear.render(popup, buf);
+        block.render(popup, buf);
+
+        let rows = inner.height as usize;
+        let offset = self.offset.min(self.bindings.len().saturating_sub(rows));
+        let key_style = Style::new().fg(theme::void::GREEN.into());
+        let visible = keys.iter().zip(self.bindings).skip(offset).take(rows);
+        for (y, (key, (_, label))) in (inner.y..).zip(visible) {
+            let line = Line::from(vec![
+                Span::raw(" "),
+                Span::styled(format!("{key:<key_width$}"), key_style),
+                Span::raw("  "),
+                Span::raw(*label),
+            ]);
+            line.render(Rect::new(inner.x, y, inner.width, 1), buf);
+        }
+
+        // Drawn over the right border, beside the listed rows
+        if inner.height > 0 && popup.width > 0 {
+            let track = Rect::new(popup.right() - 1, inner.y, 1, inner.height);
+            ThemedScrollbar::new(self.bindings.len(), rows, offset).render(track, buf);
+        }
+    }
+}
+
+/// Draws a [`KeymapOverlay`] of `bindings` over `area`.
+///
+/// A shorthand for rendering the overlay from its first binding inside a
+/// render function, e.g. while a help flag in the snapshot is set.
+///
+/// # Arguments
+///
+/// * `bindings` - Key combinations and their action labels
+/// * `area` - Region to dim and center the popup in, usually the whole frame
+/// * `frame` - Frame to draw into
+pub fn render_keymap_overlay(bindings: &[KeyBinding<'_>], area: Rect, frame: &mut Frame<'_>) {
+    frame.render_widget(KeymapOverlay::new(bindings), area);
+}
+
+/// Confirmation dialog with a dimmed backdrop.
+///
+/// Dims everything in the render area with
+/// [`semantic::MODAL_OVERLAY`](theme::semantic::MODAL_OVERLAY) and draws a
+/// centered, bordered box sized to fit the message, with each line of the
+/// message centered and the buttons in a row along the bottom. When the
+/// dialog does not fit, it is clamped to the area: message lines are cut
+/// from the bottom and right first, while the buttons keep the last row.
+///
+/// Use [`button_areas`](Self::button_areas) with the same area to find
+/// where each button was drawn, for example to hit-test mouse clicks.
+///
+/// # Examples
+///
+/// ```rust
+/// use oxitty::{tui::{buffer_to_string, render_to_buffer}, widgets::Modal};
+/// use ratatui::layout::{Position, Rect, Size};
+///
+/// let modal = Modal::new("Discard changes?", &["Yes", "No"]);
+/// let buffer = render_to_buffer(Size::new(30, 7), |area, frame| {
+///     frame.render_widget(modal, area);
+/// });
+/// assert!(buffer_to_string(&buffer).contains("Discard changes?"));
+///
+/// let [yes, no] = modal.button_areas(Rect::new(0, 0, 30, 7))[..] else {
+///     panic!("one area per button");
+/// };
+/// assert!(yes.contains(Position::new(yes.x + 1, yes.y)));
+/// assert!(no.x > yes.right());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Modal<'a> {
+    /// Message shown above the buttons, one row per line
+    message: &'a str,
+    /// Button labels, left to right
+    buttons: &'a [&'a str],
+}
+
+impl<'a> Modal<'a> {
+    /// Creates a dialog showing `message` above a row of `buttons`.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - Text of the dialog, possibly spanning several lines
+    /// * `buttons` - Button labels, left to right
+    pub fn new(message: &'a str, buttons: &'a [&'a str]) -> Self {
+        Self { message, buttons }
+    }
+
+    /// Returns the box the dialog occupies when rendered into `area`.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Region the dialog is rendered into
+    pub fn popup_area(&self, area: Rect) -> Rect {
+        let message_width = self
+            .message
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let rows = self.message.lines().count() + if self.buttons.is_empty() { 0 } else { 2 };
+
+        // A border and a space of padding either side
+        let width = (message_width.max(self.buttons_width()) + 4).min(u16::MAX as usize) as u16;
+        let height = (rows + 2).min(u16::MAX as usize) as u16;
+        let [popup] = Layout::horizontal([Constraint::Length(width)])
+            .flex(Flex::Center)
+            .areas(area);
+        let [popup] = Layout::vertical([Constraint::Length(height)])
+            .flex(Flex::Center)
+            .areas(popup);
+        popup
+    }
+
+    /// Returns where each button is drawn when rendered into `area`.
+    ///
+    /// Areas are returned in button order and clipped to the inside of the
+    /// dialog, so a button that does not fit has an empty area.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - Region the dialog is rendered into
+    pub fn button_areas(&self, area: Rect) -> Vec<Rect> {
+        let inner = self.popup_area(area).inner(Margin::new(1, 1));
+        let row = inner.bottom().saturating_sub(1);
+        let total = self.buttons_width().min(u16::MAX as usize) as u16;
+
+        let mut x = inner
+            .x
+            .saturating_add(inner.width.saturating_sub(total) / 2);
+        self.buttons
+            .iter()
+            .map(|label| {
+                let width = (label.chars().count() + 2).min(u16::MAX as usize) as u16;
+                let button = Rect::new(x, row, width, 1).intersection(inner);
+                x = x.saturating_add(width).saturating_add(BUTTON_GAP as u16);
+                button
+            })
+            .collect()
+    }
+
+    /// Width of the button row, with a space of padding inside each button.
+    fn buttons_width(&self) -> usize {
+        let labels: usize = self
+            .buttons
+            .iter()
+            .map(|label| label.chars().count() + 2)
+            .sum();
+        labels + BUTTON_GAP * self.buttons.len().saturating_sub(1)
+    }
+}
+
+impl Widget for Modal<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+
+        dim(area, buf);
+
+        let popup = self.popup_area(area);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .style(
+                Style::new()
+                    .fg(theme::text::PRIMARY.into())
+                    .bg(theme::background::ELEVATION_1.into()),
+            )
+            .border_style(Style::new().fg(theme::semantic::FOCUS_RING.into()));
+        let inner = block.inner(popup);
+        Clear.render(popup, buf);
+        block.render(popup, buf);
+
+        // Leave a blank row above the buttons when there is room for it
+        let reserved = if self.buttons.is_empty() { 0 } else { 2 };
+        let rows = inner.height.saturating_sub(reserved) as usize;
+        for (y, line) in (inner.y..).zip(self.message.lines()).take(rows) {
+            Line::from(line)
+                .centered()
+                .render(Rect::new(inner.x, y, inner.width, 1), buf);
+        }
+
+        let button_style = Style::new()
+            .fg(theme::text::PRIMARY.into())
+            .bg(theme::background::ELEVATION_3.into());
+        for (label, button) in self.buttons.iter().zip(self.button_areas(area)) {
+            buf.set_style(button, button_style);
+            Line::from(format!(" {label} ")).render(button, buf);
+        }
+    }
+}
+
+/// Draws a [`Modal`] over `area` and returns the area of each button.
+///
+/// A shorthand for rendering a confirmation dialog inside a render
+/// function. The returned areas match `buttons` by index and can be
+/// checked against mouse positions to find the clicked button.
+///
+/// # Arguments
+///
+/// * `frame` - Frame to draw into
+/// * `message` - Text of the dialog, possibly spanning several lines
+/// * `buttons` - Button labels, left to right
+/// * `area` - Region to dim and center the dialog in, usually the whole frame
+pub fn render_modal(
+    frame: &mut Frame<'_>,
+    message: &str,
+    buttons: &[&str],
+    area: Rect,
+) -> Vec<Rect> {
+    let area = area.intersection(frame.area());
+    let modal = Modal::new(message, buttons);
+    frame.render_widget(modal, area);
+    modal.button_areas(area)
+}
+
+/// Blends [`semantic::MODAL_OVERLAY`](theme::semantic::MODAL_OVERLAY) over
+/// every cell of `area`, treating unset colors as the base background.
+fn dim(area: Rect, buf: &mut Buffer) {
+    let overlay = theme::semantic::MODAL_OVERLAY;
+    let base = theme::background::BASE;
+
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            let cell = &mut buf[(x, y)];
+            let bg = Color::from_ratatui(cell.bg).unwrap_or(base);
+            let fg = Color::from_ratatui(cell.fg).unwrap_or(theme::text::PRIMARY);
+            cell.set_bg(overlay.over(&bg).into());
+            cell.set_fg(overlay.over(&fg).into());
+        }
+    }
+}
+
+/// Formats a key combination such as `Ctrl+Shift+x` for display.
+///
+/// Modifiers use the names accepted by [`key!`](crate::key).
+fn key_label(code: KeyCode, modifiers: KeyModifiers) -> String {
+    const NAMES: [(KeyModifiers, &str); 6] = [
+        (KeyModifiers::CONTROL, "Ctrl"),
+        (KeyModifiers::ALT, "Alt"),
+        (KeyModifiers::SHIFT, "Shift"),
+        (KeyModifiers::SUPER, "Super"),
+        (KeyModifiers::HYPER, "Hyper"),
+        (KeyModifiers::META, "Meta"),
+    ];
+
+    let mut label = String::new();
+    for (modifier, name) in NAMES {
+        if modifiers.contains(modifier) {
+            label.push_str(name);
+            label.push('+');
+        }
+    }
+    match code {
+        KeyCode::Char(' ') => label.push_str("Space"),
+        code => label.push_str(&code.to_string()),
+    }
+    label
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::{buffer_to_string, render_to_buffer};
+    use ratatui::layout::Size;
+
+    fn render_bar(bar: GradientBar, width: u16, height: u16) -> Buffer {
+        render_to_buffer(Size::new(width, height), |area, frame| {
+            frame.render_widget(bar, area);
+        })
+    }
+
+    #[test]
+    fn test_gradient_fill_colors() {
+        let start = theme::void::GREEN;
+        let end = theme::void::PURPLE;
+
+        // Full bar spans the whole gradient
+        let buffer = render_bar(GradientBar::new(start, end, 1.0), 10, 1);
+        assert_eq!(buffer[(0, 0)].fg, start.into());
+        assert_eq!(buffer[(9, 0)].fg, end.into());
+        assert_eq!(buffer_to_string(&buffer), FILLED_SYMBOL.repeat(10));
+
+        // Half bar: rightmost filled cell sits midway along the gradient
+        let buffer = render_bar(GradientBar::new(start, end, 0.5), 10, 2);
+        assert_eq!(buffer[(0, 1)].fg, start.into());
+        assert_eq!(buffer[(4, 1)].fg, start.mix(&end, 4.0 / 9.0).into());
+        assert_eq!(buffer[(5, 1)].symbol(), EMPTY_SYMBOL);
+        assert_eq!(buffer[(5, 1)].fg, theme::background::ELEVATION_3.into());
+    }
+
+    #[test]
+    fn test_ratio_clamping_and_degenerate_areas() {
+        let bar = GradientBar::new(Color::RED, Color::BLUE, 1.5);
+        assert_eq!(bar.ratio(), 1.0);
+        assert_eq!(GradientBar::new(Color::RED, Color::BLUE, -1.0).ratio(), 0.0);
+        assert_eq!(
+            GradientBar::new(Color::RED, Color::BLUE, f32::NAN).ratio(),
+            0.0
+        );
+
+        // An empty bar only draws unfilled cells
+        let buffer = render_bar(GradientBar::new(Color::RED, Color::BLUE, 0.0), 4, 1);
+        assert_eq!(buffer_to_string(&buffer), EMPTY_SYMBOL.repeat(4));
+
+        // Zero-width areas and areas outside the buffer are ignored
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+        bar.render(Rect::new(0, 0, 0, 1), &mut buffer);
+        bar.render(Rect::new(10, 10, 4, 1), &mut buffer);
+        assert_eq!(buffer, Buffer::empty(Rect::new(0, 0, 4, 1)));
+
+        // A single cell uses the start color
+        let buffer = render_bar(bar, 1, 1);
+        assert_eq!(buffer[(0, 0)].fg, Color::RED.into());
+    }
+
+    /// Renders a scrollbar into a one-column buffer as a string of symbols.
+    fn scrollbar_column(scrollbar: ThemedScrollbar, height: u16) -> String {
+        let buffer = render_to_buffer(Size::new(1, height), |area, frame| {
+            frame.render_widget(scrollbar, area);
+        });
+        buffer_to_string(&buffer).replace('\n', "")
+    }
+
+    #[test]
+    fn test_scrollbar_thumb_tracks_offset() {
+        // A quarter of the content is visible, so the thumb covers a quarter
+        assert_eq!(
+            scrollbar_column(ThemedScrollbar::new(40, 10, 0), 8),
+            "██││││││"
+        );
+        assert_eq!(
+            scrollbar_column(ThemedScrollbar::new(40, 10, 15), 8),
+            "│││██│││"
+        );
+        assert_eq!(
+            scrollbar_column(ThemedScrollbar::new(40, 10, 30), 8),
+            "││││││██"
+        );
+
+        // Offsets past the end clamp to the last page
+        let clamped = ThemedScrollbar::new(40, 10, 99);
+        assert_eq!(clamped.offset(), 30);
+        assert_eq!(scrollbar_column(clamped, 8), "││││││██");
+
+        let buffer = render_to_buffer(Size::new(1, 8), |area, frame| {
+            frame.render_widget(ThemedScrollbar::new(40, 10, 0), area);
+        });
+        assert_eq!(buffer[(0, 0)].fg, theme::void::PURPLE.into());
+        assert_eq!(buffer[(0, 7)].fg, theme::background::ELEVATION_2.into());
+    }
+
+    #[test]
+    fn test_scrollbar_hidden_when_content_fits() {
+        for scrollbar in [
+            ThemedScrollbar::new(3, 10, 0),
+            ThemedScrollbar::new(10, 10, 0),
+            ThemedScrollbar::new(0, 0, 5),
+        ] {
+            assert!(!scrollbar.is_visible());
+            assert_eq!(scrollbar.offset(), 0);
+            assert_eq!(scrollbar_column(scrollbar, 4), "    ");
+        }
+
+        // One extra line shows a bar with a nearly full thumb
+        assert!(ThemedScrollbar::new(11, 10, 0).is_visible());
+        assert_eq!(scrollbar_column(ThemedScrollbar::new(11, 10, 1), 4), "████");
+    }
+
+    #[test]
+    fn test_keymap_overlay_lists_bindings() {
+        let bindings = [
+            (crate::key!(Ctrl + 'c'), "Quit"),
+            (crate::key!(Char(' ')), "Pause"),
+            (crate::key!(Enter), "Select"),
+        ];
+        let buffer = render_to_buffer(Size::new(30, 8), |area, frame| {
+            render_keymap_overlay(&bindings, area, frame);
+        });
+        let text = buffer_to_string(&buffer);
+
+        assert!(text.contains("Keys"));
+        assert!(text.contains("Ctrl+c  Quit"));
+        assert!(text.contains("Space   Pause"));
+        assert!(text.contains("Enter   Select"));
+
+        // The backdrop outside the popup is dimmed from the base background
+        let dimmed = theme::semantic::MODAL_OVERLAY.over(&theme::background::BASE);
+        assert_eq!(buffer[(0, 0)].bg, dimmed.into());
+    }
+
+    #[test]
+    fn test_modal_centers_over_dimmed_backdrop() {
+        let mut buttons = Vec::new();
+        let buffer = render_to_buffer(Size::new(40, 12), |area, frame| {
+            buttons = render_modal(frame, "Delete file?", &["Yes", "No"], area);
+        });
+        let rows: Vec<String> = buffer_to_string(&buffer)
+            .lines()
+            .map(str::to_string)
+            .collect();
+
+        // 16x5 box centered in the 40x12 area
+        assert_eq!(&rows[4][12..], "┌──────────────┐            ");
+        assert_eq!(&rows[5][12..], "│ Delete file? │            ");
+        assert_eq!(&rows[7][12..], "│  Yes    No   │            ");
+        assert_eq!(&rows[8][12..], "└──────────────┘            ");
+        assert_eq!(buttons, [Rect::new(14, 7, 5, 1), Rect::new(21, 7, 4, 1)]);
+
+        // The backdrop is dimmed around the box but not inside it
+        let dimmed = theme::semantic::MODAL_OVERLAY.over(&theme::background::BASE);
+        for (x, y) in [(0, 0), (11, 5), (28, 5), (20, 3), (20, 9), (39, 11)] {
+            assert_eq!(buffer[(x, y)].bg, dimmed.into(), "cell ({x}, {y})");
+        }
+        assert_eq!(buffer[(13, 5)].bg, theme::background::ELEVATION_1.into());
+        assert_eq!(buffer[(14, 7)].bg, theme::background::ELEVATION_3.into());
+    }
+
+    #[test]
+    fn test_modal_clamps_to_small_areas() {
+        let message = "A message far wider than the screen\nspanning\nseveral\nlines";
+        let mut buttons = Vec::new();
+        let buffer = render_to_buffer(Size::new(12, 5), |area, frame| {
+            buttons = render_modal(frame, message, &["OK", "Cancel"], area);
+        });
+        let area = Rect::new(0, 0, 12, 5);
+
+        // The box fills the area and the buttons keep its last inner row
+        assert_eq!(Modal::new(message, &[]).popup_area(area), area);
+        assert_eq!(buttons[0], Rect::new(1, 3, 4, 1));
+        assert_eq!(buttons[1].y, 3);
+        assert!(area.contains(buttons[1].as_position()));
+        assert_eq!(buttons[1].right(), 11);
+        assert_eq!(
+            buffer_to_string(&buffer).lines().nth(3),
+            Some("│ OK    Can│")
+        );
+
+        // Areas too small for the box do not panic
+        let buffer = render_to_buffer(Size::new(2, 1), |area, frame| {
+            buttons = render_modal(frame, message, &["OK"], area);
+        });
+        assert!(buttons[0].is_empty());
+        assert_eq!(buffer_to_string(&buffer), "┌┐");
+    }
+
+    #[test]
+    fn test_keymap_overlay_scrolls() {
+        let bindings: Vec<KeyBinding> = ('a'..='j')
+            .zip([
+                "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+            ])
+            .map(|(c, label)| (crate::key!(Char(c)), label))
+            .collect();
+        let render = |overlay: KeymapOverlay| {
+            buffer_to_string(&render_to_buffer(Size::new(20, 5), |area, frame| {
+                frame.render_widget(overlay, area);
+            }))
+        };
+
+        let top = render(KeymapOverlay::new(&bindings));
+        assert!(top.contains("a  one"));
+        assert!(top.contains("c  three"));
+        assert!(!top.contains("d  four"));
+        assert!(top.contains('█'));
+
+        let scrolled = render(KeymapOverlay::new(&bindings).offset(4));
+        assert!(!scrolled.contains("d  four"));
+        assert!(scrolled.contains("e  five"));
+        assert!(scrolled.contains("g  seven"));
+
+        // Offsets past the end show the last page
+        let clamped = render(KeymapOverlay::new(&bindings).offset(99));
+        assert!(clamped.contains("h  eight"));
+        assert!(clamped.contains("j  ten"));
+    }
+}